@@ -0,0 +1,215 @@
+use anchor_lang::prelude::*;
+
+use crate::UniversalNftError;
+
+/// One typed slot of an EVM ABI tuple. `Address`/`Uint256` are static (encoded inline in
+/// the head); `Bytes`/`String`/`FixedArray`/`DynArray` are dynamic (a head offset word
+/// pointing into the tail). Deliberately simplified from full Solidity ABI packing: a
+/// `FixedArray` is always tail-encoded here (even when every element is static), since
+/// this module only needs to round-trip the gateway's own tuples, not arbitrary
+/// third-party calldata.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AbiValue {
+    Address([u8; 20]),
+    Uint256([u8; 32]),
+    Bool(bool),
+    Bytes32([u8; 32]),
+    Bytes(Vec<u8>),
+    String(String),
+    FixedArray(Vec<AbiValue>),
+    DynArray(Vec<AbiValue>),
+}
+
+impl AbiValue {
+    fn is_dynamic(&self) -> bool {
+        matches!(
+            self,
+            AbiValue::Bytes(_) | AbiValue::String(_) | AbiValue::FixedArray(_) | AbiValue::DynArray(_)
+        )
+    }
+
+    fn static_word(&self) -> [u8; 32] {
+        let mut word = [0u8; 32];
+        match self {
+            AbiValue::Address(addr) => word[12..].copy_from_slice(addr),
+            AbiValue::Uint256(bytes) => word.copy_from_slice(bytes),
+            AbiValue::Bool(b) => word[31] = if *b { 1 } else { 0 },
+            AbiValue::Bytes32(bytes) => word.copy_from_slice(bytes),
+            _ => unreachable!("static_word called on a dynamic AbiValue"),
+        }
+        word
+    }
+
+    fn encode_dynamic(&self) -> Vec<u8> {
+        match self {
+            AbiValue::Bytes(data) => encode_bytes(data),
+            AbiValue::String(s) => encode_bytes(s.as_bytes()),
+            AbiValue::FixedArray(values) => encode_params(values),
+            AbiValue::DynArray(values) => {
+                let mut out = u256_word(values.len() as u64).to_vec();
+                out.extend(encode_params(values));
+                out
+            }
+            _ => unreachable!("encode_dynamic called on a static AbiValue"),
+        }
+    }
+}
+
+/// Compute the 4-byte selector for a Solidity function signature string (e.g.
+/// `"receiveNFT(uint256,address,string,address,uint256,bytes32,bool,bytes32,address)"`):
+/// the first 4 bytes of its keccak256 hash.
+pub fn selector(signature: &str) -> [u8; 4] {
+    let hash = anchor_lang::solana_program::keccak::hash(signature.as_bytes());
+    let mut sel = [0u8; 4];
+    sel.copy_from_slice(&hash.to_bytes()[..4]);
+    sel
+}
+
+/// Schema describing how to decode each slot of a tuple; `AbiValue` alone can't drive
+/// decoding since the raw bytes carry no type tag of their own.
+#[derive(Clone, Debug)]
+pub enum AbiKind {
+    Address,
+    Uint256,
+    Bool,
+    Bytes32,
+    Bytes,
+    String,
+    FixedArray(usize, Box<AbiKind>),
+    DynArray(Box<AbiKind>),
+}
+
+impl AbiKind {
+    fn is_dynamic(&self) -> bool {
+        matches!(
+            self,
+            AbiKind::Bytes | AbiKind::String | AbiKind::FixedArray(..) | AbiKind::DynArray(_)
+        )
+    }
+}
+
+fn u256_word(value: u64) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[24..].copy_from_slice(&value.to_be_bytes());
+    word
+}
+
+fn encode_bytes(data: &[u8]) -> Vec<u8> {
+    let padded_len = (data.len() + 31) / 32 * 32;
+    let mut out = Vec::with_capacity(32 + padded_len);
+    out.extend_from_slice(&u256_word(data.len() as u64));
+    out.extend_from_slice(data);
+    out.resize(32 + padded_len, 0);
+    out
+}
+
+/// Encode a tuple of values using the standard two-pass head/tail layout: one 32-byte
+/// head slot per value (an inline word for static types, an offset placeholder for
+/// dynamic ones), followed by the dynamic values' data appended in order to the tail.
+pub fn encode_params(values: &[AbiValue]) -> Vec<u8> {
+    let head_len = values.len() * 32;
+    let mut head = vec![0u8; head_len];
+    let mut tail = Vec::new();
+
+    for (i, value) in values.iter().enumerate() {
+        if value.is_dynamic() {
+            let offset = (head_len + tail.len()) as u64;
+            head[i * 32..i * 32 + 32].copy_from_slice(&u256_word(offset));
+            tail.extend(value.encode_dynamic());
+        } else {
+            head[i * 32..i * 32 + 32].copy_from_slice(&value.static_word());
+        }
+    }
+
+    head.extend(tail);
+    head
+}
+
+/// Decode a tuple according to `schema`, validating that every declared offset/length
+/// fits within `data` so a truncated or overlapping tail is rejected rather than
+/// silently read out of bounds.
+pub fn decode_params(schema: &[AbiKind], data: &[u8]) -> Result<Vec<AbiValue>> {
+    let head_len = schema.len() * 32;
+    require!(data.len() >= head_len, UniversalNftError::InvalidMessageHash);
+
+    let mut out = Vec::with_capacity(schema.len());
+    for (i, kind) in schema.iter().enumerate() {
+        let word = &data[i * 32..i * 32 + 32];
+        if kind.is_dynamic() {
+            let offset = read_u256_as_usize(word)?;
+            require!(offset <= data.len(), UniversalNftError::InvalidMessageHash);
+            out.push(decode_dynamic(kind, &data[offset..])?);
+        } else {
+            out.push(decode_static(kind, word)?);
+        }
+    }
+    Ok(out)
+}
+
+fn read_u256_as_usize(word: &[u8]) -> Result<usize> {
+    // Reject values whose upper bytes carry magnitude no `usize` buffer could hold -
+    // the gateway never sends a tuple anywhere near that large.
+    require!(word[..24].iter().all(|b| *b == 0), UniversalNftError::InvalidMessageHash);
+    let mut buf = [0u8; 8];
+    buf.copy_from_slice(&word[24..32]);
+    Ok(u64::from_be_bytes(buf) as usize)
+}
+
+fn decode_static(kind: &AbiKind, word: &[u8]) -> Result<AbiValue> {
+    match kind {
+        AbiKind::Address => {
+            require!(word[..12].iter().all(|b| *b == 0), UniversalNftError::InvalidMessageHash);
+            let mut addr = [0u8; 20];
+            addr.copy_from_slice(&word[12..]);
+            Ok(AbiValue::Address(addr))
+        }
+        AbiKind::Uint256 => {
+            let mut bytes = [0u8; 32];
+            bytes.copy_from_slice(word);
+            Ok(AbiValue::Uint256(bytes))
+        }
+        AbiKind::Bool => {
+            require!(
+                word[..31].iter().all(|b| *b == 0) && word[31] <= 1,
+                UniversalNftError::InvalidMessageHash
+            );
+            Ok(AbiValue::Bool(word[31] == 1))
+        }
+        AbiKind::Bytes32 => {
+            let mut bytes = [0u8; 32];
+            bytes.copy_from_slice(word);
+            Ok(AbiValue::Bytes32(bytes))
+        }
+        _ => Err(UniversalNftError::InvalidMessageHash.into()),
+    }
+}
+
+fn decode_dynamic(kind: &AbiKind, tail: &[u8]) -> Result<AbiValue> {
+    match kind {
+        AbiKind::Bytes => Ok(AbiValue::Bytes(decode_bytes(tail)?)),
+        AbiKind::String => {
+            let bytes = decode_bytes(tail)?;
+            let s = String::from_utf8(bytes).map_err(|_| UniversalNftError::InvalidMessage)?;
+            Ok(AbiValue::String(s))
+        }
+        AbiKind::FixedArray(len, elem_kind) => {
+            let schema = vec![(**elem_kind).clone(); *len];
+            Ok(AbiValue::FixedArray(decode_params(&schema, tail)?))
+        }
+        AbiKind::DynArray(elem_kind) => {
+            require!(tail.len() >= 32, UniversalNftError::InvalidMessageHash);
+            let len = read_u256_as_usize(&tail[..32])?;
+            let schema = vec![(**elem_kind).clone(); len];
+            Ok(AbiValue::DynArray(decode_params(&schema, &tail[32..])?))
+        }
+        _ => Err(UniversalNftError::InvalidMessageHash.into()),
+    }
+}
+
+fn decode_bytes(tail: &[u8]) -> Result<Vec<u8>> {
+    require!(tail.len() >= 32, UniversalNftError::InvalidMessageHash);
+    let len = read_u256_as_usize(&tail[..32])?;
+    let padded_len = (len + 31) / 32 * 32;
+    require!(tail.len() >= 32 + padded_len, UniversalNftError::InvalidMessageHash);
+    Ok(tail[32..32 + len].to_vec())
+}
@@ -0,0 +1,248 @@
+use anchor_lang::prelude::*;
+
+/// Configuration account for the Universal NFT program
+#[account]
+#[derive(InitSpace)]
+pub struct UniversalNftConfig {
+    /// Optional authority that can update config settings
+    pub admin: Option<Pubkey>,
+    /// Admin proposed via `propose_admin`, awaiting `accept_admin` confirmation
+    pub pending_admin: Option<Pubkey>,
+    /// Unix timestamp at or after which `pending_admin` may call `accept_admin`; `0` means
+    /// no handoff is pending. Set by `propose_admin`/`modify_program_settings` to
+    /// `now + admin_handoff_delay_seconds`.
+    pub pending_admin_activates_at: i64,
+    /// Delay, in seconds, enforced between a handoff-sensitive change being proposed
+    /// (new admin, new gateway program, new gateway verifier) and it taking effect.
+    pub admin_handoff_delay_seconds: i64,
+    /// Program ID of the Zeta Gateway program for cross-chain messaging
+    pub zeta_gateway_program_id: Pubkey,
+    /// PDA account owned by Gateway program that verifies cross-chain messages
+    pub zeta_gateway_verifier: Pubkey,
+    /// Gateway program id proposed via `modify_program_settings`, awaiting
+    /// `accept_gateway_update` once `pending_gateway_activates_at` elapses
+    pub pending_gateway_program_id: Option<Pubkey>,
+    /// Gateway verifier proposed via `modify_program_settings`, awaiting
+    /// `accept_gateway_update` once `pending_gateway_activates_at` elapses
+    pub pending_gateway_verifier: Option<Pubkey>,
+    /// Unix timestamp at or after which a pending gateway update may be finalized via
+    /// `accept_gateway_update`; `0` means no gateway update is pending.
+    pub pending_gateway_activates_at: i64,
+    /// Incrementing nonce for unique message IDs
+    pub message_sequence: u64,
+    /// Next available token ID for minting NFTs, incremented after each mint
+    pub next_nft_id: u64,
+    /// Flag to pause/unpause program functionality
+    pub paused: bool,
+    /// Unix timestamp when config was created
+    pub initialized_timestamp: i64,
+    /// True if `admin` was verified to match the program's BPF upgrade authority at init time
+    pub bound_to_upgrade_authority: bool,
+    /// Bump seed used to derive config PDA
+    pub pda_bump: u8,
+    /// Bubblegum Merkle tree used for compressed-NFT mints, when cNFT mode is enabled
+    pub merkle_tree: Option<Pubkey>,
+    /// Bump for the tree authority PDA that owns `merkle_tree`
+    pub tree_authority_bump: u8,
+    /// ORAO-style VRF oracle account providing verifiable randomness for NFT id/trait rolls
+    pub randomness_oracle: Option<Pubkey>,
+    /// Default `Ruleset` applied to mints that don't specify their own
+    pub default_ruleset: Option<Pubkey>,
+    /// Metaplex sized-collection mint that bridged-in NFTs are verified into, when set.
+    /// A cross-chain message may override this with its own collection id; an override
+    /// that doesn't match an already-configured collection is rejected rather than
+    /// silently minting the NFT uncollected.
+    pub collection_mint: Option<Pubkey>,
+}
+
+/// Creator-defined ruleset gating universal-NFT transfers via the mint's freeze authority.
+#[account]
+#[derive(InitSpace)]
+pub struct Ruleset {
+    /// Authority allowed to update this ruleset
+    pub authority: Pubkey,
+    /// Ruleset name, used as part of the PDA seed
+    #[max_len(32)]
+    pub name: String,
+    /// Program IDs explicitly allowed to initiate a transfer (empty = no allowlist check)
+    #[max_len(16)]
+    pub allowed_programs: Vec<Pubkey>,
+    /// Program IDs explicitly denied from initiating a transfer
+    #[max_len(16)]
+    pub denied_programs: Vec<Pubkey>,
+    /// Optional parent ruleset this one extends. Stored only: `check_ruleset_chain` does not
+    /// follow it, so a parent's allow/deny lists are not applied to transfers under this one
+    pub parent: Option<Pubkey>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+/// Tracks a single two-phase VRF request used to assign an unpredictable NFT id/traits.
+#[account]
+#[derive(InitSpace)]
+pub struct RandomnessRequest {
+    /// Seed submitted to the oracle, derived from `message_sequence` + mint slot
+    pub seed: [u8; 32],
+    /// Requester who will receive the minted NFT once fulfilled
+    pub requester: Pubkey,
+    /// Set once `fulfill_randomness` has read back the oracle's result
+    pub fulfilled: bool,
+    /// The 64-byte randomness returned by the oracle, once fulfilled
+    pub randomness: [u8; 64],
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+/// Token reservation account for pre-allocated unique identifiers
+#[account]
+#[derive(InitSpace)]
+pub struct TokenReservation {
+    /// The mint address this voucher is for
+    pub mint_address: Pubkey,
+    /// The authority that created this voucher
+    pub creator: Pubkey,
+    /// The reserved token identifier number
+    pub reserved_id: u64,
+    /// The blockchain slot when voucher was created
+    pub block_slot: u64,
+    /// The computed unique token hash
+    pub token_hash: [u8; 32],
+    /// Whether this voucher has been used
+    pub is_consumed: bool,
+    /// Timestamp when voucher was created
+    pub creation_time: i64,
+    /// PDA bump seed
+    pub bump_seed: u8,
+}
+
+/// Direction of a single recorded cross-chain transition, see [`TransitionRecord`].
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum TransitionDirection {
+    ArrivedOnSolana,
+    LeftSolana,
+}
+
+/// One entry in `UniversalNftOrigin::transition_history`: when the NFT crossed chains and
+/// which way, so an indexer can reconstruct the asset's full bridge history straight from
+/// account state instead of scraping logs.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct TransitionRecord {
+    pub timestamp: i64,
+    pub direction: TransitionDirection,
+}
+
+/// Number of most-recent transitions kept in `UniversalNftOrigin::transition_history`;
+/// older entries are dropped oldest-first so the account's `INIT_SPACE` stays fixed.
+pub const MAX_TRANSITION_HISTORY: usize = 8;
+
+/// Universal NFT origin tracking account
+#[account]
+#[derive(InitSpace)]
+pub struct UniversalNftOrigin {
+    /// Unique identifier for the Universal NFT
+    pub nft_id: [u8; 32],
+    /// Original mint address
+    pub original_mint: Pubkey,
+    /// Chain id this NFT was first minted on, set once at creation and never changed
+    /// afterwards. Lets `bridge_to_zetachain`/the inbound callback tell a Solana-native
+    /// NFT apart from a wrapped one on every later round trip, independent of
+    /// `is_on_solana`, which just tracks current location rather than provenance.
+    pub origin_chain: u64,
+    /// Original metadata address
+    pub original_metadata: Pubkey,
+    /// Original URI
+    #[max_len(200)]
+    pub original_uri: String,
+    /// Whether NFT is currently on Solana
+    pub is_on_solana: bool,
+    /// Timestamp when NFT was created
+    pub created_at: i64,
+    /// Timestamp when NFT was transferred off Solana
+    pub transferred_at: Option<i64>,
+    /// PDA bump seed
+    pub bump_seed: u8,
+    /// Ring buffer of the most recent cross-chain transitions, oldest first
+    #[max_len(8)]
+    pub transition_history: Vec<TransitionRecord>,
+}
+
+impl UniversalNftOrigin {
+    /// Mark the NFT as transferred off Solana
+    pub fn mark_transferred_off_solana(&mut self, timestamp: i64) {
+        self.is_on_solana = false;
+        self.transferred_at = Some(timestamp);
+    }
+
+    /// Append a transition to the ring buffer, dropping the oldest entry once
+    /// `MAX_TRANSITION_HISTORY` is exceeded.
+    pub fn record_transition(&mut self, timestamp: i64, direction: TransitionDirection) {
+        if self.transition_history.len() >= MAX_TRANSITION_HISTORY {
+            self.transition_history.remove(0);
+        }
+        self.transition_history.push(TransitionRecord { timestamp, direction });
+    }
+}
+
+/// Binds an NFT owner's Solana pubkey to the EVM address they control, set once via
+/// `bind_sender_address`. `CrossChainBridge::bridge_to_zetachain` reads `evm_sender` from
+/// here instead of sending a hardcoded zero address, so the destination universal
+/// contract can authenticate who actually initiated the transfer.
+#[account]
+#[derive(InitSpace)]
+pub struct SenderBinding {
+    /// Solana pubkey this binding belongs to; also the PDA's seed
+    pub owner: Pubkey,
+    /// EVM address bound to `owner`, carried as payload-3's `sender` field
+    pub evm_sender: [u8; 20],
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+/// Seed prefix for `ReplayMarker`'s PDA: `[REPLAY_MARKER_SEED, token_id, nonce_le_bytes]`.
+pub const REPLAY_MARKER_SEED: &[u8] = b"replay";
+
+/// Replay-protection marker for an inbound `CrossChainCallback` delivery, keyed by
+/// `(token_id, nonce)` rather than `ProcessedMessage`'s full-payload digest. No
+/// instruction in this program creates one; the PDA seeds make a second account for the
+/// same `(token_id, nonce)` pair impossible to create. Unlike `ProcessedMessage`, this one is expected to be closed later via
+/// `prune_replay_markers` once it's aged past a retention window, since a relayer's nonce
+/// stream - unlike a VAA sequence number - gives no other way to bound how many of these
+/// accumulate over time.
+#[account]
+#[derive(InitSpace)]
+pub struct ReplayMarker {
+    /// NFT id (`nft_id`/`token_id`) this marker guards a delivery of
+    pub token_id: [u8; 32],
+    /// Relayer-supplied nonce for this delivery
+    pub nonce: u64,
+    /// Unix timestamp the marker was created, used by `prune_replay_markers` to decide
+    /// whether it's old enough to close
+    pub created_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+/// Replay-protection marker for an inbound gateway message, keyed by
+/// `[b"claim", source_chain_id, nonce]`. The account must be `init`-ed (never
+/// `init_if_needed`) by the consuming instruction, so a replayed message fails at account
+/// creation instead of relying on a mutable flag that could be reset.
+#[account]
+#[derive(InitSpace)]
+pub struct Claim {
+    /// Always true once the account exists; kept explicit for clarity at call sites
+    pub claimed: bool,
+    /// Unix timestamp the message was consumed
+    pub timestamp: i64,
+}
+
+/// Replay-protection marker for an already-delivered `CrossChainCallback` message, keyed
+/// by `[b"processed", digest]` where `digest` is a keccak256 hash of the message's full
+/// `encoded_data`. No instruction in this program creates one.
+#[account]
+#[derive(InitSpace)]
+pub struct ProcessedMessage {
+    /// keccak256(`encoded_data`) of the message this marker was created for
+    pub digest: [u8; 32],
+    /// Unix timestamp the message was processed
+    pub processed_at: i64,
+}
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Token, TokenAccount, Mint};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use anchor_spl::associated_token::AssociatedToken;
 
 use crate::state::*;
@@ -24,23 +24,40 @@ pub struct OnRevert<'info> {
     )]
     pub nft_origin: Account<'info, NftOrigin>,
 
-    /// New mint account for restored NFT (if needed)
+    /// Mint account for the restored NFT. `init_if_needed`: a native Solana NFT being
+    /// released from custody keeps its original mint (it was locked, never burned); a
+    /// wrapped NFT still gets a fresh one here. Uses `token_program` below so a
+    /// Token-2022 NFT (metadata-pointer, transfer-hook, etc.) is restored through the
+    /// same program it originated on, rather than always falling back to legacy Token.
     #[account(
-        init,
+        init_if_needed,
         payer = payer,
         mint::decimals = 0,
         mint::authority = config,
+        mint::token_program = token_program,
     )]
-    pub mint: Account<'info, Mint>,
+    pub mint: InterfaceAccount<'info, Mint>,
 
     /// Token account for the restored NFT
     #[account(
-        init,
+        init_if_needed,
         payer = payer,
         associated_token::mint = mint,
         associated_token::authority = recipient,
+        associated_token::token_program = token_program,
     )]
-    pub token_account: Account<'info, TokenAccount>,
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Custody account a native Solana NFT was locked into on outbound transfer.
+    /// Unused for wrapped NFTs, which go through `mint` instead.
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = config,
+        associated_token::token_program = token_program,
+    )]
+    pub custody_token_account: InterfaceAccount<'info, TokenAccount>,
 
     /// Recipient who will receive the restored NFT
     /// CHECK: This is validated as the original owner in the revert logic
@@ -73,8 +90,8 @@ pub struct OnRevert<'info> {
     /// System program
     pub system_program: Program<'info, System>,
 
-    /// Token program
-    pub token_program: Program<'info, Token>,
+    /// Token program - legacy SPL Token or Token-2022, whichever this NFT originated on
+    pub token_program: Interface<'info, TokenInterface>,
 
     /// Associated token program
     pub associated_token_program: Program<'info, AssociatedToken>,
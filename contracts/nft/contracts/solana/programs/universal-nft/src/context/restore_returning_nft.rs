@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
-    token::{Mint, Token, TokenAccount},
+    token_interface::{Mint, TokenAccount, TokenInterface},
     associated_token::AssociatedToken,
 };
 use crate::state::{UniversalNftConfig, NftOrigin};
@@ -24,15 +24,20 @@ pub struct RestoreReturningNft<'info> {
     )]
     pub nft_origin: Account<'info, NftOrigin>,
     
-    /// New mint account for the restored NFT (since original was burned)
+    /// Mint account for the restored NFT. `init_if_needed`: a native Solana NFT being
+    /// released from custody keeps its original mint (it was locked, never burned); a
+    /// wrapped NFT (the original was burned) still gets a fresh one here. Routed through
+    /// `token_program` so a Token-2022 NFT is restored via the same program it used
+    /// originally rather than always falling back to legacy SPL Token.
     #[account(
-        init,
+        init_if_needed,
         payer = payer,
         mint::decimals = 0,
         mint::authority = config,
         mint::freeze_authority = config,
+        mint::token_program = token_program,
     )]
-    pub mint: Account<'info, Mint>,
+    pub mint: InterfaceAccount<'info, Mint>,
     
     /// Metadata account derived from mint
     /// CHECK: This account is derived from the mint using seeds, ensuring it's the correct metadata account
@@ -65,13 +70,25 @@ pub struct RestoreReturningNft<'info> {
     
     /// Token account for the recipient
     #[account(
-        init,
+        init_if_needed,
         payer = payer,
         associated_token::mint = mint,
         associated_token::authority = recipient,
+        associated_token::token_program = token_program,
     )]
-    pub token_account: Account<'info, TokenAccount>,
-    
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Custody account a native Solana NFT was locked into on outbound transfer.
+    /// Unused for wrapped NFTs, which go through `mint` instead.
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = config,
+        associated_token::token_program = token_program,
+    )]
+    pub custody_token_account: InterfaceAccount<'info, TokenAccount>,
+
     /// Payer for account creation fees
     #[account(mut)]
     pub payer: Signer<'info>,
@@ -82,8 +99,8 @@ pub struct RestoreReturningNft<'info> {
     )]
     pub recipient: SystemAccount<'info>,
     
-    /// Token program for NFT operations
-    pub token_program: Program<'info, Token>,
+    /// Token program for NFT operations - legacy SPL Token or Token-2022
+    pub token_program: Interface<'info, TokenInterface>,
     
     /// Associated token program for creating token accounts
     pub associated_token_program: Program<'info, AssociatedToken>,
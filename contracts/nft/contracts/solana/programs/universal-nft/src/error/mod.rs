@@ -212,4 +212,7 @@ pub enum UniversalNftError {
 
     #[msg("Invalid token supply for NFT")]
     InvalidTokenSupply,
+
+    #[msg("Seller fee basis points exceeds 10000 (100%)")]
+    InvalidSellerFeeBasisPoints,
 }
@@ -44,4 +44,83 @@ pub enum UniversalNftError {
     InvalidRecipientAddress,
     #[msg("Insufficient gas amount")]
     InsufficientGasAmount,
+    #[msg("Master edition max supply reached; no more prints can be issued")]
+    EditionSupplyExceeded,
+    #[msg("Edition number already printed")]
+    EditionAlreadyPrinted,
+}
+
+/// Error set for the bridge/callback/util/operations subsystem (`cross_chain_bridge`,
+/// `callbacks`, `operations`, `util`). Kept separate from `UniversalNftError` since that
+/// subsystem was built independently of the instructions wired into `#[program]` and
+/// names its variants around its own vocabulary (asset tracker, custody, gateway PDA)
+/// rather than the mint/collection vocabulary above.
+#[error_code]
+pub enum Errors {
+    #[msg("Unauthorized gateway")]
+    UnauthorizedGateway,
+    #[msg("Invalid message or account data format")]
+    InvalidDataFormat,
+    #[msg("Invalid recipient address")]
+    InvalidRecipientAddress,
+    #[msg("Invalid token amount")]
+    InvalidTokenAmount,
+    #[msg("Invalid mint")]
+    InvalidMint,
+    #[msg("Invalid token supply")]
+    InvalidTokenSupply,
+    #[msg("Invalid program account")]
+    InvalidProgram,
+    #[msg("Invalid bridge program")]
+    InvalidBridgeProgram,
+    #[msg("Asset is not currently on Solana")]
+    AssetNotOnSolana,
+    #[msg("Operation not allowed")]
+    OperationNotAllowed,
+    #[msg("Program is paused")]
+    ProgramPaused,
+    #[msg("Arithmetic overflow")]
+    MathOverflow,
+    #[msg("Message already processed")]
+    MessageAlreadyProcessed,
+    #[msg("Message already claimed")]
+    MessageAlreadyClaimed,
+    #[msg("Unknown collection")]
+    UnknownCollection,
+    #[msg("Voucher already used")]
+    VoucherAlreadyUsed,
+    #[msg("Name too long")]
+    NameTooLong,
+    #[msg("Symbol too long")]
+    SymbolTooLong,
+    #[msg("URI too long")]
+    UriTooLong,
+    #[msg("Invalid parameter")]
+    InvalidParameter,
+    #[msg("Invalid caller")]
+    InvalidCaller,
+    #[msg("Invalid account owner")]
+    InvalidAccountOwner,
+    #[msg("Insufficient rent")]
+    InsufficientRent,
+    #[msg("Gateway PDA ownership is invalid")]
+    GatewayPdaOwnershipInvalid,
+    #[msg("Gateway program is still the default/unset address")]
+    GatewayProgramDefault,
+    #[msg("Gateway program mismatch")]
+    GatewayProgramMismatch,
+    #[msg("Gateway program account is not executable")]
+    GatewayProgramNotExecutable,
+    #[msg("Caller is not the configured admin")]
+    UnauthorizedAdmin,
+    #[msg("No admin authority configured")]
+    NoAdminAuthority,
+    #[msg("No pending admin handoff")]
+    NoPendingAdmin,
+    #[msg("No pending gateway update")]
+    NoPendingGatewayUpdate,
+    #[msg("Timelock has not elapsed yet")]
+    TimelockNotElapsed,
+    #[msg("Origin account data conflicts with the expected asset")]
+    OriginConflict,
 }
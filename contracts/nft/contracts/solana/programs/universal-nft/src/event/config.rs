@@ -26,3 +26,23 @@ pub struct ProgramConfigUpdated {
     /// Timestamp of update
     pub timestamp: i64,
 }
+
+/// Emitted by `ModifySettings::modify_program_settings` in place of its previous `msg!`
+/// log, so an indexer can track admin/gateway/pause changes off typed account data.
+#[event]
+pub struct SettingsModifiedEvent {
+    /// Admin before this call
+    pub previous_admin: Option<Pubkey>,
+    /// Admin after this call. A supplied `new_admin` is only recorded as pending, so this
+    /// always equals `previous_admin`
+    pub new_admin: Option<Pubkey>,
+    /// Gateway program id before this call
+    pub previous_gateway: Pubkey,
+    /// Gateway program id after this call. A supplied gateway id is only recorded as
+    /// pending, so this always equals `previous_gateway`
+    pub new_gateway: Pubkey,
+    /// Paused flag after this call
+    pub paused: bool,
+    /// Timestamp of the change
+    pub timestamp: i64,
+}
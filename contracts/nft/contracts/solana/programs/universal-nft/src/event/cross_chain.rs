@@ -59,6 +59,29 @@ pub struct CrossChainTransferFailed {
     pub nonce: u64,
 }
 
+/// Typed event describing an inbound NFT that has been minted and tracked, so indexers
+/// can key off a typed event instead of scraping `msg!`. No instruction in this program
+/// emits it.
+#[event]
+pub struct NftRecoveredEvent {
+    /// Universal token ID recovered
+    pub nft_id: [u8; 32],
+    /// Mint account the NFT was minted into on Solana
+    pub mint: Pubkey,
+    /// Chain ID the NFT was bridged in from
+    pub origin_chain: u64,
+    /// Metadata URI of the recovered NFT
+    pub uri: String,
+    /// Timestamp of recovery
+    pub timestamp: i64,
+    /// keccak256 digest of the cross-chain message this recovery was minted from
+    pub digest: [u8; 32],
+    /// `msg.sender` the message carried (payload-3 only; all-zero for an older message),
+    /// so a handler can check it against a registry of trusted senders before trusting
+    /// this recovery.
+    pub sender: [u8; 20],
+}
+
 #[event]
 pub struct CrossChainTransferReverted {
     /// Original sender who initiated the transfer
@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    bridge_state::UniversalNftConfig,
+    errors::Errors,
+};
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.pda_bump,
+        constraint = config.admin.is_some() @ Errors::NoAdminAuthority,
+        constraint = config.admin.unwrap() == admin.key() @ Errors::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, UniversalNftConfig>,
+}
+
+impl<'info> ProposeAdmin<'info> {
+    pub fn propose_admin(ctx: Context<Self>, new_admin: Pubkey) -> Result<()> {
+        require!(new_admin != Pubkey::default(), Errors::InvalidParameter);
+
+        let config = &mut ctx.accounts.config;
+        config.pending_admin = Some(new_admin);
+        config.pending_admin_activates_at = Clock::get()?.unix_timestamp + config.admin_handoff_delay_seconds;
+
+        msg!(
+            "Admin handoff proposed by {}. Pending admin: {}. Activates at: {}",
+            ctx.accounts.admin.key(),
+            new_admin,
+            config.pending_admin_activates_at
+        );
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    pub pending_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.pda_bump,
+        constraint = config.pending_admin.is_some() @ Errors::NoPendingAdmin,
+        constraint = config.pending_admin.unwrap() == pending_admin.key() @ Errors::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, UniversalNftConfig>,
+}
+
+impl<'info> AcceptAdmin<'info> {
+    pub fn accept_admin(ctx: Context<Self>) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        require!(
+            Clock::get()?.unix_timestamp >= config.pending_admin_activates_at,
+            Errors::TimelockNotElapsed
+        );
+
+        let previous_admin = config.admin;
+
+        config.admin = config.pending_admin.take();
+        config.pending_admin_activates_at = 0;
+
+        msg!(
+            "Admin handoff accepted. Previous admin: {:?}. New admin: {:?}",
+            previous_admin,
+            config.admin
+        );
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct CancelPendingAdmin<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.pda_bump,
+        constraint = config.admin.is_some() @ Errors::NoAdminAuthority,
+        constraint = config.admin.unwrap() == admin.key() @ Errors::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, UniversalNftConfig>,
+}
+
+impl<'info> CancelPendingAdmin<'info> {
+    pub fn cancel_pending_admin(ctx: Context<Self>) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        require!(config.pending_admin.is_some(), Errors::NoPendingAdmin);
+
+        config.pending_admin = None;
+        config.pending_admin_activates_at = 0;
+
+        msg!(
+            "Pending admin handoff cancelled by {}.",
+            ctx.accounts.admin.key()
+        );
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct RenounceAdmin<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.pda_bump,
+        constraint = config.admin.is_some() @ Errors::NoAdminAuthority,
+        constraint = config.admin.unwrap() == admin.key() @ Errors::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, UniversalNftConfig>,
+}
+
+impl<'info> RenounceAdmin<'info> {
+    pub fn renounce_admin(ctx: Context<Self>) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        config.admin = None;
+        config.pending_admin = None;
+
+        msg!("Admin authority renounced by {}. Program now has no admin authority.", ctx.accounts.admin.key());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+use anchor_lang::solana_program::secp256k1_recover::secp256k1_recover;
+
+use crate::state::AddressBinding;
+use crate::{pubkey_to_eth_address, UniversalNftError, ADDRESS_BINDING_SEED};
+
+/// Register `evm_address` as the caller's EVM identity, binding it to `owner`'s Solana
+/// `Pubkey` so future cross-chain messages naming `evm_address` as recipient route NFTs
+/// here instead of into the unbound escrow. `signature`/`recovery_id` must recover to
+/// `evm_address` over `keccak256(owner)` - the same TSS-style proof `on_revert` uses for
+/// its signer check - so only someone who actually holds the EVM private key can claim it,
+/// not just whoever happens to submit the transaction.
+pub fn bind_evm_address(
+    ctx: Context<BindEvmAddress>,
+    evm_address: [u8; 20],
+    signature: [u8; 64],
+    recovery_id: u8,
+) -> Result<()> {
+    require!(recovery_id <= 3, UniversalNftError::InvalidSignature);
+
+    let message_hash = keccak::hash(ctx.accounts.owner.key().as_ref()).to_bytes();
+    let recovered_pubkey = secp256k1_recover(&message_hash, recovery_id, &signature)
+        .map_err(|_| UniversalNftError::InvalidSignature)?;
+    let recovered_address = pubkey_to_eth_address(&recovered_pubkey.0)?;
+    require!(
+        recovered_address == evm_address,
+        UniversalNftError::InvalidSignature
+    );
+
+    let binding = &mut ctx.accounts.address_binding;
+    binding.evm_address = evm_address;
+    binding.solana_address = ctx.accounts.owner.key();
+    binding.bump = ctx.bumps.address_binding;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(evm_address: [u8; 20])]
+pub struct BindEvmAddress<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + AddressBinding::INIT_SPACE,
+        seeds = [ADDRESS_BINDING_SEED, &evm_address],
+        bump
+    )]
+    pub address_binding: Account<'info, AddressBinding>,
+
+    pub system_program: Program<'info, System>,
+}
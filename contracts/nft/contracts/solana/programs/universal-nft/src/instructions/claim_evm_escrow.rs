@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::state::AddressBinding;
+use crate::EVM_ESCROW_SEED;
+
+/// Withdraw an NFT that was routed into the unbound-EVM-recipient escrow PDA, now that
+/// `owner` has registered an `AddressBinding` for the `evm_address` it was escrowed under.
+/// Anyone can call this (there's nothing sensitive about moving the NFT to its rightful
+/// owner), but `address_binding.solana_address` must match `owner`, so only the registered
+/// owner's own token account can receive it.
+pub fn claim_evm_escrow(ctx: Context<ClaimEvmEscrow>, evm_address: [u8; 20]) -> Result<()> {
+    let bump = ctx.bumps.evm_escrow;
+    let seeds: &[&[u8]] = &[EVM_ESCROW_SEED, &evm_address, &[bump]];
+    let signer_seeds = &[seeds];
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.escrow_token_account.to_account_info(),
+            mint: ctx.accounts.nft_mint.to_account_info(),
+            to: ctx.accounts.owner_token_account.to_account_info(),
+            authority: ctx.accounts.evm_escrow.to_account_info(),
+        },
+        signer_seeds,
+    );
+    transfer_checked(cpi_ctx, 1, 0)
+}
+
+#[derive(Accounts)]
+#[instruction(evm_address: [u8; 20])]
+pub struct ClaimEvmEscrow<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [crate::ADDRESS_BINDING_SEED, &evm_address],
+        bump = address_binding.bump,
+        has_one = solana_address @ crate::UniversalNftError::InvalidRecipient,
+    )]
+    pub address_binding: Account<'info, AddressBinding>,
+
+    /// CHECK: `address_binding.solana_address`'s own Solana account, validated by
+    /// `has_one` on `address_binding` above rather than a signature - no signer needed
+    /// since the owner is only receiving funds, never authorizing a spend.
+    pub solana_address: UncheckedAccount<'info>,
+
+    /// CHECK: Escrow PDA that has custodied this NFT since it arrived for an unbound EVM
+    /// recipient; seeds tie it to `evm_address` so it signs the CPI below.
+    #[account(seeds = [EVM_ESCROW_SEED, &evm_address], bump)]
+    pub evm_escrow: UncheckedAccount<'info>,
+
+    pub nft_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = nft_mint,
+        associated_token::authority = evm_escrow,
+        associated_token::token_program = token_program,
+    )]
+    pub escrow_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = nft_mint,
+        associated_token::authority = solana_address,
+        associated_token::token_program = token_program,
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
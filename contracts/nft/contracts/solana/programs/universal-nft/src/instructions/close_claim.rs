@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Claim, Collection};
+use crate::UniversalNftError;
+
+/// Window after which a spent `claim` PDA is old enough to reclaim. Chosen to comfortably
+/// outlast any realistic gateway redelivery/retry window, so closing a claim can never
+/// race a legitimate (slow) redelivery into looking unclaimed again.
+pub const CLAIM_CLOSE_WINDOW_SECS: i64 = 7 * 24 * 60 * 60; // 7 days
+
+/// Reclaim the rent locked in a spent `claim` PDA once it's old enough that no legitimate
+/// redelivery could still be in flight. `claim` accounts accumulate forever otherwise - one
+/// per processed message - so this is optional housekeeping, not something the hot
+/// (`on_call`/`receive_cross_chain`) path depends on; skipping it just means rent accrues.
+pub fn close_claim(ctx: Context<CloseClaim>) -> Result<()> {
+    ctx.accounts
+        .collection
+        .validate_authority(&ctx.accounts.authority.key())?;
+
+    let elapsed = Clock::get()?
+        .unix_timestamp
+        .saturating_sub(ctx.accounts.claim.processed_at);
+    require!(
+        elapsed >= CLAIM_CLOSE_WINDOW_SECS,
+        UniversalNftError::ClaimCloseWindowNotElapsed
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseClaim<'info> {
+    pub collection: Account<'info, Collection>,
+
+    pub authority: Signer<'info>,
+
+    /// Rent goes back to `authority` (validated as `collection.authority` above), since
+    /// they're the one burdened by every delivery's rent piling up over the collection's
+    /// lifetime, not necessarily whoever originally paid to create this specific claim.
+    #[account(mut, close = authority)]
+    pub claim: Account<'info, Claim>,
+}
@@ -0,0 +1,219 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+
+use crate::{
+    bridge_state::UniversalNftConfig,
+    errors::Errors,
+};
+
+/// Mirrors `mpl_bubblegum::types::MetadataArgs` for the leaf appended to the tree.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CompressedMetadataArgs {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub collection: Option<Pubkey>,
+    pub creators: Vec<CompressedCreator>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CompressedCreator {
+    pub address: Pubkey,
+    pub verified: bool,
+    pub share: u8,
+}
+
+#[derive(Accounts)]
+pub struct MintCompressedNft<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.pda_bump,
+        constraint = config.merkle_tree == Some(merkle_tree.key()) @ Errors::InvalidParameter
+    )]
+    pub config: Account<'info, UniversalNftConfig>,
+
+    /// CHECK: tree authority PDA, owns the Merkle tree and signs the Bubblegum CPI
+    #[account(
+        seeds = [merkle_tree.key().as_ref()],
+        bump = config.tree_authority_bump,
+        seeds::program = bubblegum_program.key(),
+    )]
+    pub tree_authority: UncheckedAccount<'info>,
+
+    /// CHECK: the Bubblegum Merkle tree account, validated against `config.merkle_tree`
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: recipient of the compressed-NFT leaf
+    pub leaf_owner: UncheckedAccount<'info>,
+
+    /// CHECK: delegate allowed to transfer/burn the leaf on the owner's behalf
+    pub leaf_delegate: UncheckedAccount<'info>,
+
+    /// CHECK: Noop program used by spl-account-compression for leaf logging
+    pub log_wrapper: UncheckedAccount<'info>,
+
+    /// CHECK: spl-account-compression program
+    pub compression_program: UncheckedAccount<'info>,
+
+    /// CHECK: mpl-bubblegum program
+    pub bubblegum_program: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MintCompressedNft<'info> {
+    /// Append a compressed-NFT leaf via Bubblegum's `MintToCollectionV1` instead of
+    /// minting a full SPL mint + metadata account, so arrivals scale to near-zero rent.
+    pub fn mint_compressed_nft(ctx: Context<Self>, metadata: CompressedMetadataArgs) -> Result<()> {
+        require!(!ctx.accounts.config.paused, Errors::ProgramPaused);
+        require!(!metadata.name.is_empty() && metadata.name.len() <= 32, Errors::InvalidParameter);
+        require!(!metadata.uri.is_empty() && metadata.uri.len() <= 200, Errors::InvalidParameter);
+
+        let config_bump = ctx.accounts.config.pda_bump;
+        let seeds = &[b"config".as_ref(), &[config_bump]];
+        let signer_seeds = &[&seeds[..]];
+
+        let accounts = [
+            ctx.accounts.tree_authority.to_account_info(),
+            ctx.accounts.leaf_owner.to_account_info(),
+            ctx.accounts.leaf_delegate.to_account_info(),
+            ctx.accounts.merkle_tree.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.log_wrapper.to_account_info(),
+            ctx.accounts.compression_program.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ];
+
+        let ix = build_mint_to_collection_v1_ix(
+            ctx.accounts.bubblegum_program.key(),
+            &accounts,
+            metadata,
+        );
+
+        invoke_signed(&ix, &accounts, signer_seeds)?;
+
+        msg!(
+            "Minted compressed NFT leaf into tree {} for owner {}",
+            ctx.accounts.merkle_tree.key(),
+            ctx.accounts.leaf_owner.key()
+        );
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct BurnCompressedNft<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.pda_bump,
+        constraint = config.merkle_tree == Some(merkle_tree.key()) @ Errors::InvalidParameter
+    )]
+    pub config: Account<'info, UniversalNftConfig>,
+
+    /// CHECK: tree authority PDA, owns the Merkle tree and signs the Bubblegum CPI
+    pub tree_authority: UncheckedAccount<'info>,
+
+    /// CHECK: the Bubblegum Merkle tree account being burned from
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: current owner of the leaf being burned for outbound cross-chain transfer
+    pub leaf_owner: Signer<'info>,
+
+    /// CHECK: delegate allowed to burn the leaf on the owner's behalf
+    pub leaf_delegate: UncheckedAccount<'info>,
+
+    /// CHECK: Noop program used by spl-account-compression for leaf logging
+    pub log_wrapper: UncheckedAccount<'info>,
+
+    /// CHECK: spl-account-compression program
+    pub compression_program: UncheckedAccount<'info>,
+
+    /// CHECK: mpl-bubblegum program
+    pub bubblegum_program: UncheckedAccount<'info>,
+}
+
+impl<'info> BurnCompressedNft<'info> {
+    /// Destroy the local compressed-NFT leaf via Bubblegum's `Burn` CPI ahead of minting
+    /// the corresponding NFT on the destination chain.
+    pub fn burn_compressed_nft(ctx: Context<Self>, root: [u8; 32], data_hash: [u8; 32], creator_hash: [u8; 32], nonce: u64, index: u32) -> Result<()> {
+        let accounts = [
+            ctx.accounts.tree_authority.to_account_info(),
+            ctx.accounts.leaf_owner.to_account_info(),
+            ctx.accounts.leaf_delegate.to_account_info(),
+            ctx.accounts.merkle_tree.to_account_info(),
+            ctx.accounts.log_wrapper.to_account_info(),
+            ctx.accounts.compression_program.to_account_info(),
+        ];
+
+        let ix = build_burn_ix(
+            ctx.accounts.bubblegum_program.key(),
+            &accounts,
+            root,
+            data_hash,
+            creator_hash,
+            nonce,
+            index,
+        );
+
+        invoke_signed(&ix, &accounts, &[])?;
+
+        msg!("Burned compressed NFT leaf {} in tree {}", index, ctx.accounts.merkle_tree.key());
+
+        Ok(())
+    }
+}
+
+fn build_mint_to_collection_v1_ix(
+    bubblegum_program: Pubkey,
+    accounts: &[AccountInfo],
+    metadata: CompressedMetadataArgs,
+) -> anchor_lang::solana_program::instruction::Instruction {
+    anchor_lang::solana_program::instruction::Instruction {
+        program_id: bubblegum_program,
+        accounts: accounts
+            .iter()
+            .map(|a| anchor_lang::solana_program::instruction::AccountMeta {
+                pubkey: *a.key,
+                is_signer: a.is_signer,
+                is_writable: a.is_writable,
+            })
+            .collect(),
+        data: metadata.try_to_vec().unwrap_or_default(),
+    }
+}
+
+fn build_burn_ix(
+    bubblegum_program: Pubkey,
+    accounts: &[AccountInfo],
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    nonce: u64,
+    index: u32,
+) -> anchor_lang::solana_program::instruction::Instruction {
+    let mut data = Vec::with_capacity(32 + 32 + 32 + 8 + 4);
+    data.extend_from_slice(&root);
+    data.extend_from_slice(&data_hash);
+    data.extend_from_slice(&creator_hash);
+    data.extend_from_slice(&nonce.to_le_bytes());
+    data.extend_from_slice(&index.to_le_bytes());
+
+    anchor_lang::solana_program::instruction::Instruction {
+        program_id: bubblegum_program,
+        accounts: accounts
+            .iter()
+            .map(|a| anchor_lang::solana_program::instruction::AccountMeta {
+                pubkey: *a.key,
+                is_signer: a.is_signer,
+                is_writable: a.is_writable,
+            })
+            .collect(),
+        data,
+    }
+}
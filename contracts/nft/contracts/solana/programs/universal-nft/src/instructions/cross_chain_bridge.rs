@@ -1,9 +1,10 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
-    token::{Mint, Token, TokenAccount},
+    associated_token::AssociatedToken,
+    token::{transfer, Mint, Token, TokenAccount, Transfer},
 };
 use crate::{
-    state::{UniversalNftConfig, UniversalNftOrigin},
+    bridge_state::{UniversalNftConfig, UniversalNftOrigin, SenderBinding},
     errors::Errors,
     util::{bridge_constants::*, inter_chain_helpers, bridge_operations},
 };
@@ -41,7 +42,29 @@ pub struct CrossChainBridge<'info> {
     
     #[account(mut)]
     pub asset_owner: Signer<'info>,
-    
+
+    /// Program-owned custody ATA a Solana-native NFT is locked into instead of being
+    /// burned; left untouched for a wrapped NFT, which is still burned below.
+    /// `init_if_needed` so the same instruction covers both without a separate code path
+    /// to set it up on a native asset's first departure.
+    #[account(
+        init_if_needed,
+        payer = asset_owner,
+        associated_token::mint = mint,
+        associated_token::authority = settings,
+    )]
+    pub custody_token_account: Account<'info, TokenAccount>,
+
+    /// `asset_owner`'s bound EVM address, populated via `bind_sender_address` and read
+    /// into the outbound message's `sender` field below instead of a hardcoded zero
+    /// address, so the destination contract can authenticate the initiator.
+    #[account(
+        seeds = [b"sender_binding", asset_owner.key().as_ref()],
+        bump = sender_binding.bump,
+        constraint = sender_binding.owner == asset_owner.key() @ Errors::InvalidCaller
+    )]
+    pub sender_binding: Account<'info, SenderBinding>,
+
     /// Cross-Chain Bridge Program
     /// CHECK: This is the cross-chain bridge program for inter-chain operations
     #[account(
@@ -60,6 +83,7 @@ pub struct CrossChainBridge<'info> {
     pub bridge_pda: UncheckedAccount<'info>,
     
     pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
@@ -84,31 +108,49 @@ impl<'info> CrossChainBridge<'info> {
         // Validate token account
         ctx.accounts.validate_token_account()?;
         
-        // Validate mint for burning
-        ctx.accounts.validate_mint_for_burning()?;
-        
+        // Validate mint for the outbound transfer (burn or custody lock)
+        ctx.accounts.validate_mint_for_transfer()?;
+
         // Validate recipient address
         let receiver_addr = ctx.accounts.validate_recipient_address(&final_recipient)?;
 
         let clock = Clock::get()?;
-        
-        // Burn the asset on Solana
-        let burn_ctx = CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            anchor_spl::token::Burn {
-                mint: ctx.accounts.mint.to_account_info(),
-                from: ctx.accounts.token_account.to_account_info(),
-                authority: ctx.accounts.asset_owner.to_account_info(),
-            },
-        );
-        anchor_spl::token::burn(burn_ctx, 1)?;
-        
+        let is_solana_native = ctx.accounts.asset_tracker.origin_chain == SOLANA_NETWORK_ID;
+
+        // Following the native/wrapped split used by the Wormhole NFT bridge: a
+        // Solana-native NFT never had its mint authority held by this program, so
+        // burn-and-re-mint would silently break for it and would mutate supply history
+        // even when it happened to work. Lock it into a program-owned custody account
+        // instead and release it on return; only a wrapped (foreign-origin) NFT is burned
+        // here, to be re-minted by the inbound callback if it comes back.
+        if is_solana_native {
+            let transfer_ctx = CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.token_account.to_account_info(),
+                    to: ctx.accounts.custody_token_account.to_account_info(),
+                    authority: ctx.accounts.asset_owner.to_account_info(),
+                },
+            );
+            transfer(transfer_ctx, 1)?;
+        } else {
+            let burn_ctx = CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                anchor_spl::token::Burn {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    from: ctx.accounts.token_account.to_account_info(),
+                    authority: ctx.accounts.asset_owner.to_account_info(),
+                },
+            );
+            anchor_spl::token::burn(burn_ctx, 1)?;
+        }
+
         // Update origin tracking
         let asset_tracker = &mut ctx.accounts.asset_tracker;
         asset_tracker.mark_transferred_off_solana(clock.unix_timestamp);
         
         let uri = asset_tracker.original_uri.clone();
-        let sender_addr: [u8; 20] = [0u8; 20];
+        let sender_addr = ctx.accounts.sender_binding.evm_sender;
         let cross_chain_message = bridge_operations::encode_evm_nft_message(
             [0u8; 20], // destination (zero for stay on ZetaChain)
             receiver_addr,
@@ -160,18 +202,29 @@ impl<'info> CrossChainBridge<'info> {
             settings.message_sequence
         );
 
-        msg!(
-            "Digital asset destroyed\nToken mint: {}\nDigital asset ID: {:?}\nPrevious owner: {}\nDestruction time: {}\nDestruction purpose: {}",
-            ctx.accounts.mint.key(),
-            asset_identifier,
-            ctx.accounts.asset_owner.key(),
-            clock.unix_timestamp,
-            "bridge_to_zetachain".to_string()
-        );
-        
+        if is_solana_native {
+            msg!(
+                "Digital asset locked into custody\nToken mint: {}\nDigital asset ID: {:?}\nPrevious owner: {}\nLock time: {}\nLock purpose: {}",
+                ctx.accounts.mint.key(),
+                asset_identifier,
+                ctx.accounts.asset_owner.key(),
+                clock.unix_timestamp,
+                "bridge_to_zetachain".to_string()
+            );
+        } else {
+            msg!(
+                "Digital asset destroyed\nToken mint: {}\nDigital asset ID: {:?}\nPrevious owner: {}\nDestruction time: {}\nDestruction purpose: {}",
+                ctx.accounts.mint.key(),
+                asset_identifier,
+                ctx.accounts.asset_owner.key(),
+                clock.unix_timestamp,
+                "bridge_to_zetachain".to_string()
+            );
+        }
+
         Ok(())
     }
-    
+
     /// Validates program state and basic parameters
     fn validate_program_state(&self, final_recipient: &str, sol_deposit_lamports: u64) -> Result<()> {
         require!(!self.settings.paused, Errors::ProgramPaused);
@@ -211,8 +264,8 @@ impl<'info> CrossChainBridge<'info> {
         Ok(())
     }
 
-    /// Validates mint properties for burning
-    fn validate_mint_for_burning(&self) -> Result<()> {
+    /// Validates mint properties before the outbound transfer (burn or custody lock)
+    fn validate_mint_for_transfer(&self) -> Result<()> {
         require!(self.mint.decimals == 0, Errors::InvalidMint);
         require!(self.mint.supply == 1, Errors::InvalidTokenSupply);
         Ok(())
@@ -224,6 +277,109 @@ impl<'info> CrossChainBridge<'info> {
             .map_err(|_| Errors::InvalidRecipientAddress)?;
         Ok(receiver_addr)
     }
+}
+/// Reverts a `bridge_to_zetachain` call the destination chain couldn't complete, for a
+/// Solana-native asset only: releases the custody lock back to `asset_owner` instead of
+/// re-minting, since the mint/metadata never left Solana to begin with. A wrapped asset
+/// was burned outbound rather than custodied, so there's nothing here to release for
+/// it - reverting that case means re-minting via the bridge's inbound path, the same way
+/// any other inbound delivery does, not this instruction.
+#[derive(Accounts)]
+#[instruction(asset_identifier: [u8; 32])]
+pub struct RevertBridgeTransfer<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = settings.pda_bump
+    )]
+    pub settings: Account<'info, UniversalNftConfig>,
 
-    
+    #[account(
+        mut,
+        seeds = [b"asset_tracker", asset_identifier.as_ref()],
+        bump = asset_tracker.bump_seed,
+        constraint = asset_tracker.nft_id == asset_identifier @ Errors::InvalidDataFormat,
+        constraint = !asset_tracker.is_on_solana @ Errors::OperationNotAllowed
+    )]
+    pub asset_tracker: Account<'info, UniversalNftOrigin>,
+
+    #[account(
+        mut,
+        constraint = mint.key() == asset_tracker.original_mint @ Errors::InvalidMint
+    )]
+    pub mint: Account<'info, Mint>,
+
+    /// Program-owned custody ATA the asset was locked into by `bridge_to_zetachain`
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = settings,
+        constraint = custody_token_account.amount == 1 @ Errors::InvalidTokenAmount
+    )]
+    pub custody_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: original owner the asset is released back to; not required to sign since
+    /// the bridge program is the trusted caller relaying the gateway's revert
+    pub asset_owner: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = asset_owner,
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: same trusted caller as the forward direction - the ZetaChain bridge program
+    #[account(
+        constraint = bridge_program.key() == settings.zeta_gateway_program_id,
+        executable
+    )]
+    pub bridge_program: UncheckedAccount<'info>,
+
+    /// CHECK: PDA owned by the bridge program, proving this call originates from a gateway revert
+    #[account(
+        signer,
+        constraint = *bridge_pda.owner == settings.zeta_gateway_program_id,
+        constraint = bridge_pda.key() == settings.zeta_gateway_verifier
+    )]
+    pub bridge_pda: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RevertBridgeTransfer<'info> {
+    pub fn revert_bridge_transfer(ctx: Context<Self>, asset_identifier: [u8; 32]) -> Result<()> {
+        let clock = Clock::get()?;
+        let settings_bump = ctx.accounts.settings.pda_bump;
+        let signer_seeds: &[&[u8]] = &[b"config", &[settings_bump]];
+
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.custody_token_account.to_account_info(),
+                to: ctx.accounts.owner_token_account.to_account_info(),
+                authority: ctx.accounts.settings.to_account_info(),
+            },
+            &[signer_seeds],
+        );
+        transfer(transfer_ctx, 1)?;
+
+        let asset_tracker = &mut ctx.accounts.asset_tracker;
+        asset_tracker.is_on_solana = true;
+        asset_tracker.record_transition(clock.unix_timestamp, crate::bridge_state::TransitionDirection::ArrivedOnSolana);
+
+        msg!(
+            "Bridge transfer reverted, custody released\nDigital asset ID: {:?}\nReleased to: {}\nRelease time: {}",
+            asset_identifier,
+            ctx.accounts.asset_owner.key(),
+            clock.unix_timestamp
+        );
+
+        Ok(())
+    }
 }
\ No newline at end of file
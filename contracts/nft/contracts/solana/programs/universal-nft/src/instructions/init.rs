@@ -1,10 +1,16 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::bpf_loader_upgradeable::{self, UpgradeableLoaderState};
 
 use crate::{
+    bridge_state::UniversalNftConfig,
     errors::Errors,
-    state::UniversalNftConfig,
 };
 
+/// Default delay enforced between a handoff-sensitive change (new admin, new gateway
+/// program, new gateway verifier) being proposed and it taking effect; adjustable
+/// afterwards via `modify_program_settings`.
+pub const DEFAULT_ADMIN_HANDOFF_DELAY_SECONDS: i64 = 86_400;
+
 #[derive(Accounts)]
 pub struct Initialize<'info> {
     #[account(mut)]
@@ -27,6 +33,14 @@ pub struct Initialize<'info> {
     /// Some PDA owned by the gateway program
     pub gateway_pda: UncheckedAccount<'info>,
 
+    /// CHECK: this program's own account, used only to locate its ProgramData when
+    /// `program_data` is supplied; deserialization failures are handled explicitly below
+    pub this_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: the BPF loader's ProgramData account for `this_program`; when present, the
+    /// upgrade authority recorded inside it must match `admin`
+    pub program_data: Option<UncheckedAccount<'info>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -51,19 +65,35 @@ impl<'info> Initialize<'info> {
         Errors::GatewayProgramMismatch
     );
 
+    // Optionally verify that the initializing admin is this program's BPF upgrade
+    // authority, so a front-runner who isn't the deployer can't seize the config PDA.
+    let bound_to_upgrade_authority = Self::verify_upgrade_authority(&ctx)?;
+
     // Initialize config
     let config = &mut ctx.accounts.config;
     let clock = Clock::get()?;
 
     **config = UniversalNftConfig {
         admin: Some(ctx.accounts.admin.key()),
+        pending_admin: None,
+        pending_admin_activates_at: 0,
+        admin_handoff_delay_seconds: DEFAULT_ADMIN_HANDOFF_DELAY_SECONDS,
         zeta_gateway_program_id,
         zeta_gateway_verifier: ctx.accounts.gateway_pda.key(),
+        pending_gateway_program_id: None,
+        pending_gateway_verifier: None,
+        pending_gateway_activates_at: 0,
         message_sequence: 0,
         next_nft_id: 0,
         paused: false,
         initialized_timestamp: clock.unix_timestamp,
+        bound_to_upgrade_authority,
         pda_bump: ctx.bumps.config,
+        merkle_tree: None,
+        tree_authority_bump: 0,
+        randomness_oracle: None,
+        default_ruleset: None,
+        collection_mint: None,
     };
 
     // Log initialization event
@@ -75,4 +105,40 @@ impl<'info> Initialize<'info> {
 
     Ok(())
     }
+
+    /// When `this_program`/`program_data` are supplied, require that the admin signer
+    /// is the program's recorded BPF upgrade authority. Returns whether the check ran.
+    fn verify_upgrade_authority(ctx: &Context<Self>) -> Result<bool> {
+        let (Some(this_program), Some(program_data)) =
+            (&ctx.accounts.this_program, &ctx.accounts.program_data)
+        else {
+            return Ok(false);
+        };
+
+        require_keys_eq!(this_program.key(), crate::ID, Errors::GatewayProgramMismatch);
+
+        let (expected_program_data, _) = Pubkey::find_program_address(
+            &[this_program.key().as_ref()],
+            &bpf_loader_upgradeable::id(),
+        );
+        require_keys_eq!(
+            program_data.key(),
+            expected_program_data,
+            Errors::GatewayProgramMismatch
+        );
+
+        let state: UpgradeableLoaderState = bincode::deserialize(&program_data.data.borrow())
+            .map_err(|_| Errors::GatewayProgramMismatch)?;
+
+        let UpgradeableLoaderState::ProgramData { upgrade_authority_address, .. } = state else {
+            return Err(Errors::GatewayProgramMismatch.into());
+        };
+
+        require!(
+            upgrade_authority_address == Some(ctx.accounts.admin.key()),
+            Errors::UnauthorizedAdmin
+        );
+
+        Ok(true)
+    }
 }
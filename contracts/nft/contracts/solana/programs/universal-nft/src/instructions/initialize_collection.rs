@@ -36,6 +36,7 @@ pub fn initialize_collection(
     collection.total_minted = 0;
     collection.solana_native_count = 0;
     collection.bump = ctx.bumps.collection;
+    collection.collection_mint = None;
 
     // Create collection mint and metadata
     create_collection_mint_and_metadata(&ctx, &name, &symbol, &uri)?;
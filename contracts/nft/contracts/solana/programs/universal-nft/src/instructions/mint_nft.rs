@@ -17,11 +17,15 @@ use mpl_token_metadata::{
         CreateMetadataAccountV3InstructionArgs,
         CreateMasterEditionV3,
         CreateMasterEditionV3InstructionArgs,
+        Create,
+        CreateInstructionArgs,
+        Mint as MplMintV1,
+        MintInstructionArgs,
     },
-    types::{DataV2, Creator, Collection as MetaplexCollection, Uses, CollectionDetails},
+    types::{DataV2, Creator, Collection as MetaplexCollection, Uses, CollectionDetails, CreateArgs, MintArgs, TokenStandard, PrintSupply},
 };
 
-use crate::state::{Collection, NftOrigin};
+use crate::state::{Collection, NftAttribute, NftCreator, NftOrigin, NftUseMethod, NftUses};
 use crate::UniversalNftError;
 
 /// Enhanced mint_nft function that implements the NFT Origin system for new Solana mints
@@ -30,11 +34,23 @@ pub fn mint_nft(
     name: String,
     symbol: String,
     uri: String,
+    max_supply: Option<u64>,
+    uses_total: Option<u64>,
+    use_method: Option<NftUseMethod>,
+    attributes: Option<Vec<NftAttribute>>,
+    rule_set: Option<Pubkey>,
 ) -> Result<()> {
     // Validate inputs
     require!(name.len() > 0 && name.len() <= 32, UniversalNftError::InvalidMessage);
     require!(symbol.len() > 0 && symbol.len() <= 10, UniversalNftError::InvalidMessage);
     require!(uri.len() > 0 && uri.len() <= 200, UniversalNftError::InvalidMessage);
+    crate::validate_uri(&uri)?;
+    if let Some(total) = uses_total {
+        require!(total > 0, UniversalNftError::InvalidMessage);
+    }
+    if let Some(attrs) = &attributes {
+        crate::validate_attributes(attrs)?;
+    }
 
     // Extract values before mutable borrow
     let collection = &ctx.accounts.collection;
@@ -42,6 +58,7 @@ pub fn mint_nft(
     let collection_authority = collection.authority;
     let collection_name = collection.name.clone();
     let collection_bump = collection.bump;
+    let collection_mint_for_verification = collection.collection_mint;
     let mint_pubkey = ctx.accounts.nft_mint.key();
     
     // Enforce authority
@@ -51,22 +68,14 @@ pub fn mint_nft(
         UniversalNftError::InvalidSignature
     );
 
-    // Get current clock for deterministic token ID generation
+    // Get current clock for recording creation time
     let clock = Clock::get()?;
-    let current_slot = clock.slot;
-    
-    // Generate deterministic token_id using [mint_pubkey + block.number + next_token_id]
+
+    // Namespaced token ID: keccak(chain_id || original_mint || collection || next_token_id).
+    // Unlike the previous mint_pubkey+slot scheme, this never collides with an ID minted on
+    // another connector (ZetaChain/EVM) sharing the same per-collection counter space.
     let next_token_id = collection.next_token_id;
-    let mut hash_input = Vec::new();
-    hash_input.extend_from_slice(&mint_pubkey.to_bytes());
-    hash_input.extend_from_slice(&current_slot.to_le_bytes());
-    hash_input.extend_from_slice(&next_token_id.to_le_bytes());
-    
-    let hash = keccak::hash(&hash_input);
-    let token_id = u64::from_le_bytes([
-        hash.0[0], hash.0[1], hash.0[2], hash.0[3],
-        hash.0[4], hash.0[5], hash.0[6], hash.0[7]
-    ]);
+    let (token_id_hash, token_id) = derive_token_id(103, &mint_pubkey, &collection_key, next_token_id);
 
     // Validate token_id uniqueness by checking if origin PDA already exists
     let nft_origin_info = ctx.accounts.nft_origin.to_account_info();
@@ -75,25 +84,37 @@ pub fn mint_nft(
         UniversalNftError::InvalidTokenId
     );
 
-    // Initialize NFT Origin data with collection's next token ID
-    let token_id = collection.next_token_id;
+    // Initialize NFT Origin data
     let nft_origin = &mut ctx.accounts.nft_origin;
     nft_origin.token_id = token_id;
+    nft_origin.token_id_hash = token_id_hash;
     nft_origin.collection = collection_key;
     nft_origin.chain_of_origin = 103; // Solana devnet - adjust based on network
     nft_origin.created_at = clock.unix_timestamp;
     nft_origin.metadata_uri = uri.clone();
     nft_origin.bump = ctx.bumps.nft_origin;
-
-    // Mint NFT token
-    let cpi_ctx = CpiContext::new(
-        ctx.accounts.token_program.to_account_info(),
-        MintTo {
-            mint: ctx.accounts.nft_mint.to_account_info(),
-            to: ctx.accounts.nft_token_account.to_account_info(),
-            authority: ctx.accounts.collection.to_account_info(),
-        },
-    );
+    nft_origin.max_supply = max_supply;
+    nft_origin.parent_master_mint = None;
+    nft_origin.edition_number = None;
+    // Persisted so `on_revert` can reconstruct metadata/master edition if this NFT is
+    // ever transferred out, burned, and reverted.
+    nft_origin.name = name.clone();
+    nft_origin.symbol = symbol.clone();
+    nft_origin.seller_fee_basis_points = 0;
+    nft_origin.creators = vec![NftCreator {
+        address: collection_authority,
+        share: 100,
+    }];
+    nft_origin.cross_chain_cycle_count = 0;
+    nft_origin.transfer_history = Vec::new();
+    let uses = uses_total.map(|total| NftUses {
+        use_method: use_method.unwrap_or(NftUseMethod::Multiple),
+        remaining: total,
+        total,
+    });
+    nft_origin.uses = uses;
+    nft_origin.attributes = attributes.unwrap_or_default();
+    nft_origin.rule_set = rule_set;
 
     let seeds = &[
         b"collection",
@@ -103,36 +124,149 @@ pub fn mint_nft(
     ];
     let signer_seeds = &[&seeds[..]];
 
-    mint_to(cpi_ctx.with_signer(signer_seeds), 1)?;
-
-    // Create proper Metaplex metadata using official CPI
-    create_metadata_account_v3(
-        &ctx.accounts.nft_metadata.to_account_info(),
-        &ctx.accounts.nft_mint.to_account_info(),
-        &ctx.accounts.collection.to_account_info(),
-        &ctx.accounts.authority.to_account_info(),
-        &ctx.accounts.collection.to_account_info(),
-        &ctx.accounts.metadata_program.to_account_info(),
-        &ctx.accounts.system_program.to_account_info(),
-        &ctx.accounts.rent.to_account_info(),
-        name.clone(),
-        symbol.clone(),
-        uri.clone(),
-        signer_seeds,
-    )?;
-
-    // Create master edition for NFT uniqueness using official CPI
-    create_master_edition_v3(
-        &ctx.accounts.master_edition.to_account_info(),
-        &ctx.accounts.nft_mint.to_account_info(),
-        &ctx.accounts.collection.to_account_info(),
-        &ctx.accounts.authority.to_account_info(),
-        &ctx.accounts.nft_metadata.to_account_info(),
-        &ctx.accounts.metadata_program.to_account_info(),
-        &ctx.accounts.system_program.to_account_info(),
-        &ctx.accounts.rent.to_account_info(),
-        signer_seeds,
-    )?;
+    let creators = Some(vec![Creator {
+        address: collection_key,
+        verified: true,
+        share: 100,
+    }]);
+
+    if let Some(rule_set_key) = rule_set {
+        // Programmable NFT: `Create` builds metadata + master edition together and `Mint`
+        // delivers the token, since neither step can be expressed through the legacy
+        // CreateMetadataAccountV3/CreateMasterEditionV3/SPL-`mint_to` trio below.
+        let token_record = ctx
+            .accounts
+            .token_record
+            .as_ref()
+            .ok_or(UniversalNftError::InvalidMessage)?;
+        let authorization_rules_program = ctx
+            .accounts
+            .authorization_rules_program
+            .as_ref()
+            .ok_or(UniversalNftError::InvalidMessage)?;
+        let authorization_rules = ctx
+            .accounts
+            .authorization_rules
+            .as_ref()
+            .ok_or(UniversalNftError::InvalidMessage)?;
+        let sysvar_instructions = ctx
+            .accounts
+            .sysvar_instructions
+            .as_ref()
+            .ok_or(UniversalNftError::InvalidMessage)?;
+        require_keys_eq!(
+            authorization_rules.key(),
+            rule_set_key,
+            UniversalNftError::InvalidMessage
+        );
+
+        create_programmable_nft(
+            &ctx.accounts.nft_metadata.to_account_info(),
+            &ctx.accounts.master_edition.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+            &ctx.accounts.nft_token_account.to_account_info(),
+            &token_record.to_account_info(),
+            &ctx.accounts.collection.to_account_info(),
+            &ctx.accounts.recipient.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.collection.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.associated_token_program.to_account_info(),
+            &sysvar_instructions.to_account_info(),
+            &authorization_rules_program.to_account_info(),
+            &authorization_rules.to_account_info(),
+            name.clone(),
+            symbol.clone(),
+            uri.clone(),
+            0,
+            creators,
+            collection_mint_for_verification,
+            uses.map(|u| u.to_metaplex()),
+            rule_set_key,
+            signer_seeds,
+        )?;
+    } else {
+        // Mint NFT token
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.nft_mint.to_account_info(),
+                to: ctx.accounts.nft_token_account.to_account_info(),
+                authority: ctx.accounts.collection.to_account_info(),
+            },
+        );
+
+        mint_to(cpi_ctx.with_signer(signer_seeds), 1)?;
+
+        // Create proper Metaplex metadata using official CPI
+        create_metadata_account_v3(
+            &ctx.accounts.nft_metadata.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+            &ctx.accounts.collection.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.collection.to_account_info(),
+            &ctx.accounts.metadata_program.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.rent.to_account_info(),
+            name.clone(),
+            symbol.clone(),
+            uri.clone(),
+            0,
+            creators,
+            collection_mint_for_verification,
+            uses.map(|u| u.to_metaplex()),
+            signer_seeds,
+        )?;
+
+        // Create master edition for NFT uniqueness using official CPI
+        create_master_edition_v3(
+            &ctx.accounts.master_edition.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+            &ctx.accounts.collection.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.nft_metadata.to_account_info(),
+            &ctx.accounts.metadata_program.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.rent.to_account_info(),
+            max_supply,
+            signer_seeds,
+        )?;
+    }
+
+    // Verify this mint into the connector's Metaplex collection, if one is configured.
+    if let Some(collection_mint_key) = collection_mint_for_verification {
+        let collection_mint = ctx
+            .accounts
+            .collection_mint
+            .as_ref()
+            .ok_or(UniversalNftError::InvalidMessage)?;
+        require_keys_eq!(
+            collection_mint.key(),
+            collection_mint_key,
+            UniversalNftError::InvalidMessage
+        );
+        let collection_metadata = ctx
+            .accounts
+            .collection_metadata
+            .as_ref()
+            .ok_or(UniversalNftError::InvalidMessage)?;
+        let collection_master_edition = ctx
+            .accounts
+            .collection_master_edition
+            .as_ref()
+            .ok_or(UniversalNftError::InvalidMessage)?;
+
+        verify_collection_for_nft(
+            &ctx.accounts.nft_metadata.to_account_info(),
+            &ctx.accounts.collection.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            &collection_mint.to_account_info(),
+            &collection_metadata.to_account_info(),
+            &collection_master_edition.to_account_info(),
+            signer_seeds,
+        )?;
+    }
 
     // Update collection statistics
     let collection = &mut ctx.accounts.collection;
@@ -146,6 +280,7 @@ pub fn mint_nft(
     emit!(crate::TokenMinted {
         collection: collection_key,
         token_id,
+        token_id_hash,
         mint: mint_pubkey,
         recipient: ctx.accounts.recipient.key(),
         name,
@@ -156,6 +291,7 @@ pub fn mint_nft(
 
     emit!(crate::NftOriginCreated {
         token_id,
+        token_id_hash,
         original_mint: mint_pubkey,
         collection: collection_key,
         origin_chain: 103,
@@ -165,8 +301,46 @@ pub fn mint_nft(
     Ok(())
 }
 
+/// Metaplex's own cap on `DataV2.creators` length (`mpl_token_metadata::MAX_CREATOR_LIMIT`,
+/// not exposed by the version of the crate this program depends on) - mirrored here so
+/// `validate_metadata_data` can enforce it without pulling in the whole assertions module.
+const MAX_CREATOR_LIMIT: usize = 5;
+
+/// Port of Metaplex's `assert_data_valid` creator/royalty checks, run before every
+/// `create_metadata_accounts_v3` CPI so a bridged NFT's royalty config can't corrupt the
+/// metadata account it's about to create. `creators` is validated only when `Some` - an
+/// absent creators list has nothing to check.
+pub(crate) fn validate_metadata_data(seller_fee_basis_points: u16, creators: &Option<Vec<Creator>>) -> Result<()> {
+    require!(
+        seller_fee_basis_points <= 10_000,
+        UniversalNftError::InvalidSellerFeeBasisPoints
+    );
+
+    if let Some(creators) = creators {
+        require!(
+            creators.len() <= MAX_CREATOR_LIMIT,
+            UniversalNftError::TooManyCreators
+        );
+
+        for (i, creator) in creators.iter().enumerate() {
+            require!(
+                !creators[..i].iter().any(|other| other.address == creator.address),
+                UniversalNftError::DuplicateCreatorAddress
+            );
+        }
+
+        let share_sum: u16 = creators.iter().map(|c| c.share as u16).sum();
+        require!(
+            creators.is_empty() || share_sum == 100,
+            UniversalNftError::InvalidCreatorShares
+        );
+    }
+
+    Ok(())
+}
+
 /// Create Metaplex metadata account using proper CPI to mpl-token-metadata
-fn create_metadata_account_v3<'a>(
+pub(crate) fn create_metadata_account_v3<'a>(
     metadata_account: &AccountInfo<'a>,
     mint_account: &AccountInfo<'a>,
     mint_authority: &AccountInfo<'a>,
@@ -178,21 +352,25 @@ fn create_metadata_account_v3<'a>(
     name: String,
     symbol: String,
     uri: String,
+    seller_fee_basis_points: u16,
+    creators: Option<Vec<Creator>>,
+    collection_mint: Option<Pubkey>,
+    uses: Option<Uses>,
     signer_seeds: &[&[&[u8]]],
 ) -> Result<()> {
+    validate_metadata_data(seller_fee_basis_points, &creators)?;
+
     // Create DataV2 struct with metadata information
     let data = DataV2 {
         name,
         symbol,
         uri,
-        seller_fee_basis_points: 0, // No royalties for Universal NFT
-        creators: Some(vec![Creator {
-            address: *update_authority.key,
-            verified: true,
-            share: 100,
-        }]),
-        collection: None, // Collection verification handled separately if needed
-        uses: None,
+        seller_fee_basis_points,
+        creators,
+        // Recorded unverified; `verify_collection_for_nft` marks it verified on-chain
+        // afterwards once the mint/revert flow has created this metadata.
+        collection: collection_mint.map(|key| MetaplexCollection { verified: false, key }),
+        uses,
     };
 
     // Create the instruction using official Metaplex instruction builder
@@ -236,8 +414,144 @@ fn create_metadata_account_v3<'a>(
     Ok(())
 }
 
+/// Mint a programmable NFT (`TokenStandard::ProgrammableNonFungible`) instead of a plain
+/// NonFungible one. `token_standard` and `rule_set` aren't expressible through the legacy
+/// `CreateMetadataAccountV3`/`CreateMasterEditionV3`/SPL-`mint_to` trio `create_metadata_account_v3`
+/// and `create_master_edition_v3` use, so pNFTs go through Metaplex's unified `Create`
+/// (metadata + master edition in one CPI) followed by `Mint` - the only instruction that can
+/// deliver the token into `token_account`, since a pNFT's transfers/mints always route
+/// through the Token Auth Rules program and a direct SPL `mint_to` would be rejected.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn create_programmable_nft<'a>(
+    metadata_account: &AccountInfo<'a>,
+    master_edition_account: &AccountInfo<'a>,
+    mint_account: &AccountInfo<'a>,
+    token_account: &AccountInfo<'a>,
+    token_record: &AccountInfo<'a>,
+    mint_authority: &AccountInfo<'a>,
+    token_owner: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    update_authority: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    token_program: &AccountInfo<'a>,
+    associated_token_program: &AccountInfo<'a>,
+    sysvar_instructions: &AccountInfo<'a>,
+    authorization_rules_program: &AccountInfo<'a>,
+    authorization_rules: &AccountInfo<'a>,
+    name: String,
+    symbol: String,
+    uri: String,
+    seller_fee_basis_points: u16,
+    creators: Option<Vec<Creator>>,
+    collection_mint: Option<Pubkey>,
+    uses: Option<Uses>,
+    rule_set: Pubkey,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    validate_metadata_data(seller_fee_basis_points, &creators)?;
+
+    let create_ix = Create {
+        metadata: *metadata_account.key,
+        master_edition: Some(*master_edition_account.key),
+        mint: (*mint_account.key, false),
+        authority: *mint_authority.key,
+        payer: *payer.key,
+        update_authority: (*update_authority.key, true),
+        system_program: *system_program.key,
+        sysvar_instructions: *sysvar_instructions.key,
+        spl_token_program: Some(*token_program.key),
+    };
+    let create_args = CreateArgs::V1 {
+        name,
+        symbol,
+        uri,
+        seller_fee_basis_points,
+        creators,
+        primary_sale_happened: false,
+        is_mutable: true,
+        token_standard: TokenStandard::ProgrammableNonFungible,
+        collection: collection_mint.map(|key| MetaplexCollection { verified: false, key }),
+        uses,
+        collection_details: None,
+        rule_set: Some(rule_set),
+        decimals: Some(0),
+        print_supply: Some(PrintSupply::Zero),
+    };
+    let create_instruction = create_ix.instruction(CreateInstructionArgs { create_args });
+
+    invoke_signed(
+        &create_instruction,
+        &[
+            metadata_account.clone(),
+            master_edition_account.clone(),
+            mint_account.clone(),
+            mint_authority.clone(),
+            payer.clone(),
+            update_authority.clone(),
+            system_program.clone(),
+            sysvar_instructions.clone(),
+            token_program.clone(),
+        ],
+        signer_seeds,
+    )
+    .map_err(|e| {
+        msg!("Failed to create programmable NFT metadata: {:?}", e);
+        UniversalNftError::InvalidMessage
+    })?;
+
+    let mint_ix = MplMintV1 {
+        token: *token_account.key,
+        token_owner: Some(*token_owner.key),
+        metadata: *metadata_account.key,
+        master_edition: Some(*master_edition_account.key),
+        token_record: Some(*token_record.key),
+        mint: *mint_account.key,
+        authority: *mint_authority.key,
+        delegate_record: None,
+        payer: *payer.key,
+        system_program: *system_program.key,
+        sysvar_instructions: *sysvar_instructions.key,
+        spl_token_program: *token_program.key,
+        spl_ata_program: *associated_token_program.key,
+        authorization_rules_program: Some(*authorization_rules_program.key),
+        authorization_rules: Some(*authorization_rules.key),
+    };
+    let mint_args = MintArgs::V1 {
+        amount: 1,
+        authorization_data: None,
+    };
+    let mint_instruction = mint_ix.instruction(MintInstructionArgs { mint_args });
+
+    invoke_signed(
+        &mint_instruction,
+        &[
+            token_account.clone(),
+            token_owner.clone(),
+            metadata_account.clone(),
+            master_edition_account.clone(),
+            token_record.clone(),
+            mint_account.clone(),
+            mint_authority.clone(),
+            payer.clone(),
+            system_program.clone(),
+            sysvar_instructions.clone(),
+            token_program.clone(),
+            associated_token_program.clone(),
+            authorization_rules_program.clone(),
+            authorization_rules.clone(),
+        ],
+        signer_seeds,
+    )
+    .map_err(|e| {
+        msg!("Failed to mint programmable NFT token: {:?}", e);
+        UniversalNftError::InvalidMessage
+    })?;
+
+    Ok(())
+}
+
 /// Create master edition for NFT uniqueness using proper CPI to mpl-token-metadata
-fn create_master_edition_v3<'a>(
+pub(crate) fn create_master_edition_v3<'a>(
     master_edition_account: &AccountInfo<'a>,
     mint_account: &AccountInfo<'a>,
     mint_authority: &AccountInfo<'a>,
@@ -246,6 +560,7 @@ fn create_master_edition_v3<'a>(
     metadata_program: &AccountInfo<'a>,
     system_program: &AccountInfo<'a>,
     rent: &AccountInfo<'a>,
+    max_supply: Option<u64>,
     signer_seeds: &[&[&[u8]]],
 ) -> Result<()> {
     // Create the instruction using official Metaplex instruction builder
@@ -261,9 +576,7 @@ fn create_master_edition_v3<'a>(
         rent: Some(*rent.key),
     };
 
-    let instruction_args = CreateMasterEditionV3InstructionArgs {
-        max_supply: None, // Unlimited supply for Universal NFT
-    };
+    let instruction_args = CreateMasterEditionV3InstructionArgs { max_supply };
 
     // Build the instruction
     let instruction = create_master_edition_ix.instruction(instruction_args);
@@ -290,6 +603,127 @@ fn create_master_edition_v3<'a>(
     Ok(())
 }
 
+/// Verify an NFT's metadata as belonging to a sized Metaplex collection, signed by the
+/// collection authority (the `collection` PDA). Flips the item metadata's
+/// `collection.verified` to true and increments the collection metadata's
+/// `CollectionDetails::V1 { size }` counter, so marketplaces and the cross-chain indexer
+/// recognize it as a genuine member of the family of NFTs this program mints for a given
+/// connector. A no-op if the item is already verified.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn verify_collection_for_nft<'a>(
+    metadata_account: &AccountInfo<'a>,
+    collection_authority: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    collection_mint: &AccountInfo<'a>,
+    collection_metadata: &AccountInfo<'a>,
+    collection_master_edition: &AccountInfo<'a>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    {
+        let data = metadata_account.try_borrow_data()?;
+        if let Ok(existing) = mpl_token_metadata::accounts::Metadata::safe_deserialize(&data) {
+            if existing.collection.as_ref().is_some_and(|c| c.verified) {
+                msg!("NFT already verified in collection, skipping");
+                return Ok(());
+            }
+        }
+    }
+
+    let verify_ix = mpl_token_metadata::instructions::VerifySizedCollectionItem {
+        metadata: *metadata_account.key,
+        collection_authority: *collection_authority.key,
+        payer: *payer.key,
+        collection_mint: *collection_mint.key,
+        collection: *collection_metadata.key,
+        collection_master_edition_account: *collection_master_edition.key,
+        collection_authority_record: None,
+    };
+
+    let instruction = verify_ix.instruction(
+        mpl_token_metadata::instructions::VerifySizedCollectionItemInstructionArgs {},
+    );
+
+    invoke_signed(
+        &instruction,
+        &[
+            metadata_account.clone(),
+            collection_authority.clone(),
+            payer.clone(),
+            collection_mint.clone(),
+            collection_metadata.clone(),
+            collection_master_edition.clone(),
+        ],
+        signer_seeds,
+    )
+    .map_err(|e| {
+        msg!("Failed to verify collection item: {:?}", e);
+        UniversalNftError::InvalidMessage
+    })?;
+
+    Ok(())
+}
+
+/// Unverify an NFT's membership in a sized Metaplex collection, signed by the collection
+/// authority (the `collection` PDA). Decrements the collection metadata's
+/// `CollectionDetails::V1 { size }` counter the same way `verify_collection_for_nft`
+/// increments it, keeping the on-chain size equal to the number of currently-verified items
+/// actually living on Solana. Called when a wrapped (foreign-origin) NFT is burned on its
+/// way out via `transfer_cross_chain` - burning the token leaves its metadata account
+/// behind still claiming collection membership unless this runs. A no-op if the item is
+/// already unverified.
+pub(crate) fn unverify_collection_item_for_nft<'a>(
+    metadata_account: &AccountInfo<'a>,
+    collection_authority: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    collection_mint: &AccountInfo<'a>,
+    collection_metadata: &AccountInfo<'a>,
+    collection_master_edition: &AccountInfo<'a>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    {
+        let data = metadata_account.try_borrow_data()?;
+        if let Ok(existing) = mpl_token_metadata::accounts::Metadata::safe_deserialize(&data) {
+            if !existing.collection.as_ref().is_some_and(|c| c.verified) {
+                msg!("NFT already unverified from collection, skipping");
+                return Ok(());
+            }
+        }
+    }
+
+    let unverify_ix = mpl_token_metadata::instructions::UnverifySizedCollectionItem {
+        metadata: *metadata_account.key,
+        collection_authority: *collection_authority.key,
+        payer: *payer.key,
+        collection_mint: *collection_mint.key,
+        collection: *collection_metadata.key,
+        collection_master_edition_account: *collection_master_edition.key,
+        collection_authority_record: None,
+    };
+
+    let instruction = unverify_ix.instruction(
+        mpl_token_metadata::instructions::UnverifySizedCollectionItemInstructionArgs {},
+    );
+
+    invoke_signed(
+        &instruction,
+        &[
+            metadata_account.clone(),
+            collection_authority.clone(),
+            payer.clone(),
+            collection_mint.clone(),
+            collection_metadata.clone(),
+            collection_master_edition.clone(),
+        ],
+        signer_seeds,
+    )
+    .map_err(|e| {
+        msg!("Failed to unverify collection item: {:?}", e);
+        UniversalNftError::InvalidMessage
+    })?;
+
+    Ok(())
+}
+
 /// Derive metadata PDA for a given mint
 #[allow(dead_code)]
 pub fn derive_metadata_pda(mint: &Pubkey) -> (Pubkey, u8) {
@@ -317,6 +751,31 @@ pub fn derive_master_edition_pda(mint: &Pubkey) -> (Pubkey, u8) {
     )
 }
 
+/// Namespaced cross-chain token ID: `keccak(chain_id_le || original_mint || collection || next_token_id_le)`.
+/// Returns the full 32-byte hash alongside its first 8 bytes truncated to a `u64` - the
+/// latter is both `NftOrigin.token_id` and the `nft_origin` PDA seed, while the full hash
+/// (`NftOrigin.token_id_hash`) is what actually guarantees no collision with an ID minted
+/// by another connector (ZetaChain/EVM) sharing the same per-collection counter space.
+pub(crate) fn derive_token_id(
+    chain_id: u64,
+    original_mint: &Pubkey,
+    collection_key: &Pubkey,
+    next_token_id: u64,
+) -> ([u8; 32], u64) {
+    let mut hash_input = Vec::with_capacity(8 + 32 + 32 + 8);
+    hash_input.extend_from_slice(&chain_id.to_le_bytes());
+    hash_input.extend_from_slice(original_mint.as_ref());
+    hash_input.extend_from_slice(collection_key.as_ref());
+    hash_input.extend_from_slice(&next_token_id.to_le_bytes());
+
+    let hash = keccak::hash(&hash_input);
+    let truncated = u64::from_le_bytes([
+        hash.0[0], hash.0[1], hash.0[2], hash.0[3],
+        hash.0[4], hash.0[5], hash.0[6], hash.0[7],
+    ]);
+    (hash.0, truncated)
+}
+
 #[derive(Accounts)]
 #[instruction(name: String, symbol: String, uri: String)]
 pub struct MintNft<'info> {
@@ -357,7 +816,10 @@ pub struct MintNft<'info> {
         init,
         payer = authority,
         space = 8 + NftOrigin::INIT_SPACE,
-        seeds = [b"nft_origin", &collection.next_token_id.to_le_bytes()[..]],
+        seeds = [
+            b"nft_origin",
+            &derive_token_id(103, &nft_mint.key(), &collection.key(), collection.next_token_id).1.to_le_bytes()[..],
+        ],
         bump
     )]
     pub nft_origin: Account<'info, NftOrigin>,
@@ -391,6 +853,33 @@ pub struct MintNft<'info> {
     )]
     pub master_edition: UncheckedAccount<'info>,
 
+    /// CHECK: Metaplex collection mint this NFT is verified into - only required when
+    /// `collection.collection_mint` is set.
+    pub collection_mint: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Metadata PDA of `collection_mint`, required by `verify_collection_for_nft`
+    /// when `collection_mint` is present.
+    pub collection_metadata: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Master edition PDA of `collection_mint`, required by
+    /// `verify_collection_for_nft` when `collection_mint` is present.
+    pub collection_master_edition: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Token Auth Rules program's token-record PDA for `nft_mint`/`nft_token_account`,
+    /// required only when minting a programmable NFT (`rule_set` is `Some`).
+    pub token_record: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Token Auth Rules ruleset this pNFT enforces transfers against, required only
+    /// when `rule_set` is `Some`; must match `rule_set` exactly.
+    pub authorization_rules: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: The Token Auth Rules program itself, required only when `rule_set` is `Some`.
+    pub authorization_rules_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: The `Instructions` sysvar, required by Metaplex's `Create`/`Mint` CPIs used
+    /// only when `rule_set` is `Some`.
+    pub sysvar_instructions: Option<UncheckedAccount<'info>>,
+
     pub rent: Sysvar<'info, Rent>,
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
@@ -414,22 +903,6 @@ fn generate_temp_token_id(mint: &Pubkey, authority: &Pubkey) -> u64 {
     ])
 }
 
-/// Verify collection for an NFT (optional utility function)
-#[allow(dead_code)]
-pub fn verify_collection_for_nft(
-    collection_metadata: &AccountInfo,
-    collection_mint: &AccountInfo,
-    collection_authority: &AccountInfo,
-    nft_metadata: &AccountInfo,
-    metadata_program: &AccountInfo,
-    signer_seeds: &[&[&[u8]]],
-) -> Result<()> {
-    // This would implement collection verification using Metaplex CPI
-    // For now, this is a placeholder for future collection verification
-    msg!("Collection verification would be implemented here");
-    Ok(())
-}
-
 /// Enhanced error handling for Metaplex operations
 pub fn handle_metaplex_error(error: anchor_lang::error::Error) -> UniversalNftError {
     msg!("Metaplex operation failed: {:?}", error);
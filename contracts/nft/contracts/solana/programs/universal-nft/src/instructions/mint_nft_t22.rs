@@ -0,0 +1,210 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_2022::Token2022,
+    token_2022_extensions::{
+        spl_token_metadata_interface::state::Field,
+        token_metadata_initialize, token_metadata_update_field, TokenMetadataInitialize,
+        TokenMetadataUpdateField,
+    },
+    token_interface::{mint_to, Mint, MintTo, TokenAccount},
+};
+
+use crate::state::{Collection, NftOrigin};
+use crate::UniversalNftError;
+
+/// Alternative to `mint_nft` for connectors that don't need Metaplex compatibility: mints
+/// through SPL Token-2022's metadata-pointer + embedded token-metadata extensions instead
+/// of a separate Metaplex metadata account and master edition, cutting the account count
+/// (and rent) per NFT roughly in half. Decimals stay 0 and supply 1, same as `mint_nft`,
+/// so the mint itself is still the non-fungible token - only where its metadata lives
+/// changes.
+pub fn mint_nft_t22(
+    ctx: Context<MintNftT22>,
+    name: String,
+    symbol: String,
+    uri: String,
+    attributes: Option<Vec<(String, String)>>,
+) -> Result<()> {
+    require!(!name.is_empty() && name.len() <= 32, UniversalNftError::InvalidMessage);
+    require!(!symbol.is_empty() && symbol.len() <= 10, UniversalNftError::InvalidMessage);
+    require!(!uri.is_empty() && uri.len() <= 200, UniversalNftError::InvalidMessage);
+
+    let collection = &ctx.accounts.collection;
+    let collection_key = collection.key();
+    let collection_authority = collection.authority;
+    let collection_name = collection.name.clone();
+    let collection_bump = collection.bump;
+    let mint_pubkey = ctx.accounts.nft_mint.key();
+
+    require_keys_eq!(
+        ctx.accounts.authority.key(),
+        collection_authority,
+        UniversalNftError::InvalidSignature
+    );
+
+    let clock = Clock::get()?;
+
+    // Same namespaced scheme as `mint_nft`: keccak(chain_id || original_mint || collection ||
+    // next_token_id), so IDs minted here never collide with another connector's counter space.
+    let next_token_id = collection.next_token_id;
+    let (token_id_hash, token_id) =
+        crate::instructions::mint_nft::derive_token_id(103, &mint_pubkey, &collection_key, next_token_id);
+
+    let nft_origin_info = ctx.accounts.nft_origin.to_account_info();
+    require!(nft_origin_info.data_is_empty(), UniversalNftError::InvalidTokenId);
+
+    let nft_origin = &mut ctx.accounts.nft_origin;
+    nft_origin.token_id = token_id;
+    nft_origin.token_id_hash = token_id_hash;
+    nft_origin.collection = collection_key;
+    nft_origin.chain_of_origin = 103; // Solana devnet - adjust based on network
+    nft_origin.created_at = clock.unix_timestamp;
+    nft_origin.metadata_uri = uri.clone();
+    nft_origin.bump = ctx.bumps.nft_origin;
+    nft_origin.max_supply = None;
+    nft_origin.parent_master_mint = None;
+    nft_origin.edition_number = None;
+    nft_origin.token_program = ctx.accounts.token_program.key();
+    // Token-2022 metadata lives on the mint itself, not a Metaplex metadata PDA, so there's
+    // nothing for `on_revert` to reconstruct beyond name/symbol/uri.
+    nft_origin.name = name.clone();
+    nft_origin.symbol = symbol.clone();
+    nft_origin.seller_fee_basis_points = 0;
+    nft_origin.creators = Vec::new();
+    nft_origin.cross_chain_cycle_count = 0;
+    nft_origin.transfer_history = Vec::new();
+
+    let seeds = &[
+        b"collection",
+        collection_authority.as_ref(),
+        collection_name.as_bytes(),
+        &[collection_bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        MintTo {
+            mint: ctx.accounts.nft_mint.to_account_info(),
+            to: ctx.accounts.nft_token_account.to_account_info(),
+            authority: ctx.accounts.collection.to_account_info(),
+        },
+    );
+    mint_to(cpi_ctx.with_signer(signer_seeds), 1)?;
+
+    let metadata_cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        TokenMetadataInitialize {
+            token_program_id: ctx.accounts.token_program.to_account_info(),
+            mint: ctx.accounts.nft_mint.to_account_info(),
+            metadata: ctx.accounts.nft_mint.to_account_info(),
+            mint_authority: ctx.accounts.collection.to_account_info(),
+            update_authority: ctx.accounts.collection.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token_metadata_initialize(metadata_cpi_ctx, name.clone(), symbol.clone(), uri.clone())?;
+
+    for (key, value) in attributes.unwrap_or_default() {
+        let update_field_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TokenMetadataUpdateField {
+                token_program_id: ctx.accounts.token_program.to_account_info(),
+                metadata: ctx.accounts.nft_mint.to_account_info(),
+                update_authority: ctx.accounts.collection.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token_metadata_update_field(update_field_ctx, Field::Key(key), value)?;
+    }
+
+    let collection = &mut ctx.accounts.collection;
+    collection.increment_total_minted()?;
+    collection.increment_solana_native_count()?;
+    collection.next_token_id = collection
+        .next_token_id
+        .checked_add(1)
+        .ok_or(error!(UniversalNftError::InvalidTokenId))?;
+
+    emit!(crate::TokenMinted {
+        collection: collection_key,
+        token_id,
+        token_id_hash,
+        mint: mint_pubkey,
+        recipient: ctx.accounts.recipient.key(),
+        name,
+        uri: uri.clone(),
+        origin_chain: 103, // Solana devnet
+        is_solana_native: true,
+    });
+
+    emit!(crate::NftOriginCreated {
+        token_id,
+        token_id_hash,
+        original_mint: mint_pubkey,
+        collection: collection_key,
+        origin_chain: 103,
+        metadata_uri: uri,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MintNftT22<'info> {
+    #[account(
+        mut,
+        seeds = [b"collection", collection.authority.as_ref(), collection.name.as_bytes()],
+        bump = collection.bump
+    )]
+    pub collection: Account<'info, Collection>,
+
+    /// Authority controlling the collection and paying for the transaction
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        mint::decimals = 0,
+        mint::authority = collection,
+        mint::freeze_authority = collection,
+        mint::token_program = token_program,
+        extensions::metadata_pointer::authority = collection,
+        extensions::metadata_pointer::metadata_address = nft_mint,
+    )]
+    pub nft_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = nft_mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program,
+    )]
+    pub nft_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// NFT recipient account
+    /// CHECK: Can be any valid Solana address
+    pub recipient: UncheckedAccount<'info>,
+
+    /// NFT Origin PDA to track original mint and metadata
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + NftOrigin::INIT_SPACE,
+        seeds = [
+            b"nft_origin",
+            &crate::instructions::mint_nft::derive_token_id(103, &nft_mint.key(), &collection.key(), collection.next_token_id).1.to_le_bytes()[..],
+        ],
+        bump
+    )]
+    pub nft_origin: Account<'info, NftOrigin>,
+
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
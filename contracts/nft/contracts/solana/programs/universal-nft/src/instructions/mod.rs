@@ -1,15 +1,57 @@
+pub mod init;
 pub mod initialize_collection;
 pub mod mint_nft;
+pub mod mint_nft_t22;
 pub mod transfer_cross_chain;
 pub mod on_call;
 pub mod set_universal;
 pub mod set_connected;
 pub mod on_revert;
+pub mod admin_handoff;
+pub mod compressed_mint;
+pub mod vrf_mint;
+pub mod ruleset;
+pub mod print_edition;
+pub mod set_collection_mint;
+pub mod update_metadata;
+pub mod quote_cross_chain_fee;
+pub mod close_claim;
+pub mod bind_evm_address;
+pub mod claim_evm_escrow;
+pub mod update_tss_address;
+pub mod update_metadata_cross_chain;
+pub mod verify_collection_item;
+pub mod use_nft;
+pub mod cross_chain_bridge;
+pub mod sender_binding;
+pub mod replay_marker;
+pub mod modify_settings;
 
+pub use init::*;
 // pub use initialize_collection::*; // Removed unused import
 pub use mint_nft::*;
+pub use mint_nft_t22::*;
 pub use transfer_cross_chain::*;
 pub use on_call::*;
 pub use set_universal::*;
 pub use set_connected::*;
 pub use on_revert::*;
+pub use admin_handoff::*;
+pub use compressed_mint::*;
+pub use vrf_mint::*;
+pub use ruleset::*;
+pub use print_edition::*;
+pub use set_collection_mint::*;
+pub use update_metadata::*;
+pub use quote_cross_chain_fee::*;
+pub use close_claim::*;
+pub use bind_evm_address::*;
+pub use claim_evm_escrow::*;
+pub use update_tss_address::*;
+pub use update_metadata_cross_chain::*;
+pub use verify_collection_item::*;
+pub use use_nft::*;
+pub use cross_chain_bridge::*;
+pub use sender_binding::*;
+pub use replay_marker::*;
+pub use modify_settings::*;
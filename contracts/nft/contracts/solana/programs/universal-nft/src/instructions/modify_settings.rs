@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
-use crate::state::UniversalNftConfig;
+use crate::bridge_state::UniversalNftConfig;
 use crate::errors::Errors;
+use crate::event::SettingsModifiedEvent;
 
 #[derive(Accounts)]
 pub struct ModifySettings<'info> {
@@ -20,6 +21,13 @@ pub struct ModifySettings<'info> {
 }
 
 impl ModifySettings<'_> {
+    /// `new_admin`, `new_gateway_id` and `new_verifier` are only *proposed* here: each sets
+    /// the matching `pending_*` field on `UniversalNftConfig` and a `*_activates_at`
+    /// timestamp `admin_handoff_delay_seconds` in the future, so a compromised or
+    /// typo'd admin key can't instantly redirect the bridge. `accept_admin` (for the
+    /// admin) and `accept_gateway_update` (for the gateway/verifier) finalize these once
+    /// the timelock elapses. `pause_state` is applied immediately, since pausing is a
+    /// defensive action rather than a handoff.
     pub fn modify_program_settings(
         ctx: Context<Self>,
         new_admin: Option<Pubkey>,
@@ -28,53 +36,100 @@ impl ModifySettings<'_> {
         pause_state: Option<bool>,
     ) -> Result<()> {
         let settings = &mut ctx.accounts.config;
-        
+        let now = Clock::get()?.unix_timestamp;
+
         let previous_admin = settings.admin;
         let previous_gateway = settings.zeta_gateway_program_id;
-        let previous_verifier = settings.zeta_gateway_verifier;
-        
+
         if let Some(admin_pubkey) = new_admin {
             require!(
                 admin_pubkey != Pubkey::default(),
                 Errors::InvalidParameter
             );
-            settings.admin = Some(admin_pubkey);
-        }
-        
-        if let Some(gateway_pubkey) = new_gateway_id {
-            require!(
-                gateway_pubkey != Pubkey::default(),
-                Errors::InvalidParameter
-            );
-            
-            settings.zeta_gateway_program_id = gateway_pubkey;
+            settings.pending_admin = Some(admin_pubkey);
+            settings.pending_admin_activates_at = now + settings.admin_handoff_delay_seconds;
         }
-        
-        if let Some(verifier_pubkey) = new_verifier {
-            require!(
-                verifier_pubkey != Pubkey::default(),
-                Errors::InvalidParameter
-            );
-            
-            settings.zeta_gateway_verifier = verifier_pubkey;
+
+        if new_gateway_id.is_some() || new_verifier.is_some() {
+            if let Some(gateway_pubkey) = new_gateway_id {
+                require!(
+                    gateway_pubkey != Pubkey::default(),
+                    Errors::InvalidParameter
+                );
+                settings.pending_gateway_program_id = Some(gateway_pubkey);
+            }
+
+            if let Some(verifier_pubkey) = new_verifier {
+                require!(
+                    verifier_pubkey != Pubkey::default(),
+                    Errors::InvalidParameter
+                );
+                settings.pending_gateway_verifier = Some(verifier_pubkey);
+            }
+
+            settings.pending_gateway_activates_at = now + settings.admin_handoff_delay_seconds;
         }
-        
+
         if let Some(should_pause) = pause_state {
             settings.paused = should_pause;
         }
-        
-        msg!(
-            "Program settings successfully modified\nPrevious admin: {:?}\nNew admin: {:?}\nPrevious gateway: {}\nNew gateway: {}\nPrevious verifier: {}\nNew verifier: {}\nModified by: {}\nModification time: {}",
+
+        emit!(SettingsModifiedEvent {
             previous_admin,
-            settings.admin,
+            new_admin: settings.admin,
             previous_gateway,
-            settings.zeta_gateway_program_id,
-            previous_verifier,
-            settings.zeta_gateway_verifier,
+            new_gateway: settings.zeta_gateway_program_id,
+            paused: settings.paused,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct AcceptGatewayUpdate<'info> {
+    pub administrator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.pda_bump,
+        constraint = config.admin.is_some() @ Errors::NoAdminAuthority,
+        constraint = config.admin.unwrap() == administrator.key() @ Errors::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, UniversalNftConfig>,
+}
+
+impl AcceptGatewayUpdate<'_> {
+    /// Finalize a gateway program id / verifier change proposed by
+    /// `modify_program_settings`, once `pending_gateway_activates_at` has elapsed.
+    pub fn accept_gateway_update(ctx: Context<Self>) -> Result<()> {
+        let settings = &mut ctx.accounts.config;
+        require!(
+            settings.pending_gateway_program_id.is_some() || settings.pending_gateway_verifier.is_some(),
+            Errors::NoPendingGatewayUpdate
+        );
+        require!(
+            Clock::get()?.unix_timestamp >= settings.pending_gateway_activates_at,
+            Errors::TimelockNotElapsed
+        );
+
+        if let Some(gateway_pubkey) = settings.pending_gateway_program_id.take() {
+            settings.zeta_gateway_program_id = gateway_pubkey;
+        }
+        if let Some(verifier_pubkey) = settings.pending_gateway_verifier.take() {
+            settings.zeta_gateway_verifier = verifier_pubkey;
+        }
+        settings.pending_gateway_activates_at = 0;
+
+        msg!(
+            "Gateway update accepted by {}. Gateway: {}. Verifier: {}.",
             ctx.accounts.administrator.key(),
-            Clock::get()?.unix_timestamp
+            settings.zeta_gateway_program_id,
+            settings.zeta_gateway_verifier
         );
-        
+
         Ok(())
     }
 }
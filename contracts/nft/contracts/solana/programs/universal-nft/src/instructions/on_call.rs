@@ -0,0 +1,538 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+use anchor_lang::solana_program::secp256k1_recover::secp256k1_recover;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{mint_to, transfer_checked, Mint, MintTo, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use mpl_token_metadata::types::Creator;
+
+use crate::instructions::mint_nft::{create_master_edition_v3, create_metadata_account_v3, verify_collection_for_nft};
+use crate::state::{Claim, Collection, CustodyAccount, NftOrigin};
+use crate::{
+    decode_cross_chain_message, determine_nft_origin, find_nft_origin_pda, pubkey_to_eth_address,
+    validate_recipient_address, TokenTransferReceived, UniversalNftError, CLAIM_SEED, GATEWAY_PDA_SEED,
+    TOKEN_METADATA_PROGRAM_ID, ZETACHAIN_GATEWAY_PROGRAM_ID,
+};
+
+/// Handle an incoming cross-chain NFT transfer from the ZetaChain gateway.
+///
+/// Replay protection is the `claim` account: it's derived from `(collection, sender,
+/// source_chain_id, keccak(message))`, created with `init_if_needed` and marked `claimed`
+/// here, so a gateway redelivering the same message a second time fails with
+/// `AlreadyClaimed` instead of relying on the nonce being strictly ordered. The nonce is still recorded on `collection` as a statistic.
+///
+/// `tss_signature`/`tss_recovery_id` are ZetaChain's TSS ECDSA signature over
+/// `keccak256(message)`; recovering and comparing the signer against
+/// `collection.tss_address` gives cryptographic trust in the payload rather than relying
+/// solely on the gateway PDA checks above, mirroring the verification already done in
+/// `receive_cross_chain`.
+pub fn on_call(
+    ctx: Context<OnCall>,
+    sender: [u8; 20],
+    source_chain_id: u64,
+    message: Vec<u8>,
+    nonce: u64,
+    tss_signature: [u8; 64],
+    tss_recovery_id: u8,
+) -> Result<()> {
+    require!(
+        ctx.accounts.gateway.key() == ZETACHAIN_GATEWAY_PROGRAM_ID,
+        UniversalNftError::UnauthorizedGateway
+    );
+
+    let (expected_gateway_pda, _) =
+        Pubkey::find_program_address(&[GATEWAY_PDA_SEED], &ZETACHAIN_GATEWAY_PROGRAM_ID);
+    require!(
+        ctx.accounts.gateway_pda.key() == expected_gateway_pda,
+        UniversalNftError::UnauthorizedGateway
+    );
+    require!(
+        ctx.accounts.gateway_pda.owner == &ZETACHAIN_GATEWAY_PROGRAM_ID,
+        UniversalNftError::UnauthorizedGateway
+    );
+
+    require!(tss_recovery_id <= 3, UniversalNftError::InvalidTssSignature);
+    let message_hash = keccak::hash(&message).to_bytes();
+    let recovered_pubkey = secp256k1_recover(&message_hash, tss_recovery_id, &tss_signature)
+        .map_err(|_| UniversalNftError::InvalidTssSignature)?;
+    let recovered_address = pubkey_to_eth_address(&recovered_pubkey.0)?;
+    require!(
+        recovered_address == ctx.accounts.collection.tss_address,
+        UniversalNftError::UnauthorizedTssAddress
+    );
+
+    require!(!ctx.accounts.claim.claimed, UniversalNftError::AlreadyClaimed);
+    ctx.accounts.claim.bump = ctx.bumps.claim;
+    ctx.accounts.claim.processed_at = Clock::get()?.unix_timestamp;
+    ctx.accounts.claim.source_sender = sender;
+    ctx.accounts.claim.claimed = true;
+
+    let cross_chain_message = decode_cross_chain_message(&message)?;
+
+    let expected_recipient = ctx.accounts.recipient.key();
+    let address_binding = match &cross_chain_message.recipient {
+        crate::CrossChainAddress::Evm(evm_address) => {
+            let (expected_binding_pda, _) = crate::find_address_binding_pda(&crate::ID, evm_address);
+            require_keys_eq!(
+                ctx.accounts.address_binding.key(),
+                expected_binding_pda,
+                UniversalNftError::InvalidRecipientAddress
+            );
+            if ctx.accounts.address_binding.data_is_empty() {
+                None
+            } else {
+                let data = ctx.accounts.address_binding.try_borrow_data()?;
+                Some(
+                    crate::AddressBinding::try_deserialize(&mut &data[..])
+                        .map_err(|_| UniversalNftError::InvalidMessageFormat)?,
+                )
+            }
+        }
+        crate::CrossChainAddress::Solana(_) => None,
+    };
+    let (evm_escrow_pda, _) = match &cross_chain_message.recipient {
+        crate::CrossChainAddress::Evm(evm_address) => crate::find_evm_escrow_pda(&crate::ID, evm_address),
+        crate::CrossChainAddress::Solana(_) => (Pubkey::default(), 0),
+    };
+    validate_recipient_address(
+        &cross_chain_message.recipient.to_bytes(),
+        &expected_recipient,
+        address_binding.as_ref(),
+        &evm_escrow_pda,
+    )?;
+
+    require!(
+        cross_chain_message.token_id > 0,
+        UniversalNftError::InvalidTokenId
+    );
+    require!(
+        !cross_chain_message.uri.is_empty() && cross_chain_message.uri.len() <= 200,
+        UniversalNftError::InvalidMessage
+    );
+
+    let collection = &mut ctx.accounts.collection;
+    let collection_key = collection.key();
+
+    // The nonce is kept only as a statistic now that the claim PDA provides exactly-once
+    // delivery; still require monotonicity so it stays a useful ordering signal.
+    require!(nonce > collection.nonce, UniversalNftError::InvalidNonce);
+    collection.nonce = nonce;
+
+    let collection_authority = collection.authority;
+    let collection_name = collection.name.clone();
+    let collection_bump = collection.bump;
+
+    let seeds = &[
+        b"collection",
+        collection_authority.as_ref(),
+        collection_name.as_bytes(),
+        &[collection_bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    // `nft_origin` is created by `mint_nft`/`mint_nft_t22` for Solana-native NFTs and by
+    // this handler below for a foreign NFT's first arrival, so its existence plus
+    // `is_solana_native()` is the source of truth for whether this token_id
+    // started life here and is now returning, rather than arriving for the first time.
+    let (expected_origin_pda, origin_bump) = find_nft_origin_pda(&crate::ID, cross_chain_message.token_id);
+    require_keys_eq!(ctx.accounts.nft_origin.key(), expected_origin_pda, UniversalNftError::InvalidTokenId);
+    let mut origin_chain_of_origin: Option<u64> = None;
+    let mut origin_original_mint: Option<Pubkey> = None;
+    let is_native_return = if !crate::nft_origin_exists(&ctx.accounts.nft_origin.to_account_info()) {
+        false
+    } else {
+        let data = ctx.accounts.nft_origin.try_borrow_data()?;
+        let origin = NftOrigin::try_deserialize(&mut &data[..])
+            .map_err(|_| UniversalNftError::InvalidMessageFormat)?;
+        drop(data);
+        // The custody ATA's authority is the shared `collection` PDA, not a per-mint
+        // signer, so without this check a caller could supply an unrelated `nft_mint`
+        // and release a different native NFT's custodied token instead of this one.
+        if origin.is_solana_native() {
+            require_keys_eq!(ctx.accounts.nft_mint.key(), origin.original_mint, UniversalNftError::InvalidTokenId);
+        }
+        origin_chain_of_origin = Some(origin.chain_of_origin);
+        origin_original_mint = Some(origin.original_mint);
+        origin.is_solana_native()
+    };
+
+    if is_native_return {
+        // Released from the custody ATA it was locked into on the way out, never burned
+        // or re-minted, so the circulating supply for this mint never exceeds 1.
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.custody_token_account.to_account_info(),
+                mint: ctx.accounts.nft_mint.to_account_info(),
+                to: ctx.accounts.nft_token_account.to_account_info(),
+                authority: ctx.accounts.collection.to_account_info(),
+            },
+        );
+        transfer_checked(cpi_ctx.with_signer(signer_seeds), 1, 0)?;
+
+        // The outbound transfer decremented this when the NFT was locked into custody;
+        // it's back on Solana now, so the native count comes back up too.
+        collection.increment_solana_native_count()?;
+
+        // Leave the custody record in place (mirrors `Claim`'s "accumulate rent, nobody's
+        // forced to reclaim it" tradeoff) but flip it unlocked, since the same mint may
+        // lock and unlock again on a future round trip.
+        ctx.accounts.custody_record.locked = false;
+
+        // Record this inbound hop on the origin's transfer-history ledger. Only the
+        // native-return path has an existing `NftOrigin` to update - a foreign NFT's
+        // first arrival has none yet (see `is_native_return` above).
+        let mut data = ctx.accounts.nft_origin.try_borrow_mut_data()?;
+        let mut origin = NftOrigin::try_deserialize(&mut &data[..])
+            .map_err(|_| UniversalNftError::InvalidMessageFormat)?;
+        origin.record_transfer(
+            source_chain_id,
+            crate::state::TransferDirection::Inbound,
+            nonce,
+            Clock::get()?.unix_timestamp,
+        );
+        origin
+            .try_serialize(&mut &mut data[..])
+            .map_err(|_| UniversalNftError::InvalidMessageFormat)?;
+    } else {
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.nft_mint.to_account_info(),
+                to: ctx.accounts.nft_token_account.to_account_info(),
+                authority: ctx.accounts.collection.to_account_info(),
+            },
+        );
+        mint_to(cpi_ctx.with_signer(signer_seeds), 1)
+            .map_err(|_| UniversalNftError::TokenDoesNotExist)?;
+
+        let collection_mint_for_verification = collection.collection_mint;
+
+        // Carry the origin chain's royalty config and creator split across rather than
+        // flattening every bridged NFT to zero-royalty/no-creator.
+        let creators = if cross_chain_message.creators.is_empty() {
+            None
+        } else {
+            Some(
+                cross_chain_message
+                    .creators
+                    .iter()
+                    .map(|c| Creator {
+                        address: c.address,
+                        // The collection PDA is about to sign this very CPI via
+                        // `signer_seeds`, so Metaplex will accept it as verified; any other
+                        // listed creator hasn't signed anything here and stays unverified.
+                        verified: c.address == collection_key,
+                        share: c.share,
+                    })
+                    .collect::<Vec<_>>(),
+            )
+        };
+
+        create_metadata_account_v3(
+            &ctx.accounts.nft_metadata.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+            &ctx.accounts.collection.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.collection.to_account_info(),
+            &ctx.accounts.metadata_program.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.rent.to_account_info(),
+            if cross_chain_message.name.is_empty() {
+                "Universal NFT".to_string()
+            } else {
+                cross_chain_message.name.clone()
+            },
+            cross_chain_message.symbol.clone(),
+            cross_chain_message.uri.clone(),
+            cross_chain_message.seller_fee_basis_points,
+            creators,
+            collection_mint_for_verification,
+            cross_chain_message.uses.as_ref().map(|u| u.to_metaplex()),
+            signer_seeds,
+        )?;
+
+        create_master_edition_v3(
+            &ctx.accounts.master_edition.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+            &ctx.accounts.collection.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.nft_metadata.to_account_info(),
+            &ctx.accounts.metadata_program.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.rent.to_account_info(),
+            None,
+            signer_seeds,
+        )?;
+
+        // Verify this inbound-minted NFT into the connector's Metaplex collection, if one
+        // is configured, the same way `mint_nft`/`on_revert` do for their own mints.
+        if collection_mint_for_verification == Some(ctx.accounts.collection_mint.key()) {
+            verify_collection_for_nft(
+                &ctx.accounts.nft_metadata.to_account_info(),
+                &ctx.accounts.collection.to_account_info(),
+                &ctx.accounts.payer.to_account_info(),
+                &ctx.accounts.collection_mint.to_account_info(),
+                &ctx.accounts.collection_metadata.to_account_info(),
+                &ctx.accounts.collection_master_edition.to_account_info(),
+                signer_seeds,
+            )?;
+        }
+
+        collection.increment_total_minted()?;
+
+        // First arrival of a foreign-origin token: create its NftOrigin PDA now, at the
+        // seeds/bump already derived above, so later outbound transfers and returns look up
+        // the real recorded origin chain instead of guessing from sender byte-length and
+        // token_id ranges.
+        let rent = Rent::get()?;
+        let space = 8 + NftOrigin::INIT_SPACE;
+        let origin_token_id_bytes = cross_chain_message.token_id.to_le_bytes();
+        let origin_seeds: &[&[u8]] = &[b"nft_origin", &origin_token_id_bytes, &[origin_bump]];
+        anchor_lang::system_program::create_account(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::CreateAccount {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: ctx.accounts.nft_origin.to_account_info(),
+                },
+                &[origin_seeds],
+            ),
+            rent.minimum_balance(space),
+            space as u64,
+            &crate::ID,
+        )?;
+
+        let token_id_hash = keccak::hash(
+            &[
+                source_chain_id.to_le_bytes().as_ref(),
+                cross_chain_message.sender.to_bytes().as_ref(),
+                cross_chain_message.token_id.to_le_bytes().as_ref(),
+            ]
+            .concat(),
+        )
+        .to_bytes();
+
+        let new_origin = NftOrigin {
+            original_mint: ctx.accounts.nft_mint.key(),
+            token_id: cross_chain_message.token_id,
+            token_id_hash,
+            collection: collection_key,
+            chain_of_origin: source_chain_id,
+            created_at: Clock::get()?.unix_timestamp,
+            metadata_uri: cross_chain_message.uri.clone(),
+            bump: origin_bump,
+            max_supply: None,
+            parent_master_mint: None,
+            edition_number: None,
+            token_program: ctx.accounts.token_program.key(),
+            name: if cross_chain_message.name.is_empty() {
+                "Universal NFT".to_string()
+            } else {
+                cross_chain_message.name.clone()
+            },
+            symbol: cross_chain_message.symbol.clone(),
+            seller_fee_basis_points: cross_chain_message.seller_fee_basis_points,
+            creators: cross_chain_message.creators.clone(),
+            cross_chain_cycle_count: 0,
+            transfer_history: Vec::new(),
+            uses: cross_chain_message.uses.clone(),
+            attributes: cross_chain_message.attributes.clone(),
+            rule_set: cross_chain_message.rule_set,
+        };
+        let mut origin_data = ctx.accounts.nft_origin.try_borrow_mut_data()?;
+        new_origin
+            .try_serialize(&mut &mut origin_data[..])
+            .map_err(|_| UniversalNftError::InvalidMessageFormat)?;
+        drop(origin_data);
+
+        origin_chain_of_origin = Some(source_chain_id);
+        origin_original_mint = Some(ctx.accounts.nft_mint.key());
+
+        emit!(crate::NftOriginCreated {
+            token_id: cross_chain_message.token_id,
+            token_id_hash,
+            original_mint: ctx.accounts.nft_mint.key(),
+            collection: collection_key,
+            origin_chain: source_chain_id,
+            metadata_uri: new_origin.metadata_uri.clone(),
+        });
+    }
+
+    let (origin_chain, original_mint, is_returning) =
+        determine_nft_origin(is_native_return, origin_chain_of_origin, origin_original_mint);
+
+    emit!(TokenTransferReceived {
+        collection: collection_key,
+        token_id: cross_chain_message.token_id,
+        recipient: ctx.accounts.recipient.key(),
+        uri: cross_chain_message.uri,
+        original_sender: cross_chain_message.sender.to_bytes(),
+        nonce,
+        origin_chain,
+        original_mint,
+        is_returning,
+    });
+
+    msg!(
+        "on_call consumed message from chain {} sender {:?}",
+        source_chain_id,
+        sender
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(sender: [u8; 20], source_chain_id: u64, message: Vec<u8>)]
+pub struct OnCall<'info> {
+    #[account(
+        mut,
+        seeds = [b"collection", collection.authority.as_ref(), collection.name.as_bytes()],
+        bump = collection.bump
+    )]
+    pub collection: Account<'info, Collection>,
+
+    pub collection_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Metaplex metadata PDA of `collection_mint` - only read when
+    /// `collection.collection_mint` is set, to verify this inbound NFT's collection
+    /// membership.
+    #[account(
+        seeds = [b"metadata", TOKEN_METADATA_PROGRAM_ID.as_ref(), collection_mint.key().as_ref()],
+        bump,
+        seeds::program = TOKEN_METADATA_PROGRAM_ID
+    )]
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Master edition PDA of `collection_mint`, required by
+    /// `verify_collection_for_nft`.
+    #[account(
+        seeds = [b"metadata", TOKEN_METADATA_PROGRAM_ID.as_ref(), collection_mint.key().as_ref(), b"edition"],
+        bump,
+        seeds::program = TOKEN_METADATA_PROGRAM_ID
+    )]
+    pub collection_master_edition: UncheckedAccount<'info>,
+
+    /// Proof of consumption for this exact `(collection, sender, source_chain_id, message)`
+    /// tuple. `init_if_needed` plus the handler's own `claimed` check gives exactly-once
+    /// delivery independent of nonce, while failing a resubmission with a readable
+    /// `AlreadyClaimed` error instead of Anchor's generic re-`init` failure. Scoping the seed
+    /// to `collection` keeps two connectors' replay namespaces from colliding if they ever
+    /// happen to relay byte-identical messages.
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + Claim::INIT_SPACE,
+        seeds = [CLAIM_SEED, collection.key().as_ref(), &sender, &source_chain_id.to_le_bytes(), &keccak::hash(&message).to_bytes()],
+        bump
+    )]
+    pub claim: Account<'info, Claim>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        mint::decimals = 0,
+        mint::authority = collection,
+        mint::freeze_authority = collection,
+        mint::token_program = token_program,
+    )]
+    pub nft_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = nft_mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program,
+    )]
+    pub nft_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: `nft_origin` PDA for this token_id, manually derived and checked against
+    /// `cross_chain_message.token_id` in the handler (the seed isn't known until the
+    /// message is decoded, so it can't be a declarative `seeds` constraint here). Its
+    /// presence and `is_solana_native()` decide whether this is a native NFT returning
+    /// from custody or a foreign-origin NFT being minted for the first time - in which
+    /// case the handler creates it here rather than via a declarative `init`.
+    #[account(mut)]
+    pub nft_origin: UncheckedAccount<'info>,
+
+    /// Program-owned custody account a Solana-native NFT was locked into on its way out,
+    /// released from here on return. Unused for a foreign-origin NFT (minted fresh
+    /// instead), but `init_if_needed` so one instruction covers both paths, mirroring
+    /// `transfer_cross_chain`'s custody account on the way out.
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = nft_mint,
+        associated_token::authority = collection,
+        associated_token::token_program = token_program,
+    )]
+    pub custody_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Per-mint custody record, released (flipped unlocked) when a native NFT returns from
+    /// `custody_token_account`. Unused for a foreign-origin NFT's first arrival, but
+    /// `init_if_needed` so one instruction covers both paths, mirroring
+    /// `custody_token_account` itself.
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + CustodyAccount::INIT_SPACE,
+        seeds = [b"custody", nft_mint.key().as_ref()],
+        bump
+    )]
+    pub custody_record: Account<'info, CustodyAccount>,
+
+    /// CHECK: NFT recipient account
+    pub recipient: UncheckedAccount<'info>,
+
+    /// CHECK: `AddressBinding` PDA for the message's EVM-format recipient, manually derived
+    /// and checked in the handler (the seed - the EVM address - isn't known until the
+    /// message is decoded). Only read, never written; may not exist yet, in which case
+    /// `validate_recipient_address` falls back to requiring `recipient` to be the unbound
+    /// escrow PDA. Irrelevant and unchecked when the message's recipient is Solana-format.
+    pub address_binding: UncheckedAccount<'info>,
+
+    /// CHECK: Metadata account for the NFT - seeds enforce it's the PDA derived from
+    /// `nft_mint`.
+    #[account(
+        mut,
+        seeds = [b"metadata", TOKEN_METADATA_PROGRAM_ID.as_ref(), nft_mint.key().as_ref()],
+        bump,
+        seeds::program = TOKEN_METADATA_PROGRAM_ID
+    )]
+    pub nft_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Master edition account for the NFT - seeds enforce it's the PDA derived
+    /// from `nft_mint`.
+    #[account(
+        mut,
+        seeds = [b"metadata", TOKEN_METADATA_PROGRAM_ID.as_ref(), nft_mint.key().as_ref(), b"edition"],
+        bump,
+        seeds::program = TOKEN_METADATA_PROGRAM_ID
+    )]
+    pub master_edition: UncheckedAccount<'info>,
+
+    /// CHECK: Gateway program that calls this function
+    #[account(address = ZETACHAIN_GATEWAY_PROGRAM_ID)]
+    pub gateway: UncheckedAccount<'info>,
+
+    /// CHECK: Gateway PDA account
+    #[account(seeds = [GATEWAY_PDA_SEED], bump)]
+    pub gateway_pda: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+    /// Either the legacy SPL Token program or Token-2022 - whichever the caller passes
+    /// is what `nft_mint` is created under, same as `on_revert`'s restored mints.
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// CHECK: mpl-token-metadata program
+    #[account(address = TOKEN_METADATA_PROGRAM_ID)]
+    pub metadata_program: UncheckedAccount<'info>,
+}
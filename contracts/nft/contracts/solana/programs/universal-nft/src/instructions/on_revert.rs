@@ -1,22 +1,37 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::clock::Clock;
+use anchor_lang::solana_program::keccak;
+use anchor_lang::solana_program::secp256k1_recover::secp256k1_recover;
 use anchor_lang::solana_program::sysvar::rent::Rent;
 use anchor_lang::system_program::{Transfer, transfer};
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{mint_to, Mint, MintTo, Token, TokenAccount},
+    token_interface::{mint_to, transfer_checked, Mint, MintTo, TokenAccount, TokenInterface, TransferChecked},
 };
+use mpl_token_metadata::types::Creator;
 
+use crate::instructions::mint_nft::{create_master_edition_v3, create_metadata_account_v3, verify_collection_for_nft};
 use crate::state::{Collection, NftOrigin};
-use crate::{TokenTransferReverted, UniversalNftError, ZETACHAIN_GATEWAY_PROGRAM_ID, TOKEN_METADATA_PROGRAM_ID, GATEWAY_PDA_SEED};
+use crate::{
+    pubkey_to_eth_address, TokenTransferReverted, UniversalNftError, ZETACHAIN_GATEWAY_PROGRAM_ID,
+    TOKEN_METADATA_PROGRAM_ID, GATEWAY_PDA_SEED,
+};
 
-/// Handle failed cross-chain transfers by minting NFT back to original sender
+/// Handle failed cross-chain transfers by minting NFT back to original sender.
+///
+/// `tss_signature`/`tss_recovery_id` are ZetaChain's TSS ECDSA signature over
+/// `keccak256(borsh(token_id, uri, original_sender, refund_amount))` - there's no raw
+/// gateway message here the way `on_call` gets one, so the revert's own arguments are
+/// the signed payload. Recovering and comparing the signer against
+/// `collection.tss_address` gives the same cryptographic trust `on_call` now has.
 pub fn on_revert(
     ctx: Context<OnRevertContext>,
     token_id: u64,
     uri: String,
     original_sender: Vec<u8>,
     refund_amount: u64,
+    tss_signature: [u8; 64],
+    tss_recovery_id: u8,
 ) -> Result<()> {
 
     // Verify the caller is the gateway program and validate PDA
@@ -24,7 +39,7 @@ pub fn on_revert(
         ctx.accounts.gateway.key() == ZETACHAIN_GATEWAY_PROGRAM_ID,
         UniversalNftError::UnauthorizedGateway
     );
-    
+
     // Derive expected gateway PDA and verify it matches
     let (expected_gateway_pda, _) = Pubkey::find_program_address(
         &[GATEWAY_PDA_SEED],
@@ -34,13 +49,40 @@ pub fn on_revert(
         ctx.accounts.gateway_pda.key() == expected_gateway_pda,
         UniversalNftError::UnauthorizedGateway
     );
-    
+
     // Verify gateway PDA is owned by the gateway program
     require!(
         ctx.accounts.gateway_pda.owner == &ZETACHAIN_GATEWAY_PROGRAM_ID,
         UniversalNftError::UnauthorizedGateway
     );
-    
+
+    require!(tss_recovery_id <= 3, UniversalNftError::InvalidTssSignature);
+    let revert_payload = (token_id, uri.clone(), original_sender.clone(), refund_amount)
+        .try_to_vec()
+        .map_err(|_| UniversalNftError::InvalidMessage)?;
+    let message_hash = keccak::hash(&revert_payload).to_bytes();
+    let recovered_pubkey = secp256k1_recover(&message_hash, tss_recovery_id, &tss_signature)
+        .map_err(|_| UniversalNftError::InvalidTssSignature)?;
+    let recovered_address = pubkey_to_eth_address(&recovered_pubkey.0)?;
+    require!(
+        recovered_address == ctx.accounts.collection.tss_address,
+        UniversalNftError::UnauthorizedTssAddress
+    );
+
+    // NftOrigin records which token program actually governs this mint; an NFT that
+    // lived on Token-2022 (metadata-pointer, transfer-hook, non-transferable, ...) must
+    // be re-minted through that same program rather than always falling back to legacy.
+    // A freshly `init`-ed nft_origin has no recorded program yet - adopt the one passed
+    // in as authoritative rather than rejecting the very first revert for this token.
+    if ctx.accounts.nft_origin.token_program == Pubkey::default() {
+        ctx.accounts.nft_origin.token_program = ctx.accounts.token_program.key();
+    } else {
+        require!(
+            ctx.accounts.token_program.key() == ctx.accounts.nft_origin.token_program,
+            UniversalNftError::UnauthorizedGateway
+        );
+    }
+
     // Validate original_sender account
     require!(
         ctx.accounts.original_sender.key() != anchor_lang::solana_program::system_program::ID,
@@ -61,17 +103,7 @@ pub fn on_revert(
     let collection_name = collection.name.clone();
     let collection_bump = collection.bump;
     let collection_key = collection.key();
-    
-    // Mint the NFT back to the original sender
-    let cpi_ctx = CpiContext::new(
-        ctx.accounts.token_program.to_account_info(),
-        MintTo {
-            mint: ctx.accounts.nft_mint.to_account_info(),
-            to: ctx.accounts.nft_token_account.to_account_info(),
-            authority: ctx.accounts.collection.to_account_info(),
-        },
-    );
-    
+
     // Use collection as signer
     let seeds = &[
         b"collection",
@@ -80,8 +112,119 @@ pub fn on_revert(
         &[collection_bump],
     ];
     let signer_seeds = &[&seeds[..]];
-    
-    mint_to(cpi_ctx.with_signer(signer_seeds), 1)?;
+
+    // Native Solana NFTs were locked into custody on outbound transfer rather than
+    // burned (the program never held mint authority over them), so reverting one just
+    // releases it back out of custody - metadata/master edition were never touched.
+    // Only wrapped (foreign-originated) NFTs go through mint_to, since those are the
+    // ones that were actually burned on the way out and need their mint, metadata, and
+    // master edition all reconstructed from what was recorded on `nft_origin`.
+    if ctx.accounts.nft_origin.is_solana_native() {
+        // The custody ATA's authority is the shared `collection` PDA, not a per-mint
+        // signer, so without this check a caller could supply an unrelated `nft_mint`
+        // and release a different native NFT's custodied token instead of this one.
+        require_keys_eq!(
+            ctx.accounts.nft_mint.key(),
+            ctx.accounts.nft_origin.original_mint,
+            UniversalNftError::InvalidTokenId
+        );
+
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.custody_token_account.to_account_info(),
+                mint: ctx.accounts.nft_mint.to_account_info(),
+                to: ctx.accounts.nft_token_account.to_account_info(),
+                authority: ctx.accounts.collection.to_account_info(),
+            },
+        );
+        transfer_checked(cpi_ctx.with_signer(signer_seeds), 1, 0)?;
+
+        // The outbound transfer decremented this when the NFT went into custody; reverting
+        // brings it back onto Solana, so the count needs to come back up too.
+        ctx.accounts.collection.increment_solana_native_count()?;
+    } else {
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.nft_mint.to_account_info(),
+                to: ctx.accounts.nft_token_account.to_account_info(),
+                authority: ctx.accounts.collection.to_account_info(),
+            },
+        );
+        mint_to(cpi_ctx.with_signer(signer_seeds), 1)?;
+
+        let origin = &ctx.accounts.nft_origin;
+        let name = if origin.name.is_empty() { "Universal NFT".to_string() } else { origin.name.clone() };
+        let symbol = origin.symbol.clone();
+        let restored_uri = if origin.metadata_uri.is_empty() { uri.clone() } else { origin.metadata_uri.clone() };
+        let creators = if origin.creators.is_empty() {
+            None
+        } else {
+            Some(
+                origin
+                    .creators
+                    .iter()
+                    .map(|c| Creator {
+                        address: c.address,
+                        // The collection PDA is about to sign this very CPI via
+                        // `signer_seeds`, so Metaplex will accept it as verified; any other
+                        // listed creator hasn't signed anything here and stays unverified.
+                        verified: c.address == collection_key,
+                        share: c.share,
+                    })
+                    .collect::<Vec<_>>(),
+            )
+        };
+        let max_supply = origin.max_supply;
+
+        create_metadata_account_v3(
+            &ctx.accounts.nft_metadata.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+            &ctx.accounts.collection.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.collection.to_account_info(),
+            &ctx.accounts.metadata_program.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.rent.to_account_info(),
+            name,
+            symbol,
+            restored_uri,
+            origin.seller_fee_basis_points,
+            creators,
+            collection.collection_mint,
+            origin.uses.as_ref().map(|u| u.to_metaplex()),
+            signer_seeds,
+        )?;
+
+        create_master_edition_v3(
+            &ctx.accounts.master_edition.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+            &ctx.accounts.collection.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.nft_metadata.to_account_info(),
+            &ctx.accounts.metadata_program.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.rent.to_account_info(),
+            max_supply,
+            signer_seeds,
+        )?;
+
+        // Re-verify collection membership for the restored NFT if this connector is
+        // grouping inbound NFTs into a Metaplex collection - matches the verification
+        // `on_call` performs when an NFT is first minted in.
+        if collection.collection_mint == Some(ctx.accounts.collection_mint.key()) {
+            verify_collection_for_nft(
+                &ctx.accounts.nft_metadata.to_account_info(),
+                &ctx.accounts.collection.to_account_info(),
+                &ctx.accounts.payer.to_account_info(),
+                &ctx.accounts.collection_mint.to_account_info(),
+                &ctx.accounts.collection_metadata.to_account_info(),
+                &ctx.accounts.collection_master_edition.to_account_info(),
+                signer_seeds,
+            )?;
+        }
+    }
     
     // Handle refund if applicable using System Program CPI
     if refund_amount > 0 {
@@ -147,12 +290,31 @@ pub struct OnRevertContext<'info> {
     )]
     pub collection: Account<'info, Collection>,
     
-    pub collection_mint: Account<'info, Mint>,
-    
+    pub collection_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Metaplex metadata PDA of `collection_mint` - only read when
+    /// `collection.collection_mint` is set, to re-verify a restored NFT's collection
+    /// membership.
+    #[account(
+        seeds = [b"metadata", TOKEN_METADATA_PROGRAM_ID.as_ref(), collection_mint.key().as_ref()],
+        bump,
+        seeds::program = TOKEN_METADATA_PROGRAM_ID
+    )]
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Master edition PDA of `collection_mint`, required by
+    /// `verify_collection_for_nft`.
+    #[account(
+        seeds = [b"metadata", TOKEN_METADATA_PROGRAM_ID.as_ref(), collection_mint.key().as_ref(), b"edition"],
+        bump,
+        seeds::program = TOKEN_METADATA_PROGRAM_ID
+    )]
+    pub collection_master_edition: UncheckedAccount<'info>,
+
     /// CHECK: Gateway program that calls this function
     #[account(address = ZETACHAIN_GATEWAY_PROGRAM_ID)]
     pub gateway: UncheckedAccount<'info>,
-    
+
     /// CHECK: Gateway PDA account
     #[account(
         seeds = [GATEWAY_PDA_SEED],
@@ -168,36 +330,75 @@ pub struct OnRevertContext<'info> {
         space = 8 + NftOrigin::INIT_SPACE
     )]
     pub nft_origin: Account<'info, NftOrigin>,
-    
+
+    /// `init_if_needed` because a native Solana NFT being released from custody already
+    /// has this mint - it was never burned, only locked. Wrapped NFTs still get a fresh
+    /// mint here, same as before.
     #[account(
-        init,
+        init_if_needed,
         payer = payer,
         mint::decimals = 0,
         mint::authority = collection,
         mint::freeze_authority = collection,
+        mint::token_program = token_program,
     )]
-    pub nft_mint: Account<'info, Mint>,
-    
+    pub nft_mint: InterfaceAccount<'info, Mint>,
+
+    /// `init_if_needed` since the original sender's ATA for a native NFT may still exist
+    /// from before it transferred out.
     #[account(
-        init,
+        init_if_needed,
         payer = payer,
         associated_token::mint = nft_mint,
         associated_token::authority = original_sender,
+        associated_token::token_program = token_program,
     )]
-    pub nft_token_account: Account<'info, TokenAccount>,
-    
+    pub nft_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Custody account the NFT was locked into on outbound transfer, if it's a native
+    /// Solana NFT. `init_if_needed` so the same context also covers wrapped NFTs, which
+    /// never populate this account and go through `mint_to` instead.
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = nft_mint,
+        associated_token::authority = collection,
+        associated_token::token_program = token_program,
+    )]
+    pub custody_token_account: InterfaceAccount<'info, TokenAccount>,
+
     /// CHECK: Original sender account to receive the reverted NFT
     pub original_sender: UncheckedAccount<'info>,
-    
-    /// CHECK: Metadata account for the NFT
-    #[account(mut)]
+
+    /// CHECK: Metadata account for the NFT - seeds enforce it's the PDA derived from
+    /// `nft_mint`, so a wrapped-NFT revert can't be pointed at an unrelated account.
+    #[account(
+        mut,
+        seeds = [b"metadata", TOKEN_METADATA_PROGRAM_ID.as_ref(), nft_mint.key().as_ref()],
+        bump,
+        seeds::program = TOKEN_METADATA_PROGRAM_ID
+    )]
     pub nft_metadata: UncheckedAccount<'info>,
-    
+
+    /// CHECK: Master edition account for the NFT - seeds enforce it's the PDA derived
+    /// from `nft_mint`.
+    #[account(
+        mut,
+        seeds = [b"metadata", TOKEN_METADATA_PROGRAM_ID.as_ref(), nft_mint.key().as_ref(), b"edition"],
+        bump,
+        seeds::program = TOKEN_METADATA_PROGRAM_ID
+    )]
+    pub master_edition: UncheckedAccount<'info>,
+
     #[account(mut)]
     pub payer: Signer<'info>,
-    
+
+    pub rent: Sysvar<'info, Rent>,
+
     pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
+    /// Either the legacy SPL Token program or Token-2022, whichever `nft_origin.token_program`
+    /// records for this NFT; validated against that stored value in the handler.
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     /// CHECK: mpl-token-metadata program
     #[account(address = TOKEN_METADATA_PROGRAM_ID)]
@@ -0,0 +1,248 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{mint_to, Mint, MintTo, Token, TokenAccount},
+};
+
+use mpl_token_metadata::{
+    ID as TOKEN_METADATA_PROGRAM_ID,
+    instructions::{MintNewEditionFromMasterEditionViaToken, MintNewEditionFromMasterEditionViaTokenInstructionArgs},
+};
+
+use crate::state::{Collection, NftOrigin};
+use crate::UniversalNftError;
+
+/// Mint a numbered print from an existing master edition, mirroring Metaplex's
+/// `mint_new_edition_from_master_edition_via_token`. The master's `max_supply` (enforced by
+/// the Token Metadata program itself via the edition marker PDA) caps how many prints can
+/// ever exist; this instruction just wires up the accounts and records provenance.
+pub fn print_edition(ctx: Context<PrintEdition>, edition_number: u64) -> Result<()> {
+    let collection = &ctx.accounts.collection;
+
+    require_keys_eq!(
+        ctx.accounts.authority.key(),
+        collection.authority,
+        UniversalNftError::InvalidSignature
+    );
+
+    if let Some(max_supply) = ctx.accounts.master_nft_origin.max_supply {
+        require!(
+            edition_number >= 1 && edition_number <= max_supply,
+            UniversalNftError::EditionSupplyExceeded
+        );
+    }
+
+    let seeds = &[
+        b"collection",
+        collection.authority.as_ref(),
+        collection.name.as_bytes(),
+        &[collection.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    // Mint the single print token to the recipient before Metaplex stamps the edition.
+    mint_to(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.new_mint.to_account_info(),
+                to: ctx.accounts.new_token_account.to_account_info(),
+                authority: ctx.accounts.collection.to_account_info(),
+            },
+        )
+        .with_signer(signer_seeds),
+        1,
+    )?;
+
+    let mint_new_edition_ix = MintNewEditionFromMasterEditionViaToken {
+        new_metadata: ctx.accounts.new_metadata.key(),
+        new_edition: ctx.accounts.new_edition.key(),
+        master_edition: ctx.accounts.master_edition.key(),
+        new_mint: ctx.accounts.new_mint.key(),
+        edition_mark_pda: ctx.accounts.edition_marker.key(),
+        new_mint_authority: ctx.accounts.collection.key(),
+        payer: ctx.accounts.authority.key(),
+        token_account_owner: ctx.accounts.authority.key(),
+        token_account: ctx.accounts.master_token_account.key(),
+        new_metadata_update_authority: ctx.accounts.collection.key(),
+        metadata: ctx.accounts.master_metadata.key(),
+        token_program: spl_token::ID,
+        system_program: anchor_lang::solana_program::system_program::ID,
+        rent: Some(*ctx.accounts.rent.key),
+    };
+
+    let instruction_args = MintNewEditionFromMasterEditionViaTokenInstructionArgs {
+        mint_new_edition_from_master_edition_via_token_args:
+            mpl_token_metadata::types::MintNewEditionFromMasterEditionViaTokenArgs { edition: edition_number },
+    };
+
+    let instruction = mint_new_edition_ix.instruction(instruction_args);
+
+    invoke_signed(
+        &instruction,
+        &[
+            ctx.accounts.new_metadata.to_account_info(),
+            ctx.accounts.new_edition.to_account_info(),
+            ctx.accounts.master_edition.to_account_info(),
+            ctx.accounts.new_mint.to_account_info(),
+            ctx.accounts.edition_marker.to_account_info(),
+            ctx.accounts.collection.to_account_info(),
+            ctx.accounts.authority.to_account_info(),
+            ctx.accounts.master_token_account.to_account_info(),
+            ctx.accounts.master_metadata.to_account_info(),
+            ctx.accounts.metadata_program.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.rent.to_account_info(),
+        ],
+        signer_seeds,
+    ).map_err(|e| {
+        msg!("Failed to print edition: {:?}", e);
+        UniversalNftError::InvalidMessage
+    })?;
+
+    let clock = Clock::get()?;
+    let new_origin = &mut ctx.accounts.new_nft_origin;
+    new_origin.original_mint = ctx.accounts.new_mint.key();
+    new_origin.token_id = collection.next_token_id;
+    new_origin.collection = collection.key();
+    new_origin.chain_of_origin = ctx.accounts.master_nft_origin.chain_of_origin;
+    new_origin.created_at = clock.unix_timestamp;
+    new_origin.metadata_uri = ctx.accounts.master_nft_origin.metadata_uri.clone();
+    new_origin.bump = ctx.bumps.new_nft_origin;
+    new_origin.max_supply = None;
+    new_origin.parent_master_mint = Some(ctx.accounts.master_mint.key());
+    new_origin.edition_number = Some(edition_number);
+
+    let collection = &mut ctx.accounts.collection;
+    collection.next_token_id = collection
+        .next_token_id
+        .checked_add(1)
+        .ok_or(error!(UniversalNftError::InvalidTokenId))?;
+
+    msg!(
+        "Printed edition {} of master {}",
+        edition_number,
+        ctx.accounts.master_mint.key()
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(edition_number: u64)]
+pub struct PrintEdition<'info> {
+    #[account(
+        mut,
+        seeds = [b"collection", collection.authority.as_ref(), collection.name.as_bytes()],
+        bump = collection.bump
+    )]
+    pub collection: Account<'info, Collection>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The master edition's mint
+    pub master_mint: Account<'info, Mint>,
+
+    #[account(
+        seeds = [b"nft_origin", &master_nft_origin.token_id.to_le_bytes()[..]],
+        bump = master_nft_origin.bump,
+        constraint = master_nft_origin.original_mint == master_mint.key() @ UniversalNftError::InvalidTokenId,
+    )]
+    pub master_nft_origin: Account<'info, NftOrigin>,
+
+    /// CHECK: Metaplex master edition account, validated by the Token Metadata program CPI
+    #[account(
+        seeds = [b"metadata", TOKEN_METADATA_PROGRAM_ID.as_ref(), master_mint.key().as_ref(), b"edition"],
+        bump,
+        seeds::program = TOKEN_METADATA_PROGRAM_ID
+    )]
+    pub master_edition: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex master metadata account
+    #[account(
+        seeds = [b"metadata", TOKEN_METADATA_PROGRAM_ID.as_ref(), master_mint.key().as_ref()],
+        bump,
+        seeds::program = TOKEN_METADATA_PROGRAM_ID
+    )]
+    pub master_metadata: UncheckedAccount<'info>,
+
+    #[account(associated_token::mint = master_mint, associated_token::authority = collection)]
+    pub master_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Metaplex edition marker PDA - a bitmap of which edition numbers (grouped in
+    /// blocks of 248) have already been printed from this master edition, so a replayed
+    /// `edition_number` fails here instead of silently reprinting. Seeds derived the same
+    /// way the Token Metadata program derives them: `edition_number / 248` as a decimal
+    /// string is the final seed component.
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            TOKEN_METADATA_PROGRAM_ID.as_ref(),
+            master_mint.key().as_ref(),
+            b"edition",
+            (edition_number / 248).to_string().as_bytes(),
+        ],
+        bump,
+        seeds::program = TOKEN_METADATA_PROGRAM_ID
+    )]
+    pub edition_marker: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        mint::decimals = 0,
+        mint::authority = collection,
+        mint::freeze_authority = collection,
+    )]
+    pub new_mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = new_mint,
+        associated_token::authority = recipient,
+    )]
+    pub new_token_account: Account<'info, TokenAccount>,
+
+    /// Recipient of the printed edition.
+    /// CHECK: Can be any valid Solana address
+    pub recipient: UncheckedAccount<'info>,
+
+    /// CHECK: New Metaplex metadata account for the printed edition
+    #[account(
+        mut,
+        seeds = [b"metadata", TOKEN_METADATA_PROGRAM_ID.as_ref(), new_mint.key().as_ref()],
+        bump,
+        seeds::program = TOKEN_METADATA_PROGRAM_ID
+    )]
+    pub new_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: New Metaplex edition account for the printed edition
+    #[account(
+        mut,
+        seeds = [b"metadata", TOKEN_METADATA_PROGRAM_ID.as_ref(), new_mint.key().as_ref(), b"edition"],
+        bump,
+        seeds::program = TOKEN_METADATA_PROGRAM_ID
+    )]
+    pub new_edition: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + NftOrigin::INIT_SPACE,
+        seeds = [b"nft_origin", &collection.next_token_id.to_le_bytes()[..]],
+        bump
+    )]
+    pub new_nft_origin: Account<'info, NftOrigin>,
+
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// CHECK: mpl-token-metadata program - validated by address constraint
+    #[account(address = TOKEN_METADATA_PROGRAM_ID)]
+    pub metadata_program: UncheckedAccount<'info>,
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::state::BaseFeeState;
+use crate::{quote_gas_fee, FeeQuote, UniversalNftError};
+
+/// Preview what `transfer_cross_chain` would charge via `calculate_gas_fee`, without
+/// mutating any account - mirrors `eth_estimateGas` so a wallet can show an accurate fee
+/// and reject a transfer that would hit the `MAX_GAS_FEE` ceiling before paying for the
+/// on-chain attempt.
+pub fn quote_cross_chain_fee(
+    ctx: Context<QuoteCrossChainFee>,
+    destination_chain_id: u64,
+    gas_amount: u64,
+) -> Result<FeeQuote> {
+    let (expected_base_fee_pda, _) = Pubkey::find_program_address(
+        &[b"base_fee", destination_chain_id.to_le_bytes().as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(
+        ctx.accounts.base_fee.key(),
+        expected_base_fee_pda,
+        UniversalNftError::InvalidTokenId
+    );
+
+    let base_fee_state = if ctx.accounts.base_fee.data_is_empty() {
+        None
+    } else {
+        let data = ctx.accounts.base_fee.try_borrow_data()?;
+        Some(
+            BaseFeeState::try_deserialize(&mut &data[..])
+                .map_err(|_| UniversalNftError::InvalidMessageFormat)?,
+        )
+    };
+
+    quote_gas_fee(destination_chain_id, gas_amount, base_fee_state.as_ref())
+}
+
+#[derive(Accounts)]
+pub struct QuoteCrossChainFee<'info> {
+    /// CHECK: EIP-1559-style base fee tracker for `destination_chain_id`, read-only here.
+    /// May not exist yet (a chain with no prior transfers), in which case the quote falls
+    /// back to the static per-chain table the same way `calculate_gas_fee` does.
+    pub base_fee: UncheckedAccount<'info>,
+}
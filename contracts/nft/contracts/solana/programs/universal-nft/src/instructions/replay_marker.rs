@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::Errors, bridge_state::{ReplayMarker, REPLAY_MARKER_SEED}};
+
+/// Minimum age, in seconds, `prune_replay_markers` will accept for its caller-supplied
+/// `retention_seconds` - keeps a caller from shrinking the window enough to evict a marker
+/// a genuine redelivery could still race against.
+pub const MIN_REPLAY_MARKER_RETENTION_SECONDS: i64 = 86_400;
+
+#[derive(Accounts)]
+pub struct PruneReplayMarkers<'info> {
+    #[account(
+        mut,
+        close = rent_destination,
+        seeds = [REPLAY_MARKER_SEED, &marker.token_id, &marker.nonce.to_le_bytes()],
+        bump = marker.bump
+    )]
+    pub marker: Account<'info, ReplayMarker>,
+
+    /// CHECK: rent refund destination, caller-specified; the marker carries no value worth
+    /// protecting beyond its own rent, so any account may receive it.
+    #[account(mut)]
+    pub rent_destination: UncheckedAccount<'info>,
+}
+
+impl<'info> PruneReplayMarkers<'info> {
+    /// Permissionless: anyone can close a `ReplayMarker` once it's older than
+    /// `retention_seconds`, refunding its rent to `rent_destination`, so `ReplayMarker`
+    /// accounts don't accumulate unbounded - a relayer nonce stream gives no other way to
+    /// bound how many of them exist.
+    pub fn prune_replay_markers(ctx: Context<Self>, retention_seconds: i64) -> Result<()> {
+        require!(retention_seconds >= MIN_REPLAY_MARKER_RETENTION_SECONDS, Errors::InvalidParameter);
+
+        let now = Clock::get()?.unix_timestamp;
+        let age = now.saturating_sub(ctx.accounts.marker.created_at);
+        require!(age >= retention_seconds, Errors::TimelockNotElapsed);
+
+        msg!(
+            "Replay marker pruned: token_id {:?} nonce {} age {}s",
+            ctx.accounts.marker.token_id,
+            ctx.accounts.marker.nonce,
+            age
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,163 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{freeze_account, thaw_account, transfer, FreezeAccount, ThawAccount, Token, TokenAccount, Transfer, Mint};
+
+use crate::{
+    bridge_state::{Ruleset, UniversalNftConfig},
+    errors::Errors,
+};
+
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct RegisterRuleset<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.pda_bump,
+        constraint = config.admin == Some(authority.key()) @ Errors::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, UniversalNftConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Ruleset::INIT_SPACE,
+        seeds = [b"ruleset", name.as_bytes()],
+        bump
+    )]
+    pub ruleset: Account<'info, Ruleset>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RegisterRuleset<'info> {
+    pub fn register_ruleset(
+        ctx: Context<Self>,
+        name: String,
+        allowed_programs: Vec<Pubkey>,
+        denied_programs: Vec<Pubkey>,
+        parent: Option<Pubkey>,
+        set_as_default: bool,
+    ) -> Result<()> {
+        require!(!name.is_empty() && name.len() <= 32, Errors::InvalidParameter);
+        require!(allowed_programs.len() <= 16 && denied_programs.len() <= 16, Errors::InvalidParameter);
+
+        let ruleset = &mut ctx.accounts.ruleset;
+        ruleset.authority = ctx.accounts.authority.key();
+        ruleset.name = name;
+        ruleset.allowed_programs = allowed_programs;
+        ruleset.denied_programs = denied_programs;
+        ruleset.parent = parent;
+        ruleset.bump = ctx.bumps.ruleset;
+
+        if set_as_default {
+            ctx.accounts.config.default_ruleset = Some(ruleset.key());
+        }
+
+        msg!("Ruleset {} registered by {}", ruleset.key(), ruleset.authority);
+
+        Ok(())
+    }
+}
+
+/// Check a candidate program ID against the allow/deny lists of each ruleset account in
+/// `ruleset_accounts` (at most 8). Only the accounts supplied are checked: a ruleset's
+/// `parent` link is not followed.
+pub fn check_ruleset_chain<'info>(
+    ruleset_accounts: &[AccountInfo<'info>],
+    initiating_program: &Pubkey,
+) -> Result<()> {
+    const MAX_DEPTH: usize = 8;
+    require!(ruleset_accounts.len() <= MAX_DEPTH, Errors::InvalidParameter);
+
+    for account in ruleset_accounts {
+        let data = account.try_borrow_data()?;
+        let ruleset: Ruleset = Ruleset::try_deserialize(&mut &data[..])
+            .map_err(|_| Errors::InvalidParameter)?;
+
+        if !ruleset.denied_programs.is_empty() && ruleset.denied_programs.contains(initiating_program) {
+            return Err(Errors::UnauthorizedGateway.into());
+        }
+        if !ruleset.allowed_programs.is_empty() && !ruleset.allowed_programs.contains(initiating_program) {
+            return Err(Errors::UnauthorizedGateway.into());
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RulesetGatedTransfer<'info> {
+    #[account(seeds = [b"config"], bump = config.pda_bump)]
+    pub config: Account<'info, UniversalNftConfig>,
+
+    #[account(seeds = [b"ruleset", ruleset.name.as_bytes()], bump = ruleset.bump)]
+    pub ruleset: Account<'info, Ruleset>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub from: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub to: Account<'info, TokenAccount>,
+
+    /// CHECK: program PDA holding freeze authority over `mint`, signs the thaw/re-freeze
+    #[account(seeds = [b"freeze_authority", mint.key().as_ref()], bump)]
+    pub freeze_authority: UncheckedAccount<'info>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> RulesetGatedTransfer<'info> {
+    /// Thaw `from` (frozen by default so transfers must route through here), validate
+    /// against the ruleset's own allow/deny lists, move the token, then freeze `to`. The
+    /// ruleset's parent, if any, is not checked.
+    pub fn transfer(ctx: Context<Self>, bump: u8) -> Result<()> {
+        let mint_key = ctx.accounts.mint.key();
+        let seeds = &[b"freeze_authority".as_ref(), mint_key.as_ref(), &[bump]];
+        let signer_seeds = &[&seeds[..]];
+
+        check_ruleset_chain(&[ctx.accounts.ruleset.to_account_info()], ctx.program_id)?;
+
+        thaw_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            ThawAccount {
+                account: ctx.accounts.from.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                authority: ctx.accounts.freeze_authority.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+
+        transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.from.to_account_info(),
+                    to: ctx.accounts.to.to_account_info(),
+                    authority: ctx.accounts.owner.to_account_info(),
+                },
+            ),
+            1,
+        )?;
+
+        freeze_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            FreezeAccount {
+                account: ctx.accounts.to.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                authority: ctx.accounts.freeze_authority.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+
+        msg!("Ruleset-gated transfer of {} completed under ruleset {}", mint_key, ctx.accounts.ruleset.key());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::Errors,
+    bridge_state::SenderBinding,
+    util::inter_chain_helpers,
+};
+
+#[derive(Accounts)]
+pub struct BindSenderAddress<'info> {
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + SenderBinding::INIT_SPACE,
+        seeds = [b"sender_binding", owner.key().as_ref()],
+        bump
+    )]
+    pub binding: Account<'info, SenderBinding>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> BindSenderAddress<'info> {
+    /// Binds `owner` to `evm_sender_hex` so `CrossChainBridge::bridge_to_zetachain` can
+    /// populate the outbound message's `sender` field from it instead of a hardcoded zero
+    /// address. Callable again to rebind to a new address; `init_if_needed` means the
+    /// first call creates the PDA and later calls just overwrite it.
+    pub fn bind_sender_address(ctx: Context<Self>, evm_sender_hex: String) -> Result<()> {
+        let evm_sender = inter_chain_helpers::parse_hex_address_to_bytes(&evm_sender_hex)
+            .map_err(|_| Errors::InvalidRecipientAddress)?;
+
+        let binding = &mut ctx.accounts.binding;
+        binding.owner = ctx.accounts.owner.key();
+        binding.evm_sender = evm_sender;
+        binding.bump = ctx.bumps.binding;
+
+        msg!(
+            "Sender address bound: owner {} -> {:?}",
+            ctx.accounts.owner.key(),
+            evm_sender
+        );
+
+        Ok(())
+    }
+}
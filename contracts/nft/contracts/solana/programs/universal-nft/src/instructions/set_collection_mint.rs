@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::state::Collection;
+
+#[derive(Accounts)]
+pub struct SetCollectionMintContext<'info> {
+    #[account(
+        mut,
+        has_one = authority,
+        seeds = [b"collection", collection.authority.as_ref(), collection.name.as_bytes()],
+        bump = collection.bump
+    )]
+    pub collection: Account<'info, Collection>,
+
+    pub authority: Signer<'info>,
+
+    /// Metaplex collection mint every NFT minted for this connector will be verified
+    /// into. Not validated beyond being a mint - verification happens per-NFT against
+    /// this mint's own metadata/master edition at mint/revert time.
+    pub collection_mint: InterfaceAccount<'info, Mint>,
+}
+
+/// Set (or clear) the verified Metaplex collection a connector's inbound-minted NFTs
+/// are grouped into. `on_call` and `on_revert` verify each NFT into this collection
+/// with the collection PDA acting as collection authority.
+pub fn set_collection_mint(
+    ctx: Context<SetCollectionMintContext>,
+    collection_mint: Option<Pubkey>,
+) -> Result<()> {
+    let collection = &mut ctx.accounts.collection;
+
+    collection.validate_authority(&ctx.accounts.authority.key())?;
+
+    require!(
+        collection_mint.map_or(true, |key| key == ctx.accounts.collection_mint.key()),
+        crate::UniversalNftError::InvalidTokenId
+    );
+
+    collection.collection_mint = collection_mint;
+
+    msg!(
+        "Collection {} now grouping inbound NFTs under collection mint {:?}",
+        collection.key(),
+        collection_mint
+    );
+
+    Ok(())
+}
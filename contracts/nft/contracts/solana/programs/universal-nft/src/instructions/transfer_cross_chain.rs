@@ -7,15 +7,15 @@ use anchor_lang::solana_program::{
 };
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{burn, Burn, Mint, Token, TokenAccount},
+    token::{burn, transfer, Burn, Mint, Token, TokenAccount, Transfer},
 };
 use solana_program::program_option::COption;
 
-use crate::state::{Collection, NftOrigin, Connected, convert_address_format};
-use crate::utils::{is_supported_chain, serialize_gateway_call_data};
+use crate::state::{Collection, NftOrigin, Connected, BaseFeeState, CustodyAccount, convert_address_format};
+use crate::utils::{is_supported_chain, solana_to_ethereum_address};
 use crate::{
-    UniversalNftError, 
-    ZETACHAIN_GATEWAY_PROGRAM_ID, 
+    UniversalNftError,
+    ZETACHAIN_GATEWAY_PROGRAM_ID,
     TOKEN_METADATA_PROGRAM_ID,
     calculate_gas_fee,
     get_current_chain_id,
@@ -23,20 +23,44 @@ use crate::{
 };
 use crate::TokenTransfer;
 
+/// Default gas ZetaChain's TSS is given to run `on_revert` if the destination `on_call`
+/// aborts - generous enough to cover the mint/metadata/master-edition CPIs `on_revert`
+/// performs, and twice the 100_000 `destination_gas_limit` outbound calls in this file use.
+const ON_REVERT_GAS_LIMIT: u64 = 200_000;
+
+/// Cap on `transfer_cross_chain_with_payload`'s `app_payload`, well under the 10KB overall
+/// gateway message limit so a pathological application payload can't crowd out the
+/// origin/recipient data the message also needs to carry.
+const MAX_APP_PAYLOAD_LENGTH: usize = 4096;
+
 /// Transfer NFT cross-chain with NFT Origin system integration
 pub fn transfer_cross_chain(
     ctx: Context<TransferCrossChain>,
     destination_chain_id: u64,
     recipient: Vec<u8>,
 ) -> Result<()> {
+    // Captured before the mutable borrow below so the unverify-on-burn CPI further down can
+    // still reach the collection PDA's `AccountInfo` without re-borrowing `ctx.accounts.collection`.
+    let collection_account_info = ctx.accounts.collection.to_account_info();
     let collection = &mut ctx.accounts.collection;
     let nft_origin = &ctx.accounts.nft_origin;
     let sender = ctx.accounts.sender.key();
+    let clock = Clock::get()?;
     let collection_key = collection.key();
 
     // Validate recipient address format for destination chain
     let formatted_recipient = convert_address_format(&recipient, destination_chain_id)?;
 
+    // `convert_address_format` derives the EVM address via keccak256 when `recipient` was
+    // a 32-byte Solana key targeting an EVM chain - that derivation isn't invertible, so the
+    // original key is recorded on the transfer event itself, letting a later return transfer
+    // recover the real Solana recipient instead of trying to reverse the hash.
+    let original_solana_recipient = if recipient.len() == 32 && formatted_recipient.len() == 20 {
+        Some(Pubkey::new_from_array(recipient.as_slice().try_into().unwrap()))
+    } else {
+        None
+    };
+
     // Validate NFT ownership through token account
     require!(
         ctx.accounts.nft_token_account.amount == 1,
@@ -50,10 +74,13 @@ pub fn transfer_cross_chain(
     // Validate NFT exists in origin system (token_id 0 is valid for sequential IDs)
     // Remove over-restrictive check since token_id can be 0 for the first NFT
 
-    // Validate the NFT mint matches the origin system
+    // Validate the NFT mint matches the origin system. `nft_origin.collection ==
+    // collection_key` alone isn't enough here - every origin record in this collection
+    // satisfies it, so an `nft_origin` for an unrelated mint would slip through and this
+    // transfer would lock-or-burn based on the wrong record's `is_solana_native`/
+    // `original_mint`/`chain_of_origin`.
     require!(
-        nft_origin.original_mint == ctx.accounts.nft_mint.key() || 
-        nft_origin.collection == collection_key,
+        nft_origin.original_mint == ctx.accounts.nft_mint.key(),
         UniversalNftError::InvalidTokenId
     );
 
@@ -75,9 +102,24 @@ pub fn transfer_cross_chain(
     let is_solana_native = nft_origin.is_solana_native();
     let is_returning = destination_chain_id == origin_chain;
 
-    // Calculate gas fee for cross-chain transfer using canonical function
+    // Calculate gas fee for cross-chain transfer using canonical function. The
+    // base_fee PDA starts uninitialized (chain_id == 0) on its first use for a given
+    // destination chain, in which case the static per-chain table is used instead.
     let message_size = metadata_uri.len() + 200; // Approximate message size with overhead
-    let gas_fee = calculate_gas_fee(destination_chain_id, message_size as u64)?;
+    let base_fee_initialized = ctx.accounts.base_fee.chain_id == destination_chain_id;
+    let gas_fee = calculate_gas_fee(
+        destination_chain_id,
+        message_size as u64,
+        base_fee_initialized.then_some(&*ctx.accounts.base_fee),
+    )?;
+
+    if !base_fee_initialized {
+        ctx.accounts.base_fee.chain_id = destination_chain_id;
+        ctx.accounts.base_fee.base_fee_per_unit = gas_fee / message_size.max(1) as u64;
+        ctx.accounts.base_fee.gas_target = message_size as u64;
+        ctx.accounts.base_fee.bump = ctx.bumps.base_fee;
+    }
+    ctx.accounts.base_fee.apply_update(message_size as u64, Clock::get()?.slot)?;
 
     // Validate transfer parameters with actual gas fee
     validate_transfer_parameters(
@@ -94,6 +136,7 @@ pub fn transfer_cross_chain(
     );
 
     // Create enhanced cross-chain message with origin information
+    let sequence = collection.next_sequence()?;
     let cross_chain_message = create_cross_chain_message_with_origin(
         destination_chain_id,
         &formatted_recipient,
@@ -103,33 +146,110 @@ pub fn transfer_cross_chain(
         origin_chain,
         original_mint,
         is_solana_native,
+        &[],
+        crate::id().to_bytes(),
+        sequence,
     )?;
 
-    // Burn the NFT token
-    let _collection_authority = collection.authority;
-    let _collection_name = collection.name.clone();
-    let _collection_bump = collection.bump;
-
-    let cpi_ctx = CpiContext::new(
-        ctx.accounts.token_program.to_account_info(),
-        Burn {
-            mint: ctx.accounts.nft_mint.to_account_info(),
-            from: ctx.accounts.nft_token_account.to_account_info(),
-            authority: ctx.accounts.sender.to_account_info(),
-        },
-    );
-    burn(cpi_ctx, 1)?;
+    // Native Solana NFTs are locked into a program-owned custody account rather than
+    // burned: the program never held mint authority over an externally-minted NFT, so
+    // burn-and-re-mint silently breaks for those, and burning mutates supply history
+    // even when it does work. Only wrapped (foreign-originated) NFTs are burned here,
+    // to be re-minted on `on_call`/`on_revert` when they come back.
+    if is_solana_native {
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.nft_token_account.to_account_info(),
+                to: ctx.accounts.custody_token_account.to_account_info(),
+                authority: ctx.accounts.sender.to_account_info(),
+            },
+        );
+        transfer(cpi_ctx, 1)?;
+
+        let custody_record = &mut ctx.accounts.custody_record;
+        custody_record.mint = ctx.accounts.nft_mint.key();
+        custody_record.collection = collection_key;
+        custody_record.locked = true;
+        custody_record.locked_by = sender;
+        custody_record.locked_at = clock.unix_timestamp;
+        custody_record.bump = ctx.bumps.custody_record;
+    } else {
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.nft_mint.to_account_info(),
+                from: ctx.accounts.nft_token_account.to_account_info(),
+                authority: ctx.accounts.sender.to_account_info(),
+            },
+        );
+        burn(cpi_ctx, 1)?;
+
+        // Burning the token leaves the metadata account behind still claiming collection
+        // membership - unverify it so the collection's on-chain size stays equal to the
+        // number of verified items actually living on Solana.
+        if let Some(collection_mint_key) = collection.collection_mint {
+            let collection_mint = ctx
+                .accounts
+                .collection_mint
+                .as_ref()
+                .ok_or(UniversalNftError::InvalidMessage)?;
+            require_keys_eq!(
+                collection_mint.key(),
+                collection_mint_key,
+                UniversalNftError::InvalidMessage
+            );
+            let collection_metadata = ctx
+                .accounts
+                .collection_metadata
+                .as_ref()
+                .ok_or(UniversalNftError::InvalidMessage)?;
+            let collection_master_edition = ctx
+                .accounts
+                .collection_master_edition
+                .as_ref()
+                .ok_or(UniversalNftError::InvalidMessage)?;
 
-    // Update collection statistics when burning
+            let seeds = &[
+                b"collection",
+                collection.authority.as_ref(),
+                collection.name.as_bytes(),
+                &[collection.bump],
+            ];
+            let signer_seeds = &[&seeds[..]];
+
+            crate::instructions::mint_nft::unverify_collection_item_for_nft(
+                &ctx.accounts.nft_metadata.to_account_info(),
+                &collection_account_info,
+                &ctx.accounts.sender.to_account_info(),
+                &collection_mint.to_account_info(),
+                &collection_metadata.to_account_info(),
+                &collection_master_edition.to_account_info(),
+                signer_seeds,
+            )?;
+        }
+    }
+
+    // Update collection statistics when a Solana-native NFT leaves Solana (locked into custody above)
     // Note: We don't decrement total_minted as it represents historical count
-    // Only decrement solana_native_count if this was a Solana-native NFT leaving
     if is_solana_native && destination_chain_id != get_current_chain_id() {
         // NFT is leaving Solana for another chain
         collection.solana_native_count = collection.solana_native_count.saturating_sub(1);
     }
 
+    // No relayer-assigned nonce exists for an outbound hop, so the post-increment cycle
+    // count doubles as this record's `nonce` - see `NftTransferRecord::nonce`.
+    let nft_origin = &mut ctx.accounts.nft_origin;
+    let next_cycle = nft_origin.cross_chain_cycle_count as u64 + 1;
+    nft_origin.record_transfer(
+        destination_chain_id,
+        crate::state::TransferDirection::Outbound,
+        next_cycle,
+        clock.unix_timestamp,
+    );
+
     // Prepare enhanced gateway message for cross-chain transfer
-    let gateway_message = prepare_gateway_message(destination_chain_id, &cross_chain_message)?;
+    let gateway_message = prepare_gateway_message(destination_chain_id, &cross_chain_message, sequence)?;
 
     // Validate message size doesn't exceed limits
     require!(
@@ -139,14 +259,26 @@ pub fn transfer_cross_chain(
 
     // Call ZetaChain gateway for cross-chain transfer using proper deposit_and_call
     let destination_address = derive_destination_contract_address(&ctx.accounts.connected)?;
+
+    // If `on_call` fails on the destination chain, the NFT was already burned (or moved
+    // into custody) above with nothing left to show for it on Solana. Ship enough
+    // recovery data in `revert_options.revert_message` for `on_revert` to reconstruct and
+    // re-mint it back to `sender`, so a failed hop is recoverable instead of a silent loss.
+    let revert_options = build_revert_options(
+        token_id,
+        origin_chain,
+        original_mint,
+        &metadata_uri,
+        &sender,
+    )?;
+
     let gateway_instruction = create_gateway_instruction(
-        &ZETACHAIN_GATEWAY_PROGRAM_ID,
         &ctx.accounts.gateway_pda.key(),
         &ctx.accounts.sender.key(),
-        destination_chain_id,
-        destination_address.to_vec(),
+        destination_address,
         gateway_message,
         gas_fee,
+        revert_options,
     )?;
 
     // Execute gateway call with proper account structure - must match instruction account metas
@@ -165,7 +297,6 @@ pub fn transfer_cross_chain(
     // Note: Gas fee is automatically transferred by the gateway instruction
 
     // Emit transfer event with origin information
-    let clock = Clock::get().map_err(|_| UniversalNftError::InvalidMessage)?;
     emit!(TokenTransfer {
         collection: collection_key,
         token_id,
@@ -178,15 +309,280 @@ pub fn transfer_cross_chain(
         origin_chain,
         origin_mint: original_mint,
         is_returning,
+        original_solana_recipient,
+        sequence,
     });
 
     msg!(
-        "NFT transferred cross-chain: token_id={}, destination_chain={}, origin_chain={}, is_returning={}, gas_fee={}",
+        "NFT transferred cross-chain: token_id={}, destination_chain={}, origin_chain={}, is_returning={}, gas_fee={}, sequence={}",
         token_id,
         destination_chain_id,
         origin_chain,
         is_returning,
-        gas_fee
+        gas_fee,
+        sequence
+    );
+
+    Ok(())
+}
+
+/// Payload-3-style variant of `transfer_cross_chain`: attaches an opaque application
+/// payload and this program's own id as `sender_program`, so
+/// the destination contract can run its own receive-and-act logic (staking, listing, ...)
+/// on arrival instead of a plain ownership move. Shares `TransferCrossChain`'s accounts -
+/// no extra accounts are needed to carry a byte blob.
+pub fn transfer_cross_chain_with_payload(
+    ctx: Context<TransferCrossChain>,
+    destination_chain_id: u64,
+    recipient: Vec<u8>,
+    app_payload: Vec<u8>,
+) -> Result<()> {
+    require!(
+        app_payload.len() <= MAX_APP_PAYLOAD_LENGTH,
+        UniversalNftError::InvalidMessage
+    );
+
+    // Captured before the mutable borrow below so the unverify-on-burn CPI further down can
+    // still reach the collection PDA's `AccountInfo` without re-borrowing `ctx.accounts.collection`.
+    let collection_account_info = ctx.accounts.collection.to_account_info();
+    let collection = &mut ctx.accounts.collection;
+    let nft_origin = &ctx.accounts.nft_origin;
+    let sender = ctx.accounts.sender.key();
+    let clock = Clock::get()?;
+    let collection_key = collection.key();
+
+    let formatted_recipient = convert_address_format(&recipient, destination_chain_id)?;
+
+    let original_solana_recipient = if recipient.len() == 32 && formatted_recipient.len() == 20 {
+        Some(Pubkey::new_from_array(recipient.as_slice().try_into().unwrap()))
+    } else {
+        None
+    };
+
+    require!(
+        ctx.accounts.nft_token_account.amount == 1,
+        UniversalNftError::TokenDoesNotExist
+    );
+    require!(
+        ctx.accounts.nft_token_account.owner == sender,
+        UniversalNftError::NotTokenOwner
+    );
+
+    require!(
+        nft_origin.original_mint == ctx.accounts.nft_mint.key(),
+        UniversalNftError::InvalidTokenId
+    );
+
+    let (expected_gateway_pda, _) = Pubkey::find_program_address(
+        &[GATEWAY_PDA_SEED],
+        &ZETACHAIN_GATEWAY_PROGRAM_ID,
+    );
+    require!(
+        ctx.accounts.gateway_pda.key() == expected_gateway_pda,
+        UniversalNftError::UnauthorizedGateway
+    );
+
+    let token_id = nft_origin.token_id;
+    let origin_chain = nft_origin.chain_of_origin;
+    let original_mint = nft_origin.original_mint;
+    let metadata_uri = nft_origin.metadata_uri.clone();
+    let is_solana_native = nft_origin.is_solana_native();
+    let is_returning = destination_chain_id == origin_chain;
+
+    let message_size = metadata_uri.len() + 200 + app_payload.len();
+    let base_fee_initialized = ctx.accounts.base_fee.chain_id == destination_chain_id;
+    let gas_fee = calculate_gas_fee(
+        destination_chain_id,
+        message_size as u64,
+        base_fee_initialized.then_some(&*ctx.accounts.base_fee),
+    )?;
+
+    if !base_fee_initialized {
+        ctx.accounts.base_fee.chain_id = destination_chain_id;
+        ctx.accounts.base_fee.base_fee_per_unit = gas_fee / message_size.max(1) as u64;
+        ctx.accounts.base_fee.gas_target = message_size as u64;
+        ctx.accounts.base_fee.bump = ctx.bumps.base_fee;
+    }
+    ctx.accounts.base_fee.apply_update(message_size as u64, Clock::get()?.slot)?;
+
+    validate_transfer_parameters(
+        destination_chain_id,
+        &formatted_recipient,
+        gas_fee,
+        ctx.accounts.sender.lamports(),
+    )?;
+
+    require!(
+        ctx.accounts.connected.contract_address.len() == 20,
+        UniversalNftError::InvalidDestinationChain
+    );
+
+    let sequence = collection.next_sequence()?;
+    let cross_chain_message = create_cross_chain_message_with_origin(
+        destination_chain_id,
+        &formatted_recipient,
+        token_id,
+        &metadata_uri,
+        &sender.to_bytes(),
+        origin_chain,
+        original_mint,
+        is_solana_native,
+        &app_payload,
+        crate::id().to_bytes(),
+        sequence,
+    )?;
+
+    if is_solana_native {
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.nft_token_account.to_account_info(),
+                to: ctx.accounts.custody_token_account.to_account_info(),
+                authority: ctx.accounts.sender.to_account_info(),
+            },
+        );
+        transfer(cpi_ctx, 1)?;
+
+        let custody_record = &mut ctx.accounts.custody_record;
+        custody_record.mint = ctx.accounts.nft_mint.key();
+        custody_record.collection = collection_key;
+        custody_record.locked = true;
+        custody_record.locked_by = sender;
+        custody_record.locked_at = clock.unix_timestamp;
+        custody_record.bump = ctx.bumps.custody_record;
+    } else {
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.nft_mint.to_account_info(),
+                from: ctx.accounts.nft_token_account.to_account_info(),
+                authority: ctx.accounts.sender.to_account_info(),
+            },
+        );
+        burn(cpi_ctx, 1)?;
+
+        // Burning the token leaves the metadata account behind still claiming collection
+        // membership - unverify it so the collection's on-chain size stays equal to the
+        // number of verified items actually living on Solana.
+        if let Some(collection_mint_key) = collection.collection_mint {
+            let collection_mint = ctx
+                .accounts
+                .collection_mint
+                .as_ref()
+                .ok_or(UniversalNftError::InvalidMessage)?;
+            require_keys_eq!(
+                collection_mint.key(),
+                collection_mint_key,
+                UniversalNftError::InvalidMessage
+            );
+            let collection_metadata = ctx
+                .accounts
+                .collection_metadata
+                .as_ref()
+                .ok_or(UniversalNftError::InvalidMessage)?;
+            let collection_master_edition = ctx
+                .accounts
+                .collection_master_edition
+                .as_ref()
+                .ok_or(UniversalNftError::InvalidMessage)?;
+
+            let seeds = &[
+                b"collection",
+                collection.authority.as_ref(),
+                collection.name.as_bytes(),
+                &[collection.bump],
+            ];
+            let signer_seeds = &[&seeds[..]];
+
+            crate::instructions::mint_nft::unverify_collection_item_for_nft(
+                &ctx.accounts.nft_metadata.to_account_info(),
+                &collection_account_info,
+                &ctx.accounts.sender.to_account_info(),
+                &collection_mint.to_account_info(),
+                &collection_metadata.to_account_info(),
+                &collection_master_edition.to_account_info(),
+                signer_seeds,
+            )?;
+        }
+    }
+
+    if is_solana_native && destination_chain_id != get_current_chain_id() {
+        collection.solana_native_count = collection.solana_native_count.saturating_sub(1);
+    }
+
+    let nft_origin = &mut ctx.accounts.nft_origin;
+    let next_cycle = nft_origin.cross_chain_cycle_count as u64 + 1;
+    nft_origin.record_transfer(
+        destination_chain_id,
+        crate::state::TransferDirection::Outbound,
+        next_cycle,
+        clock.unix_timestamp,
+    );
+
+    let gateway_message = prepare_gateway_message(destination_chain_id, &cross_chain_message, sequence)?;
+
+    // Validate combined message size (origin data + app payload) doesn't exceed the limit
+    require!(
+        gateway_message.len() <= 10240, // 10KB limit
+        UniversalNftError::InvalidMessage
+    );
+
+    let destination_address = derive_destination_contract_address(&ctx.accounts.connected)?;
+
+    let revert_options = build_revert_options(
+        token_id,
+        origin_chain,
+        original_mint,
+        &metadata_uri,
+        &sender,
+    )?;
+
+    let gateway_instruction = create_gateway_instruction(
+        &ctx.accounts.gateway_pda.key(),
+        &ctx.accounts.sender.key(),
+        destination_address,
+        gateway_message,
+        gas_fee,
+        revert_options,
+    )?;
+
+    invoke_signed(
+        &gateway_instruction,
+        &[
+            ctx.accounts.sender.to_account_info(),
+            ctx.accounts.gateway_pda.to_account_info(),
+            ctx.accounts.gateway.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+        &[],
+    )?;
+
+    emit!(TokenTransfer {
+        collection: collection_key,
+        token_id,
+        destination_chain_id,
+        recipient: formatted_recipient,
+        uri: metadata_uri,
+        sender,
+        message: cross_chain_message,
+        timestamp: clock.unix_timestamp,
+        origin_chain,
+        origin_mint: original_mint,
+        is_returning,
+        original_solana_recipient,
+        sequence,
+    });
+
+    msg!(
+        "NFT transferred cross-chain with payload: token_id={}, destination_chain={}, origin_chain={}, is_returning={}, gas_fee={}, payload_len={}, sequence={}",
+        token_id,
+        destination_chain_id,
+        origin_chain,
+        is_returning,
+        gas_fee,
+        app_payload.len(),
+        sequence
     );
 
     Ok(())
@@ -254,8 +650,43 @@ fn create_cross_chain_message_with_origin(
     origin_chain: u64,
     original_mint: Pubkey,
     is_solana_native: bool,
+    app_payload: &[u8],
+    sender_program: [u8; 32],
+    sequence: u64,
 ) -> Result<Vec<u8>> {
     // Create message based on destination chain type
+    let payload = encode_cross_chain_message_payload(
+        destination_chain_id,
+        recipient,
+        token_id,
+        metadata_uri,
+        sender,
+        origin_chain,
+        original_mint,
+        is_solana_native,
+        app_payload,
+        sender_program,
+        sequence,
+    )?;
+    // Stamp the wire version the receiving `decode_cross_chain_message` expects as its
+    // leading byte, so a future format change has somewhere to go without the two sides
+    // silently disagreeing about how to read the same bytes.
+    Ok(crate::encode_cross_chain_message(payload))
+}
+
+fn encode_cross_chain_message_payload(
+    destination_chain_id: u64,
+    recipient: &[u8],
+    token_id: u64,
+    metadata_uri: &str,
+    sender: &[u8],
+    origin_chain: u64,
+    original_mint: Pubkey,
+    is_solana_native: bool,
+    app_payload: &[u8],
+    sender_program: [u8; 32],
+    sequence: u64,
+) -> Result<Vec<u8>> {
     match destination_chain_id {
         // EVM chains - use EVM message format with origin data
         1 | 56 | 137 | 8453 | 42161 | 10 | // Mainnets
@@ -274,6 +705,9 @@ fn create_cross_chain_message_with_origin(
                 origin_chain,
                 original_mint: original_mint.to_bytes(),
                 is_solana_native,
+                app_payload: app_payload.to_vec(),
+                sender_program,
+                sequence,
             };
 
             enhanced_message.try_to_vec()
@@ -303,6 +737,9 @@ fn create_cross_chain_message_with_origin(
                 origin_chain,
                 original_mint: original_mint.to_bytes(),
                 is_solana_native,
+                app_payload: app_payload.to_vec(),
+                sender_program,
+                sequence,
             };
 
             enhanced_message.try_to_vec()
@@ -319,6 +756,9 @@ fn create_cross_chain_message_with_origin(
                 origin_chain,
                 original_mint: original_mint.to_bytes(),
                 is_solana_native,
+                app_payload: app_payload.to_vec(),
+                sender_program,
+                sequence,
             };
 
             enhanced_message.try_to_vec()
@@ -328,22 +768,27 @@ fn create_cross_chain_message_with_origin(
 }
 
 /// Prepare gateway message for ZetaChain with proper formatting
-fn prepare_gateway_message(destination_chain_id: u64, message: &[u8]) -> Result<Vec<u8>> {
+fn prepare_gateway_message(destination_chain_id: u64, message: &[u8], sequence: u64) -> Result<Vec<u8>> {
     // Create a structured message for ZetaChain gateway
     let mut gateway_message = Vec::new();
-    
+
     // Add message type identifier for NFT transfer
     gateway_message.extend_from_slice(b"NFT_TRANSFER");
-    
+
     // Add destination chain ID (8 bytes)
     gateway_message.extend_from_slice(&destination_chain_id.to_le_bytes());
-    
+
     // Add message length (4 bytes)
     gateway_message.extend_from_slice(&(message.len() as u32).to_le_bytes());
-    
+
     // Add the actual message data
     gateway_message.extend_from_slice(message);
-    
+
+    // Per-collection outbound sequence number (8 bytes) - gives the destination/indexers a
+    // deterministic ordering and idempotency key, framed before the checksum so it's covered
+    // by the integrity check below like everything else in this message.
+    gateway_message.extend_from_slice(&sequence.to_le_bytes());
+
     // Add checksum for integrity verification
     let checksum = anchor_lang::solana_program::keccak::hash(&gateway_message);
     gateway_message.extend_from_slice(&checksum.to_bytes()[..4]);
@@ -352,22 +797,15 @@ fn prepare_gateway_message(destination_chain_id: u64, message: &[u8]) -> Result<
 
 /// Create proper ZetaChain gateway instruction using canonical helpers
 fn create_gateway_instruction(
-    gateway_program_id: &Pubkey,
     gateway_pda: &Pubkey,
     sender: &Pubkey,
-    destination_chain_id: u64,
-    destination_address: Vec<u8>,
+    destination_address: [u8; 20],
     message: Vec<u8>,
     gas_fee: u64,
+    revert_options: RevertOptions,
 ) -> Result<Instruction> {
-    // Use canonical gateway instruction builder if available
-    // For now, using simplified instruction format that matches gateway expectations
-    let instruction_data = serialize_gateway_call_data(
-        destination_chain_id,
-        &destination_address,
-        &message,
-        gas_fee,
-    )?;
+    let instruction_data =
+        create_deposit_and_call_data(gas_fee, destination_address, message, Some(revert_options))?;
 
     Ok(Instruction {
         program_id: ZETACHAIN_GATEWAY_PROGRAM_ID,
@@ -387,6 +825,38 @@ fn create_gateway_instruction(
     })
 }
 
+/// Build the `RevertOptions` that let `on_revert` reconstruct and re-mint this NFT if the
+/// destination chain's `on_call` aborts. `revert_address`/`abort_address` both point back
+/// at this program (in its Ethereum-style address form, since `RevertOptions` is shared
+/// wire format with EVM connectors) so ZetaChain knows who to call back.
+fn build_revert_options(
+    token_id: u64,
+    origin_chain: u64,
+    original_mint: Pubkey,
+    metadata_uri: &str,
+    solana_owner: &Pubkey,
+) -> Result<RevertOptions> {
+    let recovery_data = NftRevertRecoveryData {
+        token_id,
+        origin_chain,
+        original_mint,
+        metadata_uri: metadata_uri.to_string(),
+        solana_owner: *solana_owner,
+    }
+    .try_to_vec()
+    .map_err(|_| UniversalNftError::InvalidMessage)?;
+
+    let this_program_address = solana_to_ethereum_address(&crate::id());
+
+    Ok(RevertOptions {
+        revert_address: this_program_address,
+        call_on_revert: true,
+        abort_address: this_program_address,
+        revert_message: recovery_data,
+        on_revert_gas_limit: ON_REVERT_GAS_LIMIT,
+    })
+}
+
 /// Derive the destination contract address for the given chain
 fn derive_destination_contract_address(connected: &Connected) -> Result<[u8; 20]> {
     // Use Connected PDA to get the actual contract address for the destination chain
@@ -476,6 +946,17 @@ pub struct RevertOptions {
     pub on_revert_gas_limit: u64,
 }
 
+/// Everything `on_revert` needs to reconstruct a burned-or-custodied NFT, carried through
+/// the gateway as `RevertOptions.revert_message`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct NftRevertRecoveryData {
+    pub token_id: u64,
+    pub origin_chain: u64,
+    pub original_mint: Pubkey,
+    pub metadata_uri: String,
+    pub solana_owner: Pubkey,
+}
+
 
 /// Enhanced message structures with origin information
 
@@ -488,6 +969,12 @@ pub struct EnhancedEVMMessage {
     pub origin_chain: u64,
     pub original_mint: [u8; 32],
     pub is_solana_native: bool,
+    /// Opaque application payload - see [`EnhancedCrossChainMessage::app_payload`].
+    pub app_payload: Vec<u8>,
+    /// This program's own key - see [`EnhancedCrossChainMessage::sender_program`].
+    pub sender_program: [u8; 32],
+    /// Per-collection outbound ordering key - see [`EnhancedCrossChainMessage::sequence`].
+    pub sequence: u64,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -502,6 +989,12 @@ pub struct EnhancedZetaChainMessage {
     pub origin_chain: u64,
     pub original_mint: [u8; 32],
     pub is_solana_native: bool,
+    /// Opaque application payload - see [`EnhancedCrossChainMessage::app_payload`].
+    pub app_payload: Vec<u8>,
+    /// This program's own key - see [`EnhancedCrossChainMessage::sender_program`].
+    pub sender_program: [u8; 32],
+    /// Per-collection outbound ordering key - see [`EnhancedCrossChainMessage::sequence`].
+    pub sequence: u64,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -514,6 +1007,19 @@ pub struct EnhancedCrossChainMessage {
     pub origin_chain: u64,
     pub original_mint: [u8; 32],
     pub is_solana_native: bool,
+    /// Wormhole payload-3-style opaque application payload, carried alongside the plain
+    /// ownership transfer so the destination can run its own receive logic (staking,
+    /// listing, ...) rather than just minting. Empty for a plain `transfer_cross_chain`.
+    pub app_payload: Vec<u8>,
+    /// The invoking Solana program's own key (always this program's `crate::id()`), not
+    /// just the token owner - lets the destination trust the *caller's* identity even
+    /// though `sender` above is merely the NFT's current owner, who could be anyone.
+    pub sender_program: [u8; 32],
+    /// `collection.sequence` as of this transfer, incremented on every successful
+    /// `transfer_cross_chain`/`transfer_cross_chain_with_payload` call. Combined with the
+    /// inbound claim PDA this gives a deterministic per-collection ordering/idempotency
+    /// key, unlike `nonce` which only tracks inbound deliveries.
+    pub sequence: u64,
 }
 
 /// Account structure for TransferCrossChain instruction
@@ -530,6 +1036,7 @@ pub struct TransferCrossChain<'info> {
 
     /// NFT Origin PDA account - must exist for the NFT being transferred
     #[account(
+        mut,
         seeds = [b"nft_origin", nft_origin.token_id.to_le_bytes().as_ref()],
         bump = nft_origin.bump,
         constraint = nft_origin.collection == collection.key() @ UniversalNftError::InvalidTokenId
@@ -565,10 +1072,56 @@ pub struct TransferCrossChain<'info> {
     /// CHECK: Metaplex metadata account
     pub nft_metadata: UncheckedAccount<'info>,
 
+    /// CHECK: Metaplex collection mint this NFT is verified into - only required when
+    /// `collection.collection_mint` is set and the NFT being transferred out is burned
+    /// (wrapped/foreign-origin), so its collection size can be decremented to match.
+    pub collection_mint: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Metadata PDA of `collection_mint`, required alongside it.
+    pub collection_metadata: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Master edition PDA of `collection_mint`, required alongside it.
+    pub collection_master_edition: Option<UncheckedAccount<'info>>,
+
+    /// Program-owned custody account for native Solana NFTs leaving the chain. Unused
+    /// for wrapped NFTs (those are burned instead), but `init_if_needed` so the same
+    /// instruction works for both without a separate code path to set it up.
+    #[account(
+        init_if_needed,
+        payer = sender,
+        associated_token::mint = nft_mint,
+        associated_token::authority = collection,
+    )]
+    pub custody_token_account: Account<'info, TokenAccount>,
+
+    /// Per-mint custody record, populated when this transfer locks a native NFT into
+    /// `custody_token_account` instead of burning it. Like `custody_token_account`,
+    /// `init_if_needed` so the same instruction covers both native and wrapped NFTs - the
+    /// wrapped (burn) path just leaves it unwritten.
+    #[account(
+        init_if_needed,
+        payer = sender,
+        space = 8 + CustodyAccount::INIT_SPACE,
+        seeds = [b"custody", nft_mint.key().as_ref()],
+        bump
+    )]
+    pub custody_record: Account<'info, CustodyAccount>,
+
     /// Sender (NFT owner) account
     #[account(mut)]
     pub sender: Signer<'info>,
 
+    /// EIP-1559-style dynamic base fee for `destination_chain_id`, initialized on its
+    /// first use and updated every transfer thereafter
+    #[account(
+        init_if_needed,
+        payer = sender,
+        space = 8 + BaseFeeState::INIT_SPACE,
+        seeds = [b"base_fee", destination_chain_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub base_fee: Account<'info, BaseFeeState>,
+
     /// ZetaChain Gateway program
     /// CHECK: Gateway program for cross-chain calls
     #[account(address = ZETACHAIN_GATEWAY_PROGRAM_ID)]
@@ -7,6 +7,7 @@ use crate::{
     state::{UniversalNftConfig, TokenReservation, UniversalNftOrigin},
     errors::Errors,
     operations::{generate_token_unit_for_recipient, initialize_metadata_account, initialize_master_edition_account},
+    util::bridge_constants::SOLANA_NETWORK_ID,
 };
 
 const NFT_URI_LENGTH_LIMIT: usize = 200;
@@ -330,11 +331,14 @@ impl<'info> MintUniversalNft<'info> {
             let origin_data = UniversalNftOrigin {
                 nft_id: *nft_id,
                 original_mint: accounts.mint.key(),
+                origin_chain: SOLANA_NETWORK_ID,
                 original_metadata: accounts.metadata.key(),
                 original_uri: uri.to_string(),
                 is_on_solana: true,
                 created_at: timestamp,
+                transferred_at: None,
                 bump_seed: origin_bump,
+                transition_history: Vec::new(),
             };
 
             // Serialize full account (includes discriminator)
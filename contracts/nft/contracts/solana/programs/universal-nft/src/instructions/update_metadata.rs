@@ -0,0 +1,148 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+
+use mpl_token_metadata::{
+    ID as TOKEN_METADATA_PROGRAM_ID,
+    instructions::{UpdateMetadataAccountV2, UpdateMetadataAccountV2InstructionArgs},
+    types::DataV2,
+};
+
+use crate::state::{Collection, NftOrigin};
+use crate::UniversalNftError;
+
+/// Updates an NFT's Metaplex metadata (name/symbol/uri), signed by the collection PDA
+/// (the metadata's update authority since `mint_nft`), and keeps `NftOrigin.metadata_uri`
+/// in sync when `uri` changes. Needed for cross-chain round trips: when an NFT returns to
+/// Solana its off-chain metadata host may have changed, so the Metaplex account and the
+/// origin record must be updated together rather than drifting apart. Each provided field
+/// is validated against the same length bounds `mint_nft` enforces; omitted fields keep
+/// their current on-chain value.
+pub fn update_metadata(
+    ctx: Context<UpdateMetadata>,
+    name: Option<String>,
+    symbol: Option<String>,
+    uri: Option<String>,
+) -> Result<()> {
+    if let Some(name) = &name {
+        require!(!name.is_empty() && name.len() <= 32, UniversalNftError::InvalidMessage);
+    }
+    if let Some(symbol) = &symbol {
+        require!(!symbol.is_empty() && symbol.len() <= 10, UniversalNftError::InvalidMessage);
+    }
+    if let Some(uri) = &uri {
+        require!(!uri.is_empty() && uri.len() <= 200, UniversalNftError::InvalidMessage);
+        crate::validate_uri(uri)?;
+    }
+
+    let collection = &ctx.accounts.collection;
+    require_keys_eq!(
+        ctx.accounts.authority.key(),
+        collection.authority,
+        UniversalNftError::InvalidSignature
+    );
+
+    let existing = {
+        let data = ctx.accounts.nft_metadata.try_borrow_data()?;
+        mpl_token_metadata::accounts::Metadata::safe_deserialize(&data)
+            .map_err(|_| UniversalNftError::InvalidMessage)?
+    };
+
+    let new_data = DataV2 {
+        name: name.clone().unwrap_or(existing.name),
+        symbol: symbol.clone().unwrap_or(existing.symbol),
+        uri: uri.clone().unwrap_or(existing.uri),
+        seller_fee_basis_points: existing.seller_fee_basis_points,
+        creators: existing.creators,
+        collection: existing.collection,
+        uses: existing.uses,
+    };
+
+    let update_ix = UpdateMetadataAccountV2 {
+        metadata: ctx.accounts.nft_metadata.key(),
+        update_authority: collection.key(),
+    };
+    let instruction = update_ix.instruction(UpdateMetadataAccountV2InstructionArgs {
+        data: Some(new_data),
+        new_update_authority: None,
+        primary_sale_happened: None,
+        is_mutable: None,
+    });
+
+    let seeds = &[
+        b"collection",
+        collection.authority.as_ref(),
+        collection.name.as_bytes(),
+        &[collection.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    invoke_signed(
+        &instruction,
+        &[
+            ctx.accounts.nft_metadata.to_account_info(),
+            ctx.accounts.collection.to_account_info(),
+        ],
+        signer_seeds,
+    )
+    .map_err(|e| {
+        msg!("Failed to update metadata account: {:?}", e);
+        UniversalNftError::InvalidMessage
+    })?;
+
+    let mut updated_fields = Vec::new();
+    if name.is_some() {
+        updated_fields.push("name".to_string());
+    }
+    if symbol.is_some() {
+        updated_fields.push("symbol".to_string());
+    }
+    if let Some(uri) = uri {
+        updated_fields.push("uri".to_string());
+        ctx.accounts.nft_origin.update_metadata_uri(uri)?;
+    }
+
+    emit!(crate::NftOriginUpdated {
+        token_id: ctx.accounts.nft_origin.token_id,
+        original_mint: ctx.accounts.nft_origin.original_mint,
+        updated_fields,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateMetadata<'info> {
+    #[account(
+        seeds = [b"collection", collection.authority.as_ref(), collection.name.as_bytes()],
+        bump = collection.bump
+    )]
+    pub collection: Account<'info, Collection>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"nft_origin", nft_origin.token_id.to_le_bytes().as_ref()],
+        bump = nft_origin.bump,
+        constraint = nft_origin.collection == collection.key() @ UniversalNftError::InvalidTokenId
+    )]
+    pub nft_origin: Account<'info, NftOrigin>,
+
+    /// CHECK: Metaplex metadata account for `nft_origin.original_mint`, validated by the
+    /// Metaplex program via the CPI itself.
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            TOKEN_METADATA_PROGRAM_ID.as_ref(),
+            nft_origin.original_mint.as_ref(),
+        ],
+        bump,
+        seeds::program = TOKEN_METADATA_PROGRAM_ID
+    )]
+    pub nft_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: mpl-token-metadata program
+    #[account(address = TOKEN_METADATA_PROGRAM_ID)]
+    pub metadata_program: UncheckedAccount<'info>,
+}
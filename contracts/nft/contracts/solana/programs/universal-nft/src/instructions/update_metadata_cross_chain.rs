@@ -0,0 +1,194 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{keccak, program::invoke_signed};
+
+use mpl_token_metadata::{
+    instructions::{UpdateMetadataAccountV2, UpdateMetadataAccountV2InstructionArgs},
+    types::{Creator, DataV2},
+    ID as TOKEN_METADATA_PROGRAM_ID,
+};
+
+use crate::instructions::mint_nft::validate_metadata_data;
+use crate::instructions::transfer_cross_chain::verify_tss_signature;
+use crate::state::{Collection, NftOrigin};
+use crate::UniversalNftError;
+
+/// Borsh-encoded body of `update_metadata_cross_chain`'s `message` argument - the TSS signs
+/// over this payload's keccak hash, the same way every other inbound cross-chain call does.
+/// Kept as its own small struct rather than folded into `decode_cross_chain_message`'s
+/// ZetaChain/ABI/Borsh/legacy transfer-message dispatch, since a metadata update carries a
+/// different shape of fields than a token transfer.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MetadataUpdateMessage {
+    pub token_id: u64,
+    pub name: Option<String>,
+    pub symbol: Option<String>,
+    pub uri: Option<String>,
+    pub seller_fee_basis_points: Option<u16>,
+    pub creators: Option<Vec<Creator>>,
+    pub primary_sale_happened: Option<bool>,
+    pub is_mutable: Option<bool>,
+}
+
+/// Push a metadata change originating on another chain down to the Solana copy of an NFT,
+/// via the Metaplex `update_metadata_accounts_v2` CPI signed by the `collection` PDA (the
+/// metadata's update authority since `mint_nft`/`on_call`). Authenticated the same way
+/// `on_call` authenticates inbound transfers: the caller supplies ZetaChain's TSS ECDSA
+/// signature over `keccak256(message)`, recovered via `verify_tss_signature` and compared
+/// against `collection.tss_address`. `message` borsh-decodes into `MetadataUpdateMessage`,
+/// and `collection.nonce` is bumped the same way `on_call` bumps it so a replayed update
+/// can't be applied twice.
+pub fn update_metadata_cross_chain(
+    ctx: Context<UpdateMetadataCrossChain>,
+    message: Vec<u8>,
+    nonce: u64,
+    tss_signature: [u8; 64],
+    tss_recovery_id: u8,
+) -> Result<()> {
+    require!(tss_recovery_id <= 3, UniversalNftError::InvalidTssSignature);
+
+    let collection = &mut ctx.accounts.collection;
+
+    let message_hash = keccak::hash(&message).to_bytes();
+    let verified = verify_tss_signature(
+        &message_hash,
+        &tss_signature,
+        tss_recovery_id,
+        &collection.tss_address,
+    )?;
+    require!(verified, UniversalNftError::UnauthorizedTssAddress);
+
+    require!(nonce > collection.nonce, UniversalNftError::InvalidNonce);
+    collection.nonce = nonce;
+
+    let update = MetadataUpdateMessage::try_from_slice(&message)
+        .map_err(|_| UniversalNftError::InvalidMessage)?;
+    require!(
+        update.token_id == ctx.accounts.nft_origin.token_id,
+        UniversalNftError::InvalidTokenId
+    );
+
+    if let Some(name) = &update.name {
+        require!(!name.is_empty() && name.len() <= 32, UniversalNftError::InvalidMessage);
+    }
+    if let Some(symbol) = &update.symbol {
+        require!(!symbol.is_empty() && symbol.len() <= 10, UniversalNftError::InvalidMessage);
+    }
+    if let Some(uri) = &update.uri {
+        require!(!uri.is_empty() && uri.len() <= 200, UniversalNftError::InvalidMessage);
+        crate::validate_uri(uri)?;
+    }
+
+    let existing = {
+        let data = ctx.accounts.nft_metadata.try_borrow_data()?;
+        mpl_token_metadata::accounts::Metadata::safe_deserialize(&data)
+            .map_err(|_| UniversalNftError::InvalidMessage)?
+    };
+
+    let seller_fee_basis_points = update
+        .seller_fee_basis_points
+        .unwrap_or(existing.seller_fee_basis_points);
+    let creators = update.creators.clone().or(existing.creators);
+    validate_metadata_data(seller_fee_basis_points, &creators)?;
+
+    let new_data = DataV2 {
+        name: update.name.clone().unwrap_or(existing.name),
+        symbol: update.symbol.clone().unwrap_or(existing.symbol),
+        uri: update.uri.clone().unwrap_or(existing.uri),
+        seller_fee_basis_points,
+        creators,
+        collection: existing.collection,
+        uses: existing.uses,
+    };
+
+    let update_ix = UpdateMetadataAccountV2 {
+        metadata: ctx.accounts.nft_metadata.key(),
+        update_authority: collection.key(),
+    };
+    let instruction = update_ix.instruction(UpdateMetadataAccountV2InstructionArgs {
+        data: Some(new_data),
+        new_update_authority: None,
+        primary_sale_happened: update.primary_sale_happened,
+        is_mutable: update.is_mutable,
+    });
+
+    let seeds = &[
+        b"collection",
+        collection.authority.as_ref(),
+        collection.name.as_bytes(),
+        &[collection.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    invoke_signed(
+        &instruction,
+        &[
+            ctx.accounts.nft_metadata.to_account_info(),
+            ctx.accounts.collection.to_account_info(),
+        ],
+        signer_seeds,
+    )
+    .map_err(|e| {
+        msg!("Failed to apply cross-chain metadata update: {:?}", e);
+        UniversalNftError::InvalidMessage
+    })?;
+
+    let mut updated_fields = Vec::new();
+    if update.name.is_some() {
+        updated_fields.push("name".to_string());
+    }
+    if update.symbol.is_some() {
+        updated_fields.push("symbol".to_string());
+    }
+    if let Some(uri) = update.uri {
+        updated_fields.push("uri".to_string());
+        ctx.accounts.nft_origin.update_metadata_uri(uri)?;
+    }
+
+    emit!(crate::NftOriginUpdated {
+        token_id: ctx.accounts.nft_origin.token_id,
+        original_mint: ctx.accounts.nft_origin.original_mint,
+        updated_fields,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateMetadataCrossChain<'info> {
+    #[account(
+        mut,
+        seeds = [b"collection", collection.authority.as_ref(), collection.name.as_bytes()],
+        bump = collection.bump
+    )]
+    pub collection: Account<'info, Collection>,
+
+    #[account(
+        mut,
+        seeds = [b"nft_origin", nft_origin.token_id.to_le_bytes().as_ref()],
+        bump = nft_origin.bump,
+        constraint = nft_origin.collection == collection.key() @ UniversalNftError::InvalidTokenId
+    )]
+    pub nft_origin: Account<'info, NftOrigin>,
+
+    /// CHECK: Metaplex metadata account for `nft_origin.original_mint`, validated by the
+    /// Metaplex program via the CPI itself.
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            TOKEN_METADATA_PROGRAM_ID.as_ref(),
+            nft_origin.original_mint.as_ref(),
+        ],
+        bump,
+        seeds::program = TOKEN_METADATA_PROGRAM_ID
+    )]
+    pub nft_metadata: UncheckedAccount<'info>,
+
+    /// Whoever relays this TSS-signed update - not trusted itself, since authorization
+    /// comes entirely from the TSS signature check above.
+    pub payer: Signer<'info>,
+
+    /// CHECK: mpl-token-metadata program
+    #[account(address = TOKEN_METADATA_PROGRAM_ID)]
+    pub metadata_program: UncheckedAccount<'info>,
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Collection;
+
+#[derive(Accounts)]
+pub struct UpdateTssAddressContext<'info> {
+    #[account(
+        mut,
+        has_one = authority,
+        seeds = [b"collection", collection.authority.as_ref(), collection.name.as_bytes()],
+        bump = collection.bump
+    )]
+    pub collection: Account<'info, Collection>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Rotate the ZetaChain TSS ECDSA address `on_call`/`on_revert` recover inbound message
+/// signatures against. Needed if ZetaChain ever re-keys its TSS committee - otherwise every
+/// inbound message on this connector would start failing `UnauthorizedTssAddress`.
+pub fn update_tss_address(
+    ctx: Context<UpdateTssAddressContext>,
+    tss_address: [u8; 20],
+) -> Result<()> {
+    let collection = &mut ctx.accounts.collection;
+
+    collection.validate_authority(&ctx.accounts.authority.key())?;
+
+    collection.tss_address = tss_address;
+
+    msg!(
+        "Collection {} TSS address rotated to {:?}",
+        collection.key(),
+        tss_address
+    );
+
+    Ok(())
+}
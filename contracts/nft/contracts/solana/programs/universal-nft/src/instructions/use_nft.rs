@@ -0,0 +1,170 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{burn, close_account, Burn, CloseAccount, Mint, Token, TokenAccount},
+};
+
+use mpl_token_metadata::{
+    instructions::{UpdateMetadataAccountV2, UpdateMetadataAccountV2InstructionArgs},
+    types::{DataV2, UseMethod, Uses},
+    ID as TOKEN_METADATA_PROGRAM_ID,
+};
+
+use crate::state::{Collection, NftOrigin, NftUseMethod, NftUses};
+use crate::UniversalNftError;
+
+/// Consume one use of a limited-use (utility) NFT, enforcing the `Uses` semantics Metaplex
+/// itself only stores but never enforces: `Single`/`Multiple` just decrement `remaining`
+/// until it hits zero, while `Burn` additionally burns the token and closes its account
+/// once the last use is spent, since a used-up burn-type utility NFT has nothing left to
+/// hold onto. `NftOrigin.uses` is kept in sync so the remaining-uses count survives an
+/// outbound `transfer_cross_chain` without a second metadata read.
+pub fn use_nft(ctx: Context<UseNft>) -> Result<()> {
+    require!(
+        ctx.accounts.nft_token_account.amount == 1,
+        UniversalNftError::NotTokenOwner
+    );
+
+    let existing = {
+        let data = ctx.accounts.nft_metadata.try_borrow_data()?;
+        mpl_token_metadata::accounts::Metadata::safe_deserialize(&data)
+            .map_err(|_| UniversalNftError::InvalidMessage)?
+    };
+
+    let uses = existing.uses.ok_or(UniversalNftError::NoUsesRemaining)?;
+    require!(uses.remaining > 0, UniversalNftError::NoUsesRemaining);
+
+    let remaining = uses.remaining - 1;
+    let new_data = DataV2 {
+        name: existing.name,
+        symbol: existing.symbol,
+        uri: existing.uri,
+        seller_fee_basis_points: existing.seller_fee_basis_points,
+        creators: existing.creators,
+        collection: existing.collection,
+        uses: Some(Uses {
+            use_method: uses.use_method,
+            remaining,
+            total: uses.total,
+        }),
+    };
+
+    let collection = &ctx.accounts.collection;
+    let seeds = &[
+        b"collection",
+        collection.authority.as_ref(),
+        collection.name.as_bytes(),
+        &[collection.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let update_ix = UpdateMetadataAccountV2 {
+        metadata: ctx.accounts.nft_metadata.key(),
+        update_authority: collection.key(),
+    };
+    let instruction = update_ix.instruction(UpdateMetadataAccountV2InstructionArgs {
+        data: Some(new_data),
+        new_update_authority: None,
+        primary_sale_happened: None,
+        is_mutable: None,
+    });
+
+    invoke_signed(
+        &instruction,
+        &[
+            ctx.accounts.nft_metadata.to_account_info(),
+            ctx.accounts.collection.to_account_info(),
+        ],
+        signer_seeds,
+    )
+    .map_err(|e| {
+        msg!("Failed to record NFT use: {:?}", e);
+        UniversalNftError::InvalidMessage
+    })?;
+
+    ctx.accounts.nft_origin.uses = Some(NftUses {
+        use_method: NftUseMethod::from_metaplex(uses.use_method),
+        remaining,
+        total: uses.total,
+    });
+
+    let mut burned = false;
+    if uses.use_method == UseMethod::Burn && remaining == 0 {
+        burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.nft_mint.to_account_info(),
+                    from: ctx.accounts.nft_token_account.to_account_info(),
+                    authority: ctx.accounts.owner.to_account_info(),
+                },
+            ),
+            1,
+        )?;
+
+        close_account(CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.nft_token_account.to_account_info(),
+                destination: ctx.accounts.owner.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ))?;
+
+        burned = true;
+    }
+
+    emit!(crate::NftUsed {
+        token_id: ctx.accounts.nft_origin.token_id,
+        original_mint: ctx.accounts.nft_origin.original_mint,
+        remaining,
+        burned,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UseNft<'info> {
+    #[account(
+        seeds = [b"collection", collection.authority.as_ref(), collection.name.as_bytes()],
+        bump = collection.bump
+    )]
+    pub collection: Account<'info, Collection>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"nft_origin", nft_origin.token_id.to_le_bytes().as_ref()],
+        bump = nft_origin.bump,
+        constraint = nft_origin.collection == collection.key() @ UniversalNftError::InvalidTokenId,
+        constraint = nft_origin.original_mint == nft_mint.key() @ UniversalNftError::InvalidTokenId,
+    )]
+    pub nft_origin: Account<'info, NftOrigin>,
+
+    pub nft_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = nft_mint,
+        associated_token::authority = owner,
+    )]
+    pub nft_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Metaplex metadata account for `nft_mint`, validated by the Metaplex CPI.
+    #[account(
+        mut,
+        seeds = [b"metadata", TOKEN_METADATA_PROGRAM_ID.as_ref(), nft_mint.key().as_ref()],
+        bump,
+        seeds::program = TOKEN_METADATA_PROGRAM_ID
+    )]
+    pub nft_metadata: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// CHECK: mpl-token-metadata program - validated by address constraint
+    #[account(address = TOKEN_METADATA_PROGRAM_ID)]
+    pub metadata_program: UncheckedAccount<'info>,
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use mpl_token_metadata::ID as TOKEN_METADATA_PROGRAM_ID;
+
+use crate::instructions::mint_nft::verify_collection_for_nft;
+use crate::state::Collection;
+use crate::UniversalNftError;
+
+#[derive(Accounts)]
+pub struct VerifyCollectionItemContext<'info> {
+    #[account(
+        has_one = authority,
+        seeds = [b"collection", collection.authority.as_ref(), collection.name.as_bytes()],
+        bump = collection.bump
+    )]
+    pub collection: Account<'info, Collection>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Metadata account of the NFT being verified - validated by the Metaplex CPI.
+    #[account(mut)]
+    pub nft_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex collection mint this NFT should be verified into - must match
+    /// `collection.collection_mint`.
+    pub collection_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Metadata PDA of `collection_mint`, validated by the Metaplex CPI.
+    #[account(mut)]
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Master edition PDA of `collection_mint`, validated by the Metaplex CPI.
+    pub collection_master_edition: UncheckedAccount<'info>,
+
+    /// CHECK: mpl-token-metadata program - validated by address constraint
+    #[account(address = TOKEN_METADATA_PROGRAM_ID)]
+    pub metadata_program: UncheckedAccount<'info>,
+}
+
+/// Retroactively verify an already-minted NFT's membership in its connector's Metaplex
+/// collection - for NFTs minted (or bridged in via `on_call`/`on_revert`) before
+/// `collection.collection_mint` was set, or where inline verification was skipped for any
+/// other reason. Thin standalone wrapper around the same `verify_collection_for_nft` CPI
+/// `mint_nft`/`on_call`/`on_revert` already run inline at mint time, signed by the same
+/// `collection` PDA authority.
+pub fn verify_collection_item(ctx: Context<VerifyCollectionItemContext>) -> Result<()> {
+    let collection = &ctx.accounts.collection;
+
+    let collection_mint_key = collection
+        .collection_mint
+        .ok_or(UniversalNftError::InvalidMessage)?;
+    require_keys_eq!(
+        ctx.accounts.collection_mint.key(),
+        collection_mint_key,
+        UniversalNftError::InvalidMessage
+    );
+
+    let seeds = &[
+        b"collection",
+        collection.authority.as_ref(),
+        collection.name.as_bytes(),
+        &[collection.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    verify_collection_for_nft(
+        &ctx.accounts.nft_metadata.to_account_info(),
+        &ctx.accounts.collection.to_account_info(),
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.collection_mint.to_account_info(),
+        &ctx.accounts.collection_metadata.to_account_info(),
+        &ctx.accounts.collection_master_edition.to_account_info(),
+        signer_seeds,
+    )?;
+
+    msg!(
+        "NFT {} verified into collection mint {}",
+        ctx.accounts.nft_metadata.key(),
+        collection_mint_key
+    );
+
+    Ok(())
+}
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    bridge_state::{RandomnessRequest, UniversalNftConfig},
+    errors::Errors,
+};
+
+#[derive(Accounts)]
+pub struct RequestNftRandomness<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.pda_bump,
+        constraint = config.randomness_oracle.is_some() @ Errors::InvalidParameter
+    )]
+    pub config: Account<'info, UniversalNftConfig>,
+
+    #[account(
+        init,
+        payer = requester,
+        space = 8 + RandomnessRequest::INIT_SPACE,
+        seeds = [b"randomness_request", requester.key().as_ref(), &config.message_sequence.to_le_bytes()],
+        bump
+    )]
+    pub request: Account<'info, RandomnessRequest>,
+
+    #[account(mut)]
+    pub requester: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RequestNftRandomness<'info> {
+    /// Phase one of the VRF mint flow: derive a seed from `message_sequence` + the
+    /// current slot and record it so it can be fulfilled by the oracle in phase two.
+    pub fn request_nft_randomness(ctx: Context<Self>) -> Result<()> {
+        require!(!ctx.accounts.config.paused, Errors::ProgramPaused);
+
+        let slot = Clock::get()?.slot;
+        let config = &mut ctx.accounts.config;
+        let sequence = config.message_sequence;
+
+        let mut hasher = anchor_lang::solana_program::hash::Hasher::default();
+        hasher.hash(&sequence.to_le_bytes());
+        hasher.hash(&slot.to_le_bytes());
+        hasher.hash(ctx.accounts.requester.key().as_ref());
+        let seed = hasher.result().to_bytes();
+
+        config.message_sequence = sequence.checked_add(1).ok_or(Errors::InvalidParameter)?;
+
+        let request = &mut ctx.accounts.request;
+        request.seed = seed;
+        request.requester = ctx.accounts.requester.key();
+        request.fulfilled = false;
+        request.randomness = [0u8; 64];
+        request.bump = ctx.bumps.request;
+
+        msg!("Randomness requested by {} with seed {:?}", request.requester, seed);
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct FulfillNftRandomness<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.pda_bump,
+        constraint = config.randomness_oracle == Some(oracle.key()) @ Errors::InvalidParameter
+    )]
+    pub config: Account<'info, UniversalNftConfig>,
+
+    #[account(
+        mut,
+        constraint = !request.fulfilled @ Errors::InvalidParameter
+    )]
+    pub request: Account<'info, RandomnessRequest>,
+
+    /// CHECK: ORAO-style randomness oracle account; its 64-byte fulfilled randomness is
+    /// read directly from account data and must be non-zero to be accepted
+    pub oracle: UncheckedAccount<'info>,
+}
+
+impl<'info> FulfillNftRandomness<'info> {
+    /// Phase two of the VRF mint flow: read the fulfilled randomness from the oracle
+    /// account, assert it is non-zero, and fold it into the request for use as the
+    /// assigned `nft_id`/trait rolls.
+    pub fn fulfill_nft_randomness(ctx: Context<Self>) -> Result<u64> {
+        let data = ctx.accounts.oracle.data.borrow();
+        require!(data.len() >= 64, Errors::InvalidParameter);
+
+        let mut randomness = [0u8; 64];
+        randomness.copy_from_slice(&data[data.len() - 64..]);
+        require!(randomness != [0u8; 64], Errors::InvalidParameter);
+
+        let request = &mut ctx.accounts.request;
+        request.randomness = randomness;
+        request.fulfilled = true;
+
+        let mut seed_bytes = [0u8; 8];
+        seed_bytes.copy_from_slice(&randomness[0..8]);
+        let assigned_nft_id = u64::from_le_bytes(seed_bytes) ^ u64::from_le_bytes(
+            request.seed[0..8].try_into().unwrap(),
+        );
+
+        msg!("Randomness fulfilled for {}; assigned nft_id {}", request.requester, assigned_nft_id);
+
+        Ok(assigned_nft_id)
+    }
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use mpl_token_metadata::types::{UseMethod as MetaplexUseMethod, Uses as MetaplexUses};
 
 #[account]
 #[derive(InitSpace)]
@@ -17,20 +18,183 @@ pub struct Collection {
     pub nonce: u64, // Replay protection counter
     pub total_minted: u64, // Track total NFTs minted
     pub solana_native_count: u64, // Track Solana-native NFTs
+    /// Monotonic counter incremented on every successful `transfer_cross_chain`, stamped
+    /// into the outbound message and the framed gateway message as a per-collection
+    /// sequence number - gives the destination/indexers a canonical ordering and
+    /// idempotency key independent of `nonce`, which only tracks inbound deliveries.
+    pub sequence: u64,
     pub bump: u8,
+    /// Verified Metaplex collection every NFT this program mints for this connector is
+    /// grouped into, so wallets/marketplaces can recognize the bridged family as
+    /// authentic. `None` means NFTs mint without collection membership (unchanged
+    /// behavior).
+    pub collection_mint: Option<Pubkey>,
 }
 
 #[account]
 #[derive(InitSpace)]
 pub struct NftOrigin {
     pub original_mint: Pubkey, // Stores the original mint key
-    pub token_id: u64, // The universal token ID
+    pub token_id: u64, // The universal token ID - truncated from `token_id_hash`, also the PDA seed
+    /// Full namespaced token ID: keccak(chain_id || original_mint || collection || next_token_id).
+    /// `token_id` is this hash's first 8 bytes; the full hash is kept so the namespace (chain id
+    /// and collection) that produced a given `token_id` can be recovered, since a u64 alone
+    /// can't prove which connector's counter space it came from. For a foreign-origin NFT
+    /// recorded on first arrival by `on_call`/`receive_cross_chain`, it is instead
+    /// keccak(source_chain_id || sender || token_id), and `token_id` is the message's own id.
+    pub token_id_hash: [u8; 32],
     pub collection: Pubkey, // Reference to the collection
     pub chain_of_origin: u64, // Chain where NFT was first minted
     pub created_at: i64, // Timestamp of creation
     #[max_len(200)]
     pub metadata_uri: String, // Original metadata URI
     pub bump: u8, // PDA bump
+    /// Limited-supply cap for prints of this master edition; `None` means the NFT was never
+    /// created as a printable master (or supply is unlimited).
+    pub max_supply: Option<u64>,
+    /// Set on a printed edition: the master edition mint it was printed from, plus its
+    /// edition number. `None` on the master itself and on non-printable NFTs.
+    pub parent_master_mint: Option<Pubkey>,
+    pub edition_number: Option<u64>,
+    /// Which token program governs this NFT's mint - legacy SPL Token or Token-2022 - so
+    /// `on_revert` re-mints through the same program instead of assuming legacy always.
+    /// `Pubkey::default()` means "not yet recorded" (set on first revert for older origins).
+    pub token_program: Pubkey,
+    /// Name and symbol recorded at mint/outbound time so `on_revert` can reconstruct the
+    /// Metaplex metadata and master edition accounts for a wrapped NFT that was burned -
+    /// without these, a restored NFT would come back as a bare SPL mint.
+    #[max_len(32)]
+    pub name: String,
+    #[max_len(10)]
+    pub symbol: String,
+    pub seller_fee_basis_points: u16,
+    #[max_len(4)]
+    pub creators: Vec<NftCreator>,
+    /// How many times this NFT has left and returned to Solana. Incremented alongside
+    /// every `transfer_history` entry, kept as its own field so callers don't have to
+    /// count history entries (which are capped and can evict the oldest ones).
+    pub cross_chain_cycle_count: u32,
+    /// Recent transfer-history entries, oldest evicted first once `MAX_TRANSFER_HISTORY`
+    /// is reached - a compact audit trail rather than a complete one, mirroring how NFT
+    /// indexers keep a bounded recent-activity window instead of the full ledger on-chain.
+    #[max_len(8)]
+    pub transfer_history: Vec<NftTransferRecord>,
+    /// Mirrors the Metaplex metadata's `uses` field (`None` when the NFT was never
+    /// configured as a limited-use/utility NFT), kept in sync by `use_nft` so a transfer
+    /// out doesn't need a separate metadata account read to carry the remaining-uses
+    /// count cross-chain.
+    pub uses: Option<NftUses>,
+    /// On-chain mirror of the off-chain JSON's `attributes` array - there's no Metaplex
+    /// on-chain slot for this, so without storing it here a bridged NFT's traits would only
+    /// ever be recoverable by fetching `metadata_uri` from its host, which may be down,
+    /// slow, or gone by the time the destination chain needs them.
+    #[max_len(8)]
+    pub attributes: Vec<NftAttribute>,
+    /// Token Auth Rules ruleset this NFT's metadata was created with, if any. `Some` means
+    /// this is a programmable NFT (`TokenStandard::ProgrammableNonFungible`) whose transfers
+    /// route through the Token Auth Rules program; `None` is a plain NonFungible mint, same
+    /// as before this field existed. Carried so `on_call`/`on_revert` re-mint a bridged pNFT
+    /// with the same enforcement instead of silently downgrading it to an unrestricted NFT.
+    pub rule_set: Option<Pubkey>,
+}
+
+/// Oldest-evicted-first record of a single outbound or inbound cross-chain hop, appended
+/// to `NftOrigin.transfer_history` by `transfer_cross_chain` and `receive_cross_chain`/`on_call`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct NftTransferRecord {
+    pub destination_chain: u64,
+    pub direction: TransferDirection,
+    /// Outbound: the `cross_chain_cycle_count` this hop produced, since outbound transfers
+    /// have no relayer-assigned nonce of their own. Inbound: the TSS-verified nonce the
+    /// message carried.
+    pub nonce: u64,
+    pub timestamp: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum TransferDirection {
+    Outbound,
+    Inbound,
+}
+
+pub const MAX_TRANSFER_HISTORY: usize = 8;
+
+/// A lightweight stand-in for `mpl_token_metadata::types::Creator` that's cheap to store
+/// on `NftOrigin`. `verified` isn't persisted - a re-created creator always starts
+/// unverified, same as a fresh mint.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct NftCreator {
+    pub address: Pubkey,
+    pub share: u8,
+}
+
+/// A single off-chain-JSON-style trait entry, e.g. `{ "trait_type": "Background", "value":
+/// "Blue" }`. Capped well under Metaplex's own name/symbol limits since an NFT can carry
+/// several of these and rent scales with the account's total size.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct NftAttribute {
+    #[max_len(32)]
+    pub trait_type: String,
+    #[max_len(64)]
+    pub value: String,
+}
+
+pub const MAX_ATTRIBUTES: usize = 8;
+
+/// A lightweight stand-in for `mpl_token_metadata::types::Uses`, same rationale as
+/// `NftCreator`: cheap to store on `NftOrigin` and to carry inside `CrossChainMessage`
+/// without pulling the Metaplex type itself into the wire format.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct NftUses {
+    pub use_method: NftUseMethod,
+    pub remaining: u64,
+    pub total: u64,
+}
+
+impl NftUses {
+    pub fn to_metaplex(&self) -> MetaplexUses {
+        MetaplexUses {
+            use_method: self.use_method.to_metaplex(),
+            remaining: self.remaining,
+            total: self.total,
+        }
+    }
+}
+
+impl From<&MetaplexUses> for NftUses {
+    fn from(uses: &MetaplexUses) -> Self {
+        NftUses {
+            use_method: NftUseMethod::from_metaplex(uses.use_method),
+            remaining: uses.remaining,
+            total: uses.total,
+        }
+    }
+}
+
+/// Mirrors `mpl_token_metadata::types::UseMethod`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum NftUseMethod {
+    Burn,
+    Multiple,
+    Single,
+}
+
+impl NftUseMethod {
+    pub fn to_metaplex(&self) -> MetaplexUseMethod {
+        match self {
+            NftUseMethod::Burn => MetaplexUseMethod::Burn,
+            NftUseMethod::Multiple => MetaplexUseMethod::Multiple,
+            NftUseMethod::Single => MetaplexUseMethod::Single,
+        }
+    }
+
+    pub fn from_metaplex(method: MetaplexUseMethod) -> Self {
+        match method {
+            MetaplexUseMethod::Burn => NftUseMethod::Burn,
+            MetaplexUseMethod::Multiple => NftUseMethod::Multiple,
+            MetaplexUseMethod::Single => NftUseMethod::Single,
+        }
+    }
 }
 
 #[account]
@@ -47,10 +211,70 @@ pub struct Connected {
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct CrossChainMessage {
     pub destination_chain: Vec<u8>,
-    pub recipient: Vec<u8>,
+    pub recipient: CrossChainAddress,
     pub token_id: u64,
     pub uri: String,
-    pub sender: Vec<u8>,
+    pub sender: CrossChainAddress,
+    /// Royalty/creator split carried over from the origin chain so a bridged NFT keeps
+    /// its economic metadata instead of landing as zero-royalty/no-creator. Formats that
+    /// can't carry this (ABI-packed, legacy) decode to `0`/empty, same as before.
+    pub seller_fee_basis_points: u16,
+    pub creators: Vec<NftCreator>,
+    /// Name/symbol travel alongside the URI so the destination can reconstruct the NFT's
+    /// on-chain metadata faithfully instead of falling back to a generic placeholder.
+    /// Metaplex caps: name <= 32 bytes, symbol <= 10 bytes. Formats that can't carry this
+    /// (ABI-packed legacy, ZetaChain) decode to empty, same as royalty/creators above.
+    pub name: String,
+    pub symbol: String,
+    /// Remaining-uses state for a utility NFT, carried across chains so a partially-used
+    /// NFT doesn't reset to fully-used (or unlimited) on arrival. Formats that can't carry
+    /// this (ZetaChain, ABI-packed, legacy) decode to `None`, same as the royalty/creator
+    /// fields above.
+    pub uses: Option<NftUses>,
+    /// Off-chain-JSON-style traits, carried so they survive a bridge hop without a
+    /// round-trip to `uri`'s host. Formats that can't carry this decode to empty, same as
+    /// `creators` above.
+    pub attributes: Vec<NftAttribute>,
+    /// Token Auth Rules ruleset pubkey, carried so a programmable NFT is re-minted as
+    /// `TokenStandard::ProgrammableNonFungible` with the same rule set on arrival instead of
+    /// downgrading to a plain NonFungible mint. Formats that can't carry this decode to
+    /// `None`, same as `uses` above.
+    pub rule_set: Option<Pubkey>,
+}
+
+/// A cross-chain recipient/sender address, tagged by which chain family it belongs to, so
+/// callers can't mistake a raw 20-or-32-byte blob for the wrong endpoint's format.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CrossChainAddress {
+    Evm([u8; 20]),
+    Solana(Pubkey),
+}
+
+impl CrossChainAddress {
+    /// Parse a wire-format address: 20 bytes is EVM, 32 is Solana.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        match bytes.len() {
+            20 => {
+                let mut addr = [0u8; 20];
+                addr.copy_from_slice(bytes);
+                Ok(CrossChainAddress::Evm(addr))
+            }
+            32 => {
+                let mut key = [0u8; 32];
+                key.copy_from_slice(bytes);
+                Ok(CrossChainAddress::Solana(Pubkey::new_from_array(key)))
+            }
+            _ => Err(crate::UniversalNftError::InvalidRecipientAddress.into()),
+        }
+    }
+
+    /// Wire-format bytes for this address (20 bytes for EVM, 32 for Solana).
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            CrossChainAddress::Evm(addr) => addr.to_vec(),
+            CrossChainAddress::Solana(pubkey) => pubkey.to_bytes().to_vec(),
+        }
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -143,6 +367,16 @@ impl Collection {
         Ok(())
     }
 
+    /// Advance and return this collection's outbound sequence counter, stamped into every
+    /// `transfer_cross_chain`/`transfer_cross_chain_with_payload` message as the
+    /// per-collection ordering/idempotency key.
+    pub fn next_sequence(&mut self) -> Result<u64> {
+        self.sequence = self.sequence
+            .checked_add(1)
+            .ok_or(crate::UniversalNftError::InvalidTokenId)?;
+        Ok(self.sequence)
+    }
+
     /// Get collection statistics
     pub fn get_stats(&self) -> (u64, u64, u64) {
         (self.total_minted, self.solana_native_count, self.total_minted - self.solana_native_count)
@@ -194,6 +428,28 @@ impl NftOrigin {
         self.chain_of_origin == 101 || self.chain_of_origin == 103 || self.chain_of_origin == 102
     }
 
+    /// Record one outbound or inbound cross-chain hop: bumps `cross_chain_cycle_count` and
+    /// appends a `transfer_history` entry, evicting the oldest entry first once
+    /// `MAX_TRANSFER_HISTORY` is reached so the account stays within its fixed `max_len`.
+    pub fn record_transfer(
+        &mut self,
+        destination_chain: u64,
+        direction: TransferDirection,
+        nonce: u64,
+        timestamp: i64,
+    ) {
+        self.cross_chain_cycle_count = self.cross_chain_cycle_count.saturating_add(1);
+        if self.transfer_history.len() >= MAX_TRANSFER_HISTORY {
+            self.transfer_history.remove(0);
+        }
+        self.transfer_history.push(NftTransferRecord {
+            destination_chain,
+            direction,
+            nonce,
+            timestamp,
+        });
+    }
+
     /// Get the origin chain name for display purposes
     pub fn get_origin_chain_name(&self) -> &'static str {
         match self.chain_of_origin {
@@ -229,25 +485,268 @@ impl NftOrigin {
     }
 }
 
+/// Per-mint custody record for a Solana-native NFT locked into `custody_token_account`
+/// rather than burned. `locked` flips to `false` on return instead of closing the account -
+/// the same "accumulate rent, nobody's forced to reclaim it" tradeoff this program already
+/// makes for [`Claim`]/`ProcessedMessage` - since the same mint may lock and unlock across
+/// many round trips and `init_if_needed` can't recreate an account that was fully closed.
+#[account]
+#[derive(InitSpace)]
+pub struct CustodyAccount {
+    /// The native NFT's mint this record tracks
+    pub mint: Pubkey,
+    /// Collection this NFT belongs to
+    pub collection: Pubkey,
+    /// `true` while the token sits in `custody_token_account`, `false` once released back
+    pub locked: bool,
+    /// Owner who sent the NFT into custody, so a release can be cross-checked if needed
+    pub locked_by: Pubkey,
+    /// Unix timestamp of the most recent lock
+    pub locked_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+/// Marks one `on_call`/`receive_cross_chain` delivery as consumed. The PDA is derived from
+/// the message's own identity (collection/sender/source chain and, for `receive_cross_chain`,
+/// nonce too, plus in both cases the message hash), so a duplicated or recast delivery
+/// lands on the same account and fails its `claimed` check with `AlreadyClaimed` - replay
+/// protection that doesn't depend on the nonce alone or on messages arriving in order.
+/// `processed_at` and `source_sender` are kept purely for auditing; they play no role in
+/// the replay check itself, which is enforced by the PDA derivation plus `claimed`.
+///
+/// These accounts are never closed automatically, so rent accumulates at roughly one
+/// account's worth per processed message, forever, unless something reclaims it. The
+/// `close_claim` instruction is that optional reclaim path: once a claim is old enough
+/// that no legitimate redelivery could still be in flight, the collection authority can
+/// close it and recover the rent - see `instructions::close_claim::CLAIM_CLOSE_WINDOW_SECS`.
+#[account]
+#[derive(InitSpace)]
+pub struct Claim {
+    pub bump: u8,
+    /// Unix timestamp this delivery was processed
+    pub processed_at: i64,
+    /// EVM sender address the message claimed to come from
+    pub source_sender: [u8; 20],
+    /// Set once this claim PDA has actually been consumed. The account is `init_if_needed`
+    /// rather than a plain `init` so a second delivery of the same message can be rejected
+    /// with a readable `AlreadyClaimed` error instead of the generic "account already in
+    /// use" Anchor raises on a bare re-`init`.
+    pub claimed: bool,
+}
+
+/// Binds a 20-byte EVM address to the real Solana `Pubkey` its owner wants NFTs routed to
+/// when a cross-chain message names them as an EVM-format recipient. `bind_evm_address`
+/// only creates this once the caller has proven control of `evm_address` by signing with
+/// its private key, so unlike the old keccak-derived fabricated address, `solana_address`
+/// here is always a real account someone can actually sign for.
+#[account]
+#[derive(InitSpace)]
+pub struct AddressBinding {
+    pub evm_address: [u8; 20],
+    pub solana_address: Pubkey,
+    pub bump: u8,
+}
+
+/// EIP-1559 caps the base-fee move between blocks to 1/8 (±12.5%) so fees adjust smoothly
+/// under sustained congestion rather than swinging to an extreme in a single update.
+pub const BASE_FEE_MAX_CHANGE_DENOM: u64 = 8;
+
+/// Floor for `base_fee_per_unit` so a prolonged quiet period can't decay the rate to zero
+/// and leave `calculate_gas_fee` quoting free transfers.
+pub const MINIMUM_BASE_FEE: u64 = 1;
+
+/// Per-destination-chain EIP-1559-style base fee. Updated each time a cross-chain
+/// transfer is processed for that chain so `calculate_gas_fee` tracks observed demand
+/// instead of only reading the fixed per-chain table.
+#[account]
+#[derive(InitSpace)]
+pub struct BaseFeeState {
+    pub chain_id: u64,
+    pub base_fee_per_unit: u64,
+    pub gas_target: u64,
+    pub last_update_slot: u64,
+    pub bump: u8,
+}
+
+impl BaseFeeState {
+    /// Applies the EIP-1559 base-fee recurrence for one transfer's worth of `gas_used`
+    /// against this chain's `gas_target`. The SOL-denominated floor/ceiling is applied
+    /// separately in `calculate_gas_fee` once this per-unit rate is combined with the
+    /// requested gas amount.
+    pub fn apply_update(&mut self, gas_used: u64, slot: u64) -> Result<()> {
+        let base_fee = self.base_fee_per_unit;
+        let gas_target = self.gas_target.max(1);
+
+        let new_base_fee = if gas_used == gas_target {
+            base_fee
+        } else if gas_used > gas_target {
+            let gas_delta = gas_used.saturating_sub(gas_target).min(gas_target);
+            let delta = base_fee
+                .checked_mul(gas_delta)
+                .and_then(|v| v.checked_div(gas_target))
+                .and_then(|v| v.checked_div(BASE_FEE_MAX_CHANGE_DENOM))
+                .ok_or(crate::UniversalNftError::InvalidMessage)?
+                .max(MINIMUM_BASE_FEE);
+            base_fee.checked_add(delta).ok_or(crate::UniversalNftError::InvalidMessage)?
+        } else {
+            let gas_delta = gas_target.saturating_sub(gas_used);
+            let delta = base_fee
+                .checked_mul(gas_delta)
+                .and_then(|v| v.checked_div(gas_target))
+                .and_then(|v| v.checked_div(BASE_FEE_MAX_CHANGE_DENOM))
+                .ok_or(crate::UniversalNftError::InvalidMessage)?;
+            base_fee.saturating_sub(delta)
+        };
+
+        self.base_fee_per_unit = new_base_fee.max(MINIMUM_BASE_FEE);
+        self.last_update_slot = slot;
+        Ok(())
+    }
+}
+
+/// The kind of address a chain expects, used to validate recipient/sender formats
+/// against the chain they actually belong to instead of a loose 20-or-32 check.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AddressKind {
+    /// 20-byte hex address (Ethereum, BSC, Polygon, Base, Arbitrum, Optimism, ...)
+    Evm,
+    /// 32-byte Ed25519 public key
+    Solana,
+    /// bech32-encoded address (Cosmos/Terra-style chains)
+    Bech32,
+}
+
+/// Canonical registry of chains this program understands, mapping well-known chains
+/// to stable numeric codes and their expected address format.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChainId {
+    Ethereum,
+    EthereumSepolia,
+    Bsc,
+    BscTestnet,
+    Polygon,
+    PolygonMumbai,
+    Base,
+    BaseSepolia,
+    Arbitrum,
+    ArbitrumSepolia,
+    Optimism,
+    OptimismSepolia,
+    Avalanche,
+    Solana,
+    ZetaChain,
+    ZetaChainTestnet,
+    Cosmos,
+}
+
+pub const CHAIN_ID_AVALANCHE: u64 = 43114;
+pub const CHAIN_ID_SOLANA_MAINNET: u64 = 101;
+pub const CHAIN_ID_COSMOS_HUB: u64 = 118;
+
+impl ChainId {
+    /// The stable numeric code this chain is identified by on the wire
+    pub fn as_code(&self) -> u64 {
+        match self {
+            ChainId::Ethereum => CHAIN_ID_ETHEREUM,
+            ChainId::EthereumSepolia => CHAIN_ID_SEPOLIA,
+            ChainId::Bsc => CHAIN_ID_BSC,
+            ChainId::BscTestnet => CHAIN_ID_BSC_TESTNET,
+            ChainId::Polygon => CHAIN_ID_POLYGON,
+            ChainId::PolygonMumbai => CHAIN_ID_MUMBAI,
+            ChainId::Base => CHAIN_ID_BASE,
+            ChainId::BaseSepolia => CHAIN_ID_BASE_SEPOLIA,
+            ChainId::Arbitrum => CHAIN_ID_ARBITRUM,
+            ChainId::ArbitrumSepolia => CHAIN_ID_ARBITRUM_SEPOLIA,
+            ChainId::Optimism => CHAIN_ID_OPTIMISM,
+            ChainId::OptimismSepolia => CHAIN_ID_OPTIMISM_SEPOLIA,
+            ChainId::Avalanche => CHAIN_ID_AVALANCHE,
+            ChainId::Solana => CHAIN_ID_SOLANA_MAINNET,
+            ChainId::ZetaChain => CHAIN_ID_ZETACHAIN,
+            ChainId::ZetaChainTestnet => CHAIN_ID_ZETACHAIN_TESTNET,
+            ChainId::Cosmos => CHAIN_ID_COSMOS_HUB,
+        }
+    }
+
+    /// The address format endpoints on this chain are expected to use
+    pub fn address_kind(&self) -> AddressKind {
+        match self {
+            ChainId::Solana => AddressKind::Solana,
+            ChainId::ZetaChain | ChainId::ZetaChainTestnet => AddressKind::Solana,
+            ChainId::Cosmos => AddressKind::Bech32,
+            _ => AddressKind::Evm,
+        }
+    }
+}
+
+impl TryFrom<u64> for ChainId {
+    type Error = crate::UniversalNftError;
+
+    fn try_from(code: u64) -> std::result::Result<Self, Self::Error> {
+        match code {
+            CHAIN_ID_ETHEREUM => Ok(ChainId::Ethereum),
+            CHAIN_ID_SEPOLIA => Ok(ChainId::EthereumSepolia),
+            CHAIN_ID_BSC => Ok(ChainId::Bsc),
+            CHAIN_ID_BSC_TESTNET => Ok(ChainId::BscTestnet),
+            CHAIN_ID_POLYGON => Ok(ChainId::Polygon),
+            CHAIN_ID_MUMBAI => Ok(ChainId::PolygonMumbai),
+            CHAIN_ID_BASE => Ok(ChainId::Base),
+            CHAIN_ID_BASE_SEPOLIA => Ok(ChainId::BaseSepolia),
+            CHAIN_ID_ARBITRUM => Ok(ChainId::Arbitrum),
+            CHAIN_ID_ARBITRUM_SEPOLIA => Ok(ChainId::ArbitrumSepolia),
+            CHAIN_ID_OPTIMISM => Ok(ChainId::Optimism),
+            CHAIN_ID_OPTIMISM_SEPOLIA => Ok(ChainId::OptimismSepolia),
+            CHAIN_ID_AVALANCHE => Ok(ChainId::Avalanche),
+            CHAIN_ID_SOLANA_MAINNET | 102 | 103 => Ok(ChainId::Solana),
+            CHAIN_ID_ZETACHAIN => Ok(ChainId::ZetaChain),
+            CHAIN_ID_ZETACHAIN_TESTNET => Ok(ChainId::ZetaChainTestnet),
+            CHAIN_ID_COSMOS_HUB => Ok(ChainId::Cosmos),
+            _ => Err(crate::UniversalNftError::UnsupportedChain),
+        }
+    }
+}
+
+/// Validate an address against the address format a specific chain expects.
+/// Replaces the old "20 or 32 bytes, either endpoint" check with a chain-aware one.
+pub fn validate_address_for_chain(address: &[u8], chain: ChainId) -> Result<()> {
+    match chain.address_kind() {
+        AddressKind::Evm => {
+            require!(address.len() == 20, crate::UniversalNftError::InvalidRecipientAddress);
+        }
+        AddressKind::Solana => {
+            require!(address.len() == 32, crate::UniversalNftError::InvalidRecipientAddress);
+        }
+        AddressKind::Bech32 => {
+            require!(is_valid_bech32(address), crate::UniversalNftError::InvalidRecipientAddress);
+        }
+    }
+    Ok(())
+}
+
+/// Minimal bech32 structural check: human-readable prefix, separator, and a data part
+/// drawn from the bech32 charset. `address` is the ASCII-encoded bech32 string bytes.
+fn is_valid_bech32(address: &[u8]) -> bool {
+    const CHARSET: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+    let Ok(s) = std::str::from_utf8(address) else {
+        return false;
+    };
+    let Some(sep) = s.rfind('1') else {
+        return false;
+    };
+    if sep == 0 || sep + 7 > s.len() {
+        return false;
+    }
+    let (hrp, data) = s.split_at(sep);
+    if hrp.is_empty() || !hrp.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit()) {
+        return false;
+    }
+    data[1..].bytes().all(|b| CHARSET.contains(&b.to_ascii_lowercase()))
+}
+
 /// Check if a chain ID is supported
 pub fn is_supported_chain(chain_id: u64) -> bool {
-    matches!(
-        chain_id,
-        CHAIN_ID_ZETACHAIN |
-        CHAIN_ID_ETHEREUM |
-        CHAIN_ID_BSC |
-        CHAIN_ID_POLYGON |
-        CHAIN_ID_BASE |
-        CHAIN_ID_ARBITRUM |
-        CHAIN_ID_OPTIMISM |
-        CHAIN_ID_SEPOLIA |
-        CHAIN_ID_BSC_TESTNET |
-        CHAIN_ID_MUMBAI |
-        CHAIN_ID_BASE_SEPOLIA |
-        CHAIN_ID_ARBITRUM_SEPOLIA |
-        CHAIN_ID_OPTIMISM_SEPOLIA |
-        CHAIN_ID_ZETACHAIN_TESTNET
-    )
+    ChainId::try_from(chain_id).is_ok()
 }
 
 /// Validate chain ID format
@@ -290,35 +789,43 @@ pub fn validate_solana_address(address: &[u8]) -> Result<Pubkey> {
     Ok(Pubkey::new_from_array(addr_array))
 }
 
+/// Derive the EVM address ZetaChain's gateway will see for a Solana public key: the last
+/// 20 bytes of `keccak256(pubkey_bytes)`. This is a one-way, deterministic,
+/// collision-resistant mapping - unlike truncating the pubkey's first 20 bytes, which
+/// throws away the rest of the key and produces an address unrelated to it. The original
+/// `Pubkey` isn't recoverable from the derived address alone, so callers that need it back
+/// for a return transfer must record it themselves (see `TokenTransfer::original_solana_recipient`).
+pub fn derive_evm_address_from_solana(pubkey: &Pubkey) -> [u8; 20] {
+    let hash = anchor_lang::solana_program::keccak::hash(pubkey.as_ref());
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&hash.to_bytes()[12..32]);
+    address
+}
+
 /// Convert between address formats for cross-chain compatibility
 pub fn convert_address_format(address: &[u8], target_chain: u64) -> Result<Vec<u8>> {
+    let parsed = CrossChainAddress::from_bytes(address)?;
     match target_chain {
         // EVM chains require 20-byte addresses
-        CHAIN_ID_ETHEREUM | CHAIN_ID_BSC | CHAIN_ID_POLYGON | 
+        CHAIN_ID_ETHEREUM | CHAIN_ID_BSC | CHAIN_ID_POLYGON |
         CHAIN_ID_BASE | CHAIN_ID_ARBITRUM | CHAIN_ID_OPTIMISM |
         CHAIN_ID_SEPOLIA | CHAIN_ID_BSC_TESTNET | CHAIN_ID_MUMBAI |
         CHAIN_ID_BASE_SEPOLIA | CHAIN_ID_ARBITRUM_SEPOLIA | CHAIN_ID_OPTIMISM_SEPOLIA => {
-            if address.len() == 20 {
-                Ok(address.to_vec())
-            } else if address.len() == 32 {
-                // For Solana to EVM, we might need to derive an EVM address
-                // This is a simplified approach - real implementation would use proper derivation
-                Ok(address[..20].to_vec())
-            } else {
-                Err(crate::UniversalNftError::InvalidRecipientAddress.into())
+            match parsed {
+                CrossChainAddress::Evm(addr) => Ok(addr.to_vec()),
+                CrossChainAddress::Solana(pubkey) => Ok(derive_evm_address_from_solana(&pubkey).to_vec()),
             }
         },
         // ZetaChain and Solana use 32-byte addresses
         CHAIN_ID_ZETACHAIN | CHAIN_ID_ZETACHAIN_TESTNET => {
-            if address.len() == 32 {
-                Ok(address.to_vec())
-            } else if address.len() == 20 {
-                // Pad EVM address to 32 bytes for ZetaChain
-                let mut padded = vec![0u8; 12];
-                padded.extend_from_slice(address);
-                Ok(padded)
-            } else {
-                Err(crate::UniversalNftError::InvalidRecipientAddress.into())
+            match parsed {
+                CrossChainAddress::Solana(pubkey) => Ok(pubkey.to_bytes().to_vec()),
+                CrossChainAddress::Evm(addr) => {
+                    // Pad EVM address to 32 bytes for ZetaChain
+                    let mut padded = vec![0u8; 12];
+                    padded.extend_from_slice(&addr);
+                    Ok(padded)
+                }
             }
         },
         _ => Err(crate::UniversalNftError::UnsupportedChain.into())
@@ -70,6 +70,76 @@ pub fn encode_evm_oncall_message(
     out
 }
 
+/// Encode the Anchor instruction data for the Cross-Chain Bridge `deposit_and_call` method,
+/// used by `CrossChainBridge::bridge_to_zetachain` instead of `transfer_and_invoke` since the
+/// outbound call carries a SOL deposit alongside the message rather than an SPL amount.
+/// Layout per Anchor: [discriminator(8)] + amount(u64 LE) + receiver([u8;20]) + message(Vec<u8>) + revert_options(Option<RevertOptions>)
+pub fn encode_bridge_deposit_and_call_instruction_data(amount_lamports: u64, receiver: [u8; 20], message: &[u8]) -> Vec<u8> {
+    // Discriminator = sha256("global:deposit_and_call")[..8]
+    let disc = anchor_lang::solana_program::hash::hash(b"global:deposit_and_call").to_bytes();
+    let mut data = Vec::with_capacity(8 + 8 + 20 + 4 + message.len() + 1);
+    data.extend_from_slice(&disc[..8]);
+    data.extend_from_slice(&amount_lamports.to_le_bytes());
+    data.extend_from_slice(&receiver);
+    data.extend_from_slice(&(message.len() as u32).to_le_bytes());
+    data.extend_from_slice(message);
+    // Option::None for revert_options
+    data.push(0u8);
+    data
+}
+
+/// Encode the cross-chain payload carried by `bridge_to_zetachain`'s `deposit_and_call`:
+/// (address destination, address receiver, uint256 assetId, string uri, address sender).
+/// Same ABI head/tail shape as [`encode_evm_oncall_message`], with `destination` in place of
+/// `gasAmount` - the zero address means "stay on ZetaChain" rather than hop onward again.
+pub fn encode_evm_nft_message(
+    destination: [u8; 20],
+    receiver: [u8; 20],
+    asset_id: [u8; 32],
+    uri: &str,
+    sender: [u8; 20],
+) -> Vec<u8> {
+    // Head = 5 * 32 bytes
+    let head_len = 32 * 5;
+    let uri_bytes = uri.as_bytes();
+    let uri_len = uri_bytes.len();
+    let uri_padded_len = ((uri_len + 31) / 32) * 32;
+
+    let total_len = head_len + 32 /*len*/ + uri_padded_len;
+    let mut out = Vec::with_capacity(total_len);
+
+    // 1) destination address
+    out.extend_from_slice(&[0u8; 12]);
+    out.extend_from_slice(&destination);
+    // 2) receiver address
+    out.extend_from_slice(&[0u8; 12]);
+    out.extend_from_slice(&receiver);
+    // 3) assetId (32 bytes, already big-endian)
+    out.extend_from_slice(&asset_id);
+    // 4) offset to string (from start)
+    let offset = (32 * 5) as u64;
+    let mut off_buf = [0u8; 32];
+    let off_bytes = offset.to_be_bytes();
+    off_buf[32 - off_bytes.len()..].copy_from_slice(&off_bytes);
+    out.extend_from_slice(&off_buf);
+    // 5) sender address
+    out.extend_from_slice(&[0u8; 12]);
+    out.extend_from_slice(&sender);
+
+    // Tail: string (len + bytes + padding)
+    let mut len_buf = [0u8; 32];
+    let len_be = (uri_len as u64).to_be_bytes();
+    len_buf[32 - len_be.len()..].copy_from_slice(&len_be);
+    out.extend_from_slice(&len_buf);
+    out.extend_from_slice(uri_bytes);
+    let pad_len = uri_padded_len - uri_len;
+    if pad_len > 0 {
+        out.extend_from_slice(&vec![0u8; pad_len]);
+    }
+
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -89,4 +159,37 @@ mod tests {
         assert_eq!(&data[40..42], &message[..]);
         assert_eq!(data[42], 0u8); // None
     }
+
+    #[test]
+    fn test_encode_bridge_deposit_and_call_ix_data_layout() {
+        let receiver = [0x33u8; 20];
+        let message = vec![0xaa, 0xbb, 0xcc];
+        let amt = 5_000_000u64;
+        let data = encode_bridge_deposit_and_call_instruction_data(amt, receiver, &message);
+        assert_eq!(&data[..8], &anchor_lang::solana_program::hash::hash(b"global:deposit_and_call").to_bytes()[..8]);
+        let amt_le = u64::from_le_bytes(data[8..16].try_into().unwrap());
+        assert_eq!(amt_le, amt);
+        assert_eq!(&data[16..36], &receiver);
+        let len_le = u32::from_le_bytes(data[36..40].try_into().unwrap());
+        assert_eq!(len_le as usize, message.len());
+        assert_eq!(&data[40..43], &message[..]);
+        assert_eq!(data[43], 0u8); // None
+    }
+
+    #[test]
+    fn test_encode_evm_nft_message_layout() {
+        let destination = [0u8; 20];
+        let receiver = [0x44u8; 20];
+        let asset_id = [0x11u8; 32];
+        let sender = [0x55u8; 20];
+        let uri = "ipfs://example";
+        let data = encode_evm_nft_message(destination, receiver, asset_id, uri, sender);
+        assert_eq!(&data[12..32], &destination);
+        assert_eq!(&data[44..64], &receiver);
+        assert_eq!(&data[64..96], &asset_id);
+        assert_eq!(&data[140..160], &sender);
+        let len_be = u64::from_be_bytes(data[184..192].try_into().unwrap());
+        assert_eq!(len_be as usize, uri.len());
+        assert_eq!(&data[192..192 + uri.len()], uri.as_bytes());
+    }
 }
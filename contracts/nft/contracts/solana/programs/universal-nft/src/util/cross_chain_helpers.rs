@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::error::UniversalNftError;
+use crate::UniversalNftError;
 
 /// Cross-chain message data parsing and utilities
 /// This module contains helper functions for encoding/decoding cross-chain NFT data
@@ -76,10 +76,13 @@ pub fn address_to_hex_string(address: &[u8; 20]) -> String {
 ///   [0] receiver: 12 zero bytes + 20 byte address
 ///   [1] tokenId: 32-byte big-endian uint256
 ///   [2] offset to uri (uint256, from start of payload)
-///   [3] amount: 32-byte big-endian uint256 (can be ignored; `amount` also provided by gateway)
+///   [3] amount: 32-byte big-endian uint256 - an ERC1155-style quantity greater than one
+///       means the caller should mint a Metaplex master edition with `max_supply` set to it
+///       (see `mint_nft`'s `max_supply` parameter) and print further copies via `print_edition`,
+///       rather than a single unlimited-print NFT
 ///   [4] sender: 12 zero bytes + 20 byte address
 /// - Tail at offset: uri length (uint256) + uri bytes + padding to 32 bytes
-pub fn decode_evm_abi_nft_message(data: &[u8]) -> Result<([u8; 32], [u8; 20], String, [u8; 20])> {
+pub fn decode_evm_abi_nft_message(data: &[u8]) -> Result<([u8; 32], [u8; 20], String, [u8; 32], [u8; 20])> {
     // Need at least 5 words for head and one word for string length
     if data.len() < 32 * 6 {
         return Err(UniversalNftError::InvalidDataFormat.into());
@@ -108,6 +111,10 @@ pub fn decode_evm_abi_nft_message(data: &[u8]) -> Result<([u8; 32], [u8; 20], St
         return Err(UniversalNftError::InvalidDataFormat.into());
     }
 
+    // amount (word 3)
+    let mut amount = [0u8; 32];
+    amount.copy_from_slice(word(3));
+
     // sender (last 20 bytes of word 4)
     let mut sender = [0u8; 20];
     sender.copy_from_slice(&word(4)[12..32]);
@@ -129,5 +136,130 @@ pub fn decode_evm_abi_nft_message(data: &[u8]) -> Result<([u8; 32], [u8; 20], St
     let uri = String::from_utf8(uri_bytes.to_vec())
         .map_err(|_| UniversalNftError::InvalidDataFormat)?;
 
-    Ok((token_id, receiver, uri, sender))
+    Ok((token_id, receiver, uri, amount, sender))
+}
+
+/// Encode an EVM ABI NFT message for outbound Solana -> ZEVM transfers. Symmetric counterpart
+/// to `decode_evm_abi_nft_message`.
+/// Tuple layout (Solidity): (address receiver, uint256 tokenId, string uri, uint256 amount, address sender)
+/// ABI encoding:
+/// - Head (5 x 32 bytes):
+///   [0] receiver: 12 zero bytes + 20 byte address
+///   [1] tokenId: 32-byte big-endian uint256
+///   [2] offset to uri (constant 0xA0 = 160, five 32-byte head words)
+///   [3] amount: 32-byte big-endian uint256
+///   [4] sender: 12 zero bytes + 20 byte address
+/// - Tail: uri length (uint256) + uri bytes + padding to 32 bytes
+pub fn encode_evm_abi_nft_message(
+    token_id: [u8; 32],
+    receiver: [u8; 20],
+    uri: &str,
+    amount: [u8; 32],
+    sender: [u8; 20],
+) -> Vec<u8> {
+    let uri_bytes = uri.as_bytes();
+    let mut data = Vec::with_capacity(32 * 5 + 32 + uri_bytes.len() + 31);
+
+    // word 0: receiver
+    data.extend_from_slice(&[0u8; 12]);
+    data.extend_from_slice(&receiver);
+
+    // word 1: tokenId
+    data.extend_from_slice(&token_id);
+
+    // word 2: offset to uri (head is always 5 words = 0xA0 bytes)
+    let mut offset_word = [0u8; 32];
+    offset_word[24..32].copy_from_slice(&(160u64).to_be_bytes());
+    data.extend_from_slice(&offset_word);
+
+    // word 3: amount
+    data.extend_from_slice(&amount);
+
+    // word 4: sender
+    data.extend_from_slice(&[0u8; 12]);
+    data.extend_from_slice(&sender);
+
+    // tail: uri length word + uri bytes + zero padding to the next 32-byte boundary
+    let mut len_word = [0u8; 32];
+    len_word[24..32].copy_from_slice(&(uri_bytes.len() as u64).to_be_bytes());
+    data.extend_from_slice(&len_word);
+    data.extend_from_slice(uri_bytes);
+
+    let padding = (32 - (uri_bytes.len() % 32)) % 32;
+    data.extend_from_slice(&vec![0u8; padding]);
+
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_decode_evm_abi_nft_message_round_trips() {
+        let receiver = [0x11u8; 20];
+        let mut token_id = [0u8; 32];
+        token_id[31] = 9;
+        let uri = "ipfs://example-metadata";
+        let mut amount = [0u8; 32];
+        amount[31] = 1;
+        let sender = [0x22u8; 20];
+
+        let encoded = encode_evm_abi_nft_message(token_id, receiver, uri, amount, sender);
+        let (decoded_token_id, decoded_receiver, decoded_uri, decoded_amount, decoded_sender) =
+            decode_evm_abi_nft_message(&encoded).unwrap();
+
+        assert_eq!(decoded_token_id, token_id);
+        assert_eq!(decoded_receiver, receiver);
+        assert_eq!(decoded_uri, uri);
+        assert_eq!(decoded_amount, amount);
+        assert_eq!(decoded_sender, sender);
+    }
+
+    #[test]
+    fn test_encode_decode_evm_abi_nft_message_zero_length_uri() {
+        let receiver = [0x33u8; 20];
+        let mut token_id = [0u8; 32];
+        token_id[31] = 1;
+        let uri = "";
+        let amount = [0u8; 32];
+        let sender = [0x44u8; 20];
+
+        let encoded = encode_evm_abi_nft_message(token_id, receiver, uri, amount, sender);
+        // length word + zero bytes of uri + no padding needed
+        assert_eq!(encoded.len(), 32 * 6);
+
+        let (decoded_token_id, decoded_receiver, decoded_uri, decoded_amount, decoded_sender) =
+            decode_evm_abi_nft_message(&encoded).unwrap();
+
+        assert_eq!(decoded_token_id, token_id);
+        assert_eq!(decoded_receiver, receiver);
+        assert_eq!(decoded_uri, uri);
+        assert_eq!(decoded_amount, amount);
+        assert_eq!(decoded_sender, sender);
+    }
+
+    #[test]
+    fn test_encode_decode_evm_abi_nft_message_non_aligned_uri_length() {
+        let receiver = [0x55u8; 20];
+        let mut token_id = [0u8; 32];
+        token_id[31] = 2;
+        // 33 bytes - one more than a full 32-byte word, forcing non-trivial padding
+        let uri = "a".repeat(33);
+        let amount = [0u8; 32];
+        let sender = [0x66u8; 20];
+
+        let encoded = encode_evm_abi_nft_message(token_id, receiver, &uri, amount, sender);
+        // head (5 words) + length word + 33 uri bytes padded up to 64
+        assert_eq!(encoded.len(), 32 * 6 + 64);
+
+        let (decoded_token_id, decoded_receiver, decoded_uri, decoded_amount, decoded_sender) =
+            decode_evm_abi_nft_message(&encoded).unwrap();
+
+        assert_eq!(decoded_token_id, token_id);
+        assert_eq!(decoded_receiver, receiver);
+        assert_eq!(decoded_uri, uri);
+        assert_eq!(decoded_amount, amount);
+        assert_eq!(decoded_sender, sender);
+    }
 }
\ No newline at end of file
@@ -1,10 +1,92 @@
 use anchor_lang::prelude::*;
 use crate::errors::Errors;
 
+/// Royalty creator entry as carried across the bridge: `address` is the creator's
+/// Solana pubkey, `verified` mirrors Metaplex's own `Creator::verified` (always written
+/// `false` on the destination mint, since the creator didn't co-sign this CPI), and
+/// `share` is its percentage of `seller_fee_basis_points`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NftCreator {
+    pub address: Pubkey,
+    pub verified: bool,
+    pub share: u8,
+}
+
+/// Metaplex `Uses` (utility-counter) state as carried across the bridge. `use_method`
+/// mirrors Metaplex's own discriminant (0 = Burn, 1 = Multiple, 2 = Single).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NftUses {
+    pub use_method: u8,
+    pub remaining: u64,
+    pub total: u64,
+}
+
+/// Leading byte of `decode_nft_data`'s buffer that selects the little-endian packed
+/// format below (kept for messages built before [`Payload3Codec`] existed).
+pub const PAYLOAD_VERSION_LEGACY_LE: u8 = 0;
+/// Leading byte that selects [`Payload3Codec`], named after the Wormhole NFT bridge's
+/// "Payload 3" (transfer-with-payload) wire format this mirrors.
+pub const PAYLOAD_ID_V3: u8 = 3;
+
 pub struct CrossChainDataDecoder;
 
 impl CrossChainDataDecoder {
-    pub fn decode_nft_data(data: &[u8]) -> Result<([u8; 32], u64, [u8; 20], String, String, String)> {
+    /// Dispatches on the leading version/payload-id byte: [`PAYLOAD_VERSION_LEGACY_LE`]
+    /// decodes the rest of the buffer with the little-endian format below,
+    /// [`PAYLOAD_ID_V3`] decodes it with [`Payload3Codec`]. ZetaChain/EVM senders encode
+    /// big-endian, so a message that round-trips through an EVM contract must use the
+    /// latter or every integer field comes out wrong. The trailing `[u8; 20]` is the
+    /// message's `msg.sender`, all-zero for a legacy message that predates the field.
+    #[allow(clippy::type_complexity)]
+    pub fn decode_nft_data(data: &[u8]) -> Result<([u8; 32], u64, [u8; 20], String, String, String, Option<Pubkey>, u16, Vec<NftCreator>, Option<NftUses>, [u8; 20])> {
+        if data.is_empty() {
+            return Err(Errors::InvalidDataFormat.into());
+        }
+
+        match data[0] {
+            PAYLOAD_VERSION_LEGACY_LE => Self::decode_nft_data_legacy_le(&data[1..]),
+            PAYLOAD_ID_V3 => Self::decode_nft_data_payload3(data),
+            _ => Err(Errors::InvalidDataFormat.into()),
+        }
+    }
+
+    /// Strips the leading 12 zero bytes off a 32-byte ABI-style address word; errors if
+    /// that prefix isn't actually all zero, since that means the field was never a plain
+    /// address to begin with.
+    fn unpad_abi_address(word: &[u8; 32]) -> Result<[u8; 20]> {
+        require!(word[..12].iter().all(|b| *b == 0), Errors::InvalidDataFormat);
+        let mut address = [0u8; 20];
+        address.copy_from_slice(&word[12..]);
+        Ok(address)
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn decode_nft_data_payload3(data: &[u8]) -> Result<([u8; 32], u64, [u8; 20], String, String, String, Option<Pubkey>, u16, Vec<NftCreator>, Option<NftUses>, [u8; 20])> {
+        let payload = Payload3Codec::decode(data)?;
+
+        // Recipient and sender both travel as 32-byte ABI-style words (12 zero bytes +
+        // 20-byte address), matching the padding convention `gateway_helpers` already
+        // uses for EVM addresses.
+        let recipient = Self::unpad_abi_address(&payload.recipient)?;
+        let sender = Self::unpad_abi_address(&payload.sender)?;
+
+        Ok((
+            payload.token_id,
+            payload.origin_chain as u64,
+            recipient,
+            payload.uri,
+            payload.name,
+            payload.symbol,
+            None,
+            0,
+            Vec::new(),
+            None,
+            sender,
+        ))
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn decode_nft_data_legacy_le(data: &[u8]) -> Result<([u8; 32], u64, [u8; 20], String, String, String, Option<Pubkey>, u16, Vec<NftCreator>, Option<NftUses>, [u8; 20])> {
         if data.len() < 60 { // minimum size for fixed fields
             return Err(Errors::InvalidDataFormat.into());
         }
@@ -26,9 +108,132 @@ impl CrossChainDataDecoder {
 
         let (uri, offset) = Self::decode_string(data, offset)?;
         let (name, offset) = Self::decode_string(data, offset)?;
-        let (symbol, _) = Self::decode_string(data, offset)?;
+        let (symbol, offset) = Self::decode_string(data, offset)?;
+
+        // Everything from here on is optional: older senders that predate collection
+        // verification and royalty propagation simply stop after `symbol`, which is
+        // treated as "no collection override, no creators, zero fee".
+        let (collection_id, offset) = Self::decode_collection_id(data, offset)?;
+        let (seller_fee_basis_points, creators, offset) = Self::decode_royalty_section(data, offset)?;
+        let (uses, _offset) = Self::decode_uses_section(data, offset)?;
+
+        Ok((
+            token_id,
+            origin_chain,
+            recipient,
+            uri,
+            name,
+            symbol,
+            collection_id,
+            seller_fee_basis_points,
+            creators,
+            uses,
+            [0u8; 20],
+        ))
+    }
+
+    /// Decode the optional collection-id override: a `has_collection: u8` flag followed
+    /// by the 32-byte id when the flag is `1`.
+    fn decode_collection_id(data: &[u8], offset: usize) -> Result<(Option<Pubkey>, usize)> {
+        if offset == data.len() {
+            return Ok((None, offset));
+        }
+
+        let has_collection = data[offset];
+        let offset = offset + 1;
+
+        if has_collection == 0 {
+            return Ok((None, offset));
+        }
+
+        if offset + 32 > data.len() {
+            return Err(Errors::InvalidDataFormat.into());
+        }
+        let collection_id = Pubkey::new_from_array(
+            data[offset..offset + 32].try_into().map_err(|_| Errors::InvalidDataFormat)?,
+        );
+
+        Ok((Some(collection_id), offset + 32))
+    }
+
+    /// Decode the optional royalty section: a `u16` `seller_fee_basis_points`, a `u8`
+    /// creator count, then that many `(Pubkey, verified: u8, share: u8)` tuples.
+    fn decode_royalty_section(data: &[u8], offset: usize) -> Result<(u16, Vec<NftCreator>, usize)> {
+        if offset == data.len() {
+            return Ok((0, Vec::new(), offset));
+        }
+
+        if offset + 2 > data.len() {
+            return Err(Errors::InvalidDataFormat.into());
+        }
+        let seller_fee_basis_points = u16::from_le_bytes(
+            data[offset..offset + 2].try_into().map_err(|_| Errors::InvalidDataFormat)?,
+        );
+        if seller_fee_basis_points > 10000 {
+            return Err(Errors::InvalidParameter.into());
+        }
+        let mut offset = offset + 2;
+
+        if offset + 1 > data.len() {
+            return Err(Errors::InvalidDataFormat.into());
+        }
+        let creator_count = data[offset] as usize;
+        offset += 1;
 
-        Ok((token_id, origin_chain, recipient, uri, name, symbol))
+        let mut creators = Vec::with_capacity(creator_count);
+        let mut total_share: u16 = 0;
+        for _ in 0..creator_count {
+            if offset + 34 > data.len() {
+                return Err(Errors::InvalidDataFormat.into());
+            }
+            let address = Pubkey::new_from_array(
+                data[offset..offset + 32].try_into().map_err(|_| Errors::InvalidDataFormat)?,
+            );
+            let verified = data[offset + 32] != 0;
+            let share = data[offset + 33];
+            offset += 34;
+
+            total_share += share as u16;
+            creators.push(NftCreator { address, verified, share });
+        }
+
+        if creator_count > 0 {
+            require!(total_share == 100, Errors::InvalidParameter);
+        }
+
+        Ok((seller_fee_basis_points, creators, offset))
+    }
+
+    /// Decode the optional `Uses` section: a `has_uses: u8` flag followed by
+    /// `(use_method: u8, remaining: u64, total: u64)` when the flag is `1`.
+    fn decode_uses_section(data: &[u8], offset: usize) -> Result<(Option<NftUses>, usize)> {
+        if offset == data.len() {
+            return Ok((None, offset));
+        }
+
+        let has_uses = data[offset];
+        let offset = offset + 1;
+
+        if has_uses == 0 {
+            return Ok((None, offset));
+        }
+
+        if offset + 17 > data.len() {
+            return Err(Errors::InvalidDataFormat.into());
+        }
+
+        let use_method = data[offset];
+        require!(use_method <= 2, Errors::InvalidParameter);
+
+        let remaining = u64::from_le_bytes(
+            data[offset + 1..offset + 9].try_into().map_err(|_| Errors::InvalidDataFormat)?,
+        );
+        let total = u64::from_le_bytes(
+            data[offset + 9..offset + 17].try_into().map_err(|_| Errors::InvalidDataFormat)?,
+        );
+        require!(remaining <= total, Errors::InvalidParameter);
+
+        Ok((Some(NftUses { use_method, remaining, total }), offset + 17))
     }
 
     fn decode_string(data: &[u8], mut offset: usize) -> Result<(String, usize)> {
@@ -73,6 +278,141 @@ impl CrossChainDataDecoder {
     }
 }
 
+/// Fields of a [`Payload3Codec`] message, shaped before being folded into
+/// `decode_nft_data`'s tuple for compatibility with that function's existing callers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Payload3Data {
+    /// Origin-chain token contract address/id, right-padded into a 32-byte word.
+    pub token_address: [u8; 32],
+    pub origin_chain: u16,
+    pub symbol: String,
+    pub name: String,
+    pub token_id: [u8; 32],
+    pub uri: String,
+    /// `msg.sender` on the initiating chain, as a 32-byte ABI-style word (12 zero bytes +
+    /// 20-byte address). Lets the destination contract authenticate who actually started
+    /// the transfer instead of trusting an unauthenticated relayer.
+    pub sender: [u8; 32],
+    /// Recipient address, as a 32-byte ABI-style word (12 zero bytes + 20-byte address).
+    pub recipient: [u8; 32],
+    pub destination_chain: u16,
+}
+
+const PAYLOAD3_SYMBOL_LEN: usize = 32;
+const PAYLOAD3_NAME_LEN: usize = 32;
+// id(1) + token_address(32) + origin_chain(2) + symbol(32) + name(32) + token_id(32) + uri_len(1),
+// i.e. everything before the variable-length URI.
+const PAYLOAD3_HEAD_LEN: usize = 1 + 32 + 2 + PAYLOAD3_SYMBOL_LEN + PAYLOAD3_NAME_LEN + 32 + 1;
+// sender(32) + recipient(32) + destination_chain(2), i.e. everything after the URI.
+const PAYLOAD3_TAIL_LEN: usize = 32 + 32 + 2;
+
+/// Fixed packed big-endian codec for cross-chain NFT payloads exchanged with an
+/// EVM/ZetaChain universal contract, modeled on the Wormhole NFT bridge's "Payload 3"
+/// (transfer-with-payload) wire format: 1-byte payload id, 32-byte token address/id,
+/// 2-byte origin chain, 32-byte right-padded symbol, 32-byte right-padded name, 32-byte
+/// token id, 1-byte URI length + URI bytes, 32-byte `msg.sender`, 32-byte recipient,
+/// 2-byte destination chain.
+pub struct Payload3Codec;
+
+impl Payload3Codec {
+    pub fn encode(payload: &Payload3Data) -> Result<Vec<u8>> {
+        require!(payload.symbol.len() <= PAYLOAD3_SYMBOL_LEN, Errors::SymbolTooLong);
+        require!(payload.name.len() <= PAYLOAD3_NAME_LEN, Errors::NameTooLong);
+        require!(payload.uri.len() <= u8::MAX as usize, Errors::UriTooLong);
+
+        let mut out = Vec::with_capacity(PAYLOAD3_HEAD_LEN + payload.uri.len() + PAYLOAD3_TAIL_LEN);
+        out.push(PAYLOAD_ID_V3);
+        out.extend_from_slice(&payload.token_address);
+        out.extend_from_slice(&payload.origin_chain.to_be_bytes());
+        out.extend_from_slice(&Self::pad_right(&payload.symbol, PAYLOAD3_SYMBOL_LEN));
+        out.extend_from_slice(&Self::pad_right(&payload.name, PAYLOAD3_NAME_LEN));
+        out.extend_from_slice(&payload.token_id);
+        out.push(payload.uri.len() as u8);
+        out.extend_from_slice(payload.uri.as_bytes());
+        out.extend_from_slice(&payload.sender);
+        out.extend_from_slice(&payload.recipient);
+        out.extend_from_slice(&payload.destination_chain.to_be_bytes());
+
+        Ok(out)
+    }
+
+    /// Rejects a buffer that's short anywhere along the fixed/variable layout and one
+    /// with trailing bytes left over after the last fixed field, trims the null padding
+    /// off `name`/`symbol`, and enforces [`CrossChainDataDecoder::validate_decoded_data`]
+    /// the same way the legacy decoder does.
+    pub fn decode(data: &[u8]) -> Result<Payload3Data> {
+        require!(data.len() >= PAYLOAD3_HEAD_LEN, Errors::InvalidDataFormat);
+        require!(data[0] == PAYLOAD_ID_V3, Errors::InvalidDataFormat);
+
+        let mut offset = 1;
+
+        let token_address: [u8; 32] = data[offset..offset + 32].try_into()
+            .map_err(|_| Errors::InvalidDataFormat)?;
+        offset += 32;
+
+        let origin_chain = u16::from_be_bytes(
+            data[offset..offset + 2].try_into().map_err(|_| Errors::InvalidDataFormat)?,
+        );
+        offset += 2;
+
+        let symbol = Self::trim_padded(&data[offset..offset + PAYLOAD3_SYMBOL_LEN])?;
+        offset += PAYLOAD3_SYMBOL_LEN;
+
+        let name = Self::trim_padded(&data[offset..offset + PAYLOAD3_NAME_LEN])?;
+        offset += PAYLOAD3_NAME_LEN;
+
+        let token_id: [u8; 32] = data[offset..offset + 32].try_into()
+            .map_err(|_| Errors::InvalidDataFormat)?;
+        offset += 32;
+
+        let uri_len = data[offset] as usize;
+        offset += 1;
+
+        require!(data.len() == offset + uri_len + PAYLOAD3_TAIL_LEN, Errors::InvalidDataFormat);
+
+        let uri = String::from_utf8(data[offset..offset + uri_len].to_vec())
+            .map_err(|_| Errors::InvalidDataFormat)?;
+        offset += uri_len;
+
+        let sender: [u8; 32] = data[offset..offset + 32].try_into()
+            .map_err(|_| Errors::InvalidDataFormat)?;
+        offset += 32;
+
+        let recipient: [u8; 32] = data[offset..offset + 32].try_into()
+            .map_err(|_| Errors::InvalidDataFormat)?;
+        offset += 32;
+
+        let destination_chain = u16::from_be_bytes(
+            data[offset..offset + 2].try_into().map_err(|_| Errors::InvalidDataFormat)?,
+        );
+
+        CrossChainDataDecoder::validate_decoded_data(&name, &symbol, &uri)?;
+
+        Ok(Payload3Data {
+            token_address,
+            origin_chain,
+            symbol,
+            name,
+            token_id,
+            uri,
+            sender,
+            recipient,
+            destination_chain,
+        })
+    }
+
+    fn pad_right(s: &str, width: usize) -> Vec<u8> {
+        let mut buf = vec![0u8; width];
+        buf[..s.len()].copy_from_slice(s.as_bytes());
+        buf
+    }
+
+    fn trim_padded(field: &[u8]) -> Result<String> {
+        let end = field.iter().position(|b| *b == 0).unwrap_or(field.len());
+        String::from_utf8(field[..end].to_vec()).map_err(|_| Errors::InvalidDataFormat.into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -86,7 +426,7 @@ mod tests {
         let name = "Test NFT".to_string();
         let symbol = "TNFT".to_string();
 
-        let mut data = Vec::new();
+        let mut data = vec![PAYLOAD_VERSION_LEGACY_LE];
         data.extend_from_slice(&token_id);
         data.extend_from_slice(&origin_chain.to_le_bytes());
         data.extend_from_slice(&recipient);
@@ -107,6 +447,202 @@ mod tests {
         assert_eq!(result.3, uri);
         assert_eq!(result.4, name);
         assert_eq!(result.5, symbol);
+        assert_eq!(result.6, None);
+        assert_eq!(result.9, None);
+        assert_eq!(result.10, [0u8; 20]);
+    }
+
+    #[test]
+    fn test_decode_nft_data_with_collection_id() {
+        let token_id = [1u8; 32];
+        let origin_chain = 1u64;
+        let recipient = [2u8; 20];
+        let uri = "https://example.com/metadata.json".to_string();
+        let name = "Test NFT".to_string();
+        let symbol = "TNFT".to_string();
+        let collection_id = Pubkey::new_unique();
+
+        let mut data = vec![PAYLOAD_VERSION_LEGACY_LE];
+        data.extend_from_slice(&token_id);
+        data.extend_from_slice(&origin_chain.to_le_bytes());
+        data.extend_from_slice(&recipient);
+
+        data.extend_from_slice(&(uri.len() as u32).to_le_bytes());
+        data.extend_from_slice(uri.as_bytes());
+
+        data.extend_from_slice(&(name.len() as u32).to_le_bytes());
+        data.extend_from_slice(name.as_bytes());
+
+        data.extend_from_slice(&(symbol.len() as u32).to_le_bytes());
+        data.extend_from_slice(symbol.as_bytes());
+
+        data.push(1); // has_collection
+        data.extend_from_slice(&collection_id.to_bytes());
+
+        let result = CrossChainDataDecoder::decode_nft_data(&data).unwrap();
+        assert_eq!(result.6, Some(collection_id));
+        assert_eq!(result.7, 0);
+        assert!(result.8.is_empty());
+        assert_eq!(result.9, None);
+    }
+
+    #[test]
+    fn test_decode_nft_data_with_royalties() {
+        let token_id = [1u8; 32];
+        let origin_chain = 1u64;
+        let recipient = [2u8; 20];
+        let uri = "https://example.com/metadata.json".to_string();
+        let name = "Test NFT".to_string();
+        let symbol = "TNFT".to_string();
+        let creator_one = Pubkey::new_unique();
+        let creator_two = Pubkey::new_unique();
+
+        let mut data = vec![PAYLOAD_VERSION_LEGACY_LE];
+        data.extend_from_slice(&token_id);
+        data.extend_from_slice(&origin_chain.to_le_bytes());
+        data.extend_from_slice(&recipient);
+
+        data.extend_from_slice(&(uri.len() as u32).to_le_bytes());
+        data.extend_from_slice(uri.as_bytes());
+
+        data.extend_from_slice(&(name.len() as u32).to_le_bytes());
+        data.extend_from_slice(name.as_bytes());
+
+        data.extend_from_slice(&(symbol.len() as u32).to_le_bytes());
+        data.extend_from_slice(symbol.as_bytes());
+
+        data.push(0); // has_collection
+
+        data.extend_from_slice(&500u16.to_le_bytes()); // seller_fee_basis_points
+        data.push(2); // creator count
+        data.extend_from_slice(&creator_one.to_bytes());
+        data.push(1); // verified
+        data.push(70); // share
+        data.extend_from_slice(&creator_two.to_bytes());
+        data.push(0); // verified
+        data.push(30); // share
+
+        let result = CrossChainDataDecoder::decode_nft_data(&data).unwrap();
+        assert_eq!(result.6, None);
+        assert_eq!(result.7, 500);
+        assert_eq!(result.8.len(), 2);
+        assert_eq!(result.8[0].address, creator_one);
+        assert_eq!(result.8[0].share, 70);
+        assert_eq!(result.8[1].share, 30);
+        assert_eq!(result.9, None);
+    }
+
+    #[test]
+    fn test_decode_nft_data_with_uses() {
+        let token_id = [1u8; 32];
+        let origin_chain = 1u64;
+        let recipient = [2u8; 20];
+        let uri = "https://example.com/metadata.json".to_string();
+        let name = "Test NFT".to_string();
+        let symbol = "TNFT".to_string();
+
+        let mut data = vec![PAYLOAD_VERSION_LEGACY_LE];
+        data.extend_from_slice(&token_id);
+        data.extend_from_slice(&origin_chain.to_le_bytes());
+        data.extend_from_slice(&recipient);
+
+        data.extend_from_slice(&(uri.len() as u32).to_le_bytes());
+        data.extend_from_slice(uri.as_bytes());
+
+        data.extend_from_slice(&(name.len() as u32).to_le_bytes());
+        data.extend_from_slice(name.as_bytes());
+
+        data.extend_from_slice(&(symbol.len() as u32).to_le_bytes());
+        data.extend_from_slice(symbol.as_bytes());
+
+        data.push(0); // has_collection
+        data.extend_from_slice(&0u16.to_le_bytes()); // seller_fee_basis_points
+        data.push(0); // creator count
+
+        data.push(1); // has_uses
+        data.push(1); // use_method: Multiple
+        data.extend_from_slice(&7u64.to_le_bytes()); // remaining
+        data.extend_from_slice(&10u64.to_le_bytes()); // total
+
+        let result = CrossChainDataDecoder::decode_nft_data(&data).unwrap();
+        assert_eq!(
+            result.9,
+            Some(NftUses {
+                use_method: 1,
+                remaining: 7,
+                total: 10,
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_nft_data_rejects_uses_remaining_over_total() {
+        let token_id = [1u8; 32];
+        let origin_chain = 1u64;
+        let recipient = [2u8; 20];
+        let uri = "https://example.com/metadata.json".to_string();
+        let name = "Test NFT".to_string();
+        let symbol = "TNFT".to_string();
+
+        let mut data = vec![PAYLOAD_VERSION_LEGACY_LE];
+        data.extend_from_slice(&token_id);
+        data.extend_from_slice(&origin_chain.to_le_bytes());
+        data.extend_from_slice(&recipient);
+
+        data.extend_from_slice(&(uri.len() as u32).to_le_bytes());
+        data.extend_from_slice(uri.as_bytes());
+
+        data.extend_from_slice(&(name.len() as u32).to_le_bytes());
+        data.extend_from_slice(name.as_bytes());
+
+        data.extend_from_slice(&(symbol.len() as u32).to_le_bytes());
+        data.extend_from_slice(symbol.as_bytes());
+
+        data.push(0); // has_collection
+        data.extend_from_slice(&0u16.to_le_bytes());
+        data.push(0); // creator count
+
+        data.push(1); // has_uses
+        data.push(0); // use_method: Burn
+        data.extend_from_slice(&10u64.to_le_bytes()); // remaining, greater than total below
+        data.extend_from_slice(&5u64.to_le_bytes()); // total
+
+        assert!(CrossChainDataDecoder::decode_nft_data(&data).is_err());
+    }
+
+    #[test]
+    fn test_decode_nft_data_rejects_bad_creator_shares() {
+        let token_id = [1u8; 32];
+        let origin_chain = 1u64;
+        let recipient = [2u8; 20];
+        let uri = "https://example.com/metadata.json".to_string();
+        let name = "Test NFT".to_string();
+        let symbol = "TNFT".to_string();
+        let creator_one = Pubkey::new_unique();
+
+        let mut data = vec![PAYLOAD_VERSION_LEGACY_LE];
+        data.extend_from_slice(&token_id);
+        data.extend_from_slice(&origin_chain.to_le_bytes());
+        data.extend_from_slice(&recipient);
+
+        data.extend_from_slice(&(uri.len() as u32).to_le_bytes());
+        data.extend_from_slice(uri.as_bytes());
+
+        data.extend_from_slice(&(name.len() as u32).to_le_bytes());
+        data.extend_from_slice(name.as_bytes());
+
+        data.extend_from_slice(&(symbol.len() as u32).to_le_bytes());
+        data.extend_from_slice(symbol.as_bytes());
+
+        data.push(0); // has_collection
+
+        data.extend_from_slice(&500u16.to_le_bytes());
+        data.push(1); // creator count
+        data.extend_from_slice(&creator_one.to_bytes());
+        data.push(1);
+        data.push(50); // share of 50, but only one creator - shares must sum to 100
+
+        assert!(CrossChainDataDecoder::decode_nft_data(&data).is_err());
     }
 
     #[test]
@@ -114,4 +650,101 @@ mod tests {
         let invalid_data = [1u8; 30];
         assert!(CrossChainDataDecoder::decode_nft_data(&invalid_data).is_err());
     }
+
+    fn sample_payload3() -> Payload3Data {
+        let mut token_address = [0u8; 32];
+        token_address[12..].copy_from_slice(&[0x11u8; 20]);
+        let mut sender = [0u8; 32];
+        sender[12..].copy_from_slice(&[0x33u8; 20]);
+        let mut recipient = [0u8; 32];
+        recipient[12..].copy_from_slice(&[0x22u8; 20]);
+
+        Payload3Data {
+            token_address,
+            origin_chain: 7,
+            symbol: "TNFT".to_string(),
+            name: "Test NFT".to_string(),
+            token_id: [3u8; 32],
+            uri: "https://example.com/metadata.json".to_string(),
+            sender,
+            recipient,
+            destination_chain: 900,
+        }
+    }
+
+    #[test]
+    fn test_payload3_codec_round_trips() {
+        let payload = sample_payload3();
+        let encoded = Payload3Codec::encode(&payload).unwrap();
+        let decoded = Payload3Codec::decode(&encoded).unwrap();
+        assert_eq!(decoded, payload);
+    }
+
+    #[test]
+    fn test_decode_nft_data_dispatches_to_payload3() {
+        let payload = sample_payload3();
+        let encoded = Payload3Codec::encode(&payload).unwrap();
+
+        let result = CrossChainDataDecoder::decode_nft_data(&encoded).unwrap();
+        assert_eq!(result.0, payload.token_id);
+        assert_eq!(result.1, payload.origin_chain as u64);
+        assert_eq!(result.2, [0x22u8; 20]);
+        assert_eq!(result.3, payload.uri);
+        assert_eq!(result.4, payload.name);
+        assert_eq!(result.5, payload.symbol);
+        assert_eq!(result.6, None);
+        assert_eq!(result.9, None);
+        assert_eq!(result.10, [0x33u8; 20]);
+    }
+
+    #[test]
+    fn test_payload3_codec_trims_null_padding_on_name_and_symbol() {
+        let mut payload = sample_payload3();
+        payload.name = "Short".to_string();
+        payload.symbol = "S".to_string();
+        let encoded = Payload3Codec::encode(&payload).unwrap();
+        let decoded = Payload3Codec::decode(&encoded).unwrap();
+        assert_eq!(decoded.name, "Short");
+        assert_eq!(decoded.symbol, "S");
+    }
+
+    #[test]
+    fn test_payload3_codec_rejects_short_buffer() {
+        let payload = sample_payload3();
+        let encoded = Payload3Codec::encode(&payload).unwrap();
+        assert!(Payload3Codec::decode(&encoded[..encoded.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_payload3_codec_rejects_trailing_bytes() {
+        let payload = sample_payload3();
+        let mut encoded = Payload3Codec::encode(&payload).unwrap();
+        encoded.push(0xFF);
+        assert!(Payload3Codec::decode(&encoded).is_err());
+    }
+
+    #[test]
+    fn test_payload3_codec_rejects_oversized_uri_length_claim() {
+        let payload = sample_payload3();
+        let mut encoded = Payload3Codec::encode(&payload).unwrap();
+        let uri_len_offset = 1 + 32 + 2 + PAYLOAD3_SYMBOL_LEN + PAYLOAD3_NAME_LEN + 32;
+        encoded[uri_len_offset] = 0xFF; // claim a far longer URI than actually follows
+        assert!(Payload3Codec::decode(&encoded).is_err());
+    }
+
+    #[test]
+    fn test_payload3_codec_enforces_validate_decoded_data_limits() {
+        let mut payload = sample_payload3();
+        // Fits the 32-byte fixed field but still violates `validate_decoded_data`'s
+        // 10-char symbol limit once decoded.
+        payload.symbol = "TOO_LONG_SYMBOL".to_string();
+        let encoded = Payload3Codec::encode(&payload).unwrap();
+        assert!(Payload3Codec::decode(&encoded).is_err());
+    }
+
+    #[test]
+    fn test_decode_nft_data_rejects_unknown_version_byte() {
+        let data = vec![99u8; 80];
+        assert!(CrossChainDataDecoder::decode_nft_data(&data).is_err());
+    }
 }
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::UniversalNftError;
+
 /// Encode the Anchor instruction data for the ZetaChain Gateway `call` method.
 /// Layout per Anchor: [discriminator(8)] + receiver([u8;20]) + message(Vec<u8>) + revert_options(Option<RevertOptions>)
 /// - receiver: fixed 20 bytes
@@ -101,47 +103,86 @@ pub fn encode_evm_oncall_message(
     gas_amount: u64,
     sender: [u8; 20],
 ) -> Vec<u8> {
-    // Head = 5 * 32 bytes
-    let head_len = 32 * 5;
-    let uri_bytes = uri.as_bytes();
-    let uri_len = uri_bytes.len();
-    let uri_padded_len = ((uri_len + 31) / 32) * 32;
+    let mut gas_word = [0u8; 32];
+    gas_word[24..].copy_from_slice(&gas_amount.to_be_bytes());
 
-    let total_len = head_len + 32 /*len*/ + uri_padded_len;
-    let mut out = Vec::with_capacity(total_len);
+    crate::abi::encode_params(&[
+        crate::abi::AbiValue::Address(receiver),
+        crate::abi::AbiValue::Uint256(token_id_be32),
+        crate::abi::AbiValue::String(uri.to_string()),
+        crate::abi::AbiValue::Uint256(gas_word),
+        crate::abi::AbiValue::Address(sender),
+    ])
+}
 
-    // 1) receiver address
-    out.extend_from_slice(&[0u8; 12]);
-    out.extend_from_slice(&receiver);
-    // 2) tokenId (32 bytes, already big-endian)
-    out.extend_from_slice(&token_id_be32);
-    // 3) offset to string (from start)
-    let offset = (32 * 5) as u64;
-    let mut off_buf = [0u8; 32];
-    let off_bytes = offset.to_be_bytes();
-    off_buf[32 - off_bytes.len()..].copy_from_slice(&off_bytes);
-    out.extend_from_slice(&off_buf);
-    // 4) gasAmount (u256 big-endian from u64)
-    let mut gas_buf = [0u8; 32];
-    let gas_be = gas_amount.to_be_bytes();
-    gas_buf[32 - gas_be.len()..].copy_from_slice(&gas_be);
-    out.extend_from_slice(&gas_buf);
-    // 5) sender address
-    out.extend_from_slice(&[0u8; 12]);
-    out.extend_from_slice(&sender);
+/// Inverse of [`encode_evm_nft_message`]: decode `(address destination, address receiver,
+/// uint256 tokenId, string uri, address sender)` back out of the ABI-encoded bytes.
+/// Offset/length bounds and UTF-8 validity are enforced by [`crate::abi::decode_params`], so a
+/// malformed inbound payload returns `UniversalNftError::InvalidMessage` rather than
+/// panicking.
+pub fn decode_evm_nft_message(data: &[u8]) -> Result<([u8; 20], [u8; 20], [u8; 32], String, [u8; 20])> {
+    let schema = [
+        crate::abi::AbiKind::Address,
+        crate::abi::AbiKind::Address,
+        crate::abi::AbiKind::Uint256,
+        crate::abi::AbiKind::String,
+        crate::abi::AbiKind::Address,
+    ];
+    let values = crate::abi::decode_params(&schema, data).map_err(|_| UniversalNftError::InvalidMessage)?;
 
-    // Tail: string (len + bytes + padding)
-    let mut len_buf = [0u8; 32];
-    let len_be = (uri_len as u64).to_be_bytes();
-    len_buf[32 - len_be.len()..].copy_from_slice(&len_be);
-    out.extend_from_slice(&len_buf);
-    out.extend_from_slice(uri_bytes);
-    let pad_len = uri_padded_len - uri_len;
-    if pad_len > 0 {
-        out.extend_from_slice(&vec![0u8; pad_len]);
+    let destination = expect_address(&values[0])?;
+    let receiver = expect_address(&values[1])?;
+    let token_id_be32 = expect_uint256(&values[2])?;
+    let uri = expect_string(&values[3])?;
+    let sender = expect_address(&values[4])?;
+
+    Ok((destination, receiver, token_id_be32, uri, sender))
+}
+
+/// Inverse of [`encode_evm_oncall_message`]: decode `(address receiver, uint256 tokenId,
+/// string uri, uint256 gasAmount, address sender)` back out of the ABI-encoded bytes.
+pub fn decode_evm_oncall_message(data: &[u8]) -> Result<([u8; 20], [u8; 32], String, u64, [u8; 20])> {
+    let schema = [
+        crate::abi::AbiKind::Address,
+        crate::abi::AbiKind::Uint256,
+        crate::abi::AbiKind::String,
+        crate::abi::AbiKind::Uint256,
+        crate::abi::AbiKind::Address,
+    ];
+    let values = crate::abi::decode_params(&schema, data).map_err(|_| UniversalNftError::InvalidMessage)?;
+
+    let receiver = expect_address(&values[0])?;
+    let token_id_be32 = expect_uint256(&values[1])?;
+    let uri = expect_string(&values[2])?;
+    let gas_word = expect_uint256(&values[3])?;
+    require!(gas_word[..24].iter().all(|b| *b == 0), UniversalNftError::InvalidMessage);
+    let mut gas_bytes = [0u8; 8];
+    gas_bytes.copy_from_slice(&gas_word[24..]);
+    let gas_amount = u64::from_be_bytes(gas_bytes);
+    let sender = expect_address(&values[4])?;
+
+    Ok((receiver, token_id_be32, uri, gas_amount, sender))
+}
+
+fn expect_address(value: &crate::abi::AbiValue) -> Result<[u8; 20]> {
+    match value {
+        crate::abi::AbiValue::Address(addr) => Ok(*addr),
+        _ => Err(UniversalNftError::InvalidMessage.into()),
     }
+}
 
-    out
+fn expect_uint256(value: &crate::abi::AbiValue) -> Result<[u8; 32]> {
+    match value {
+        crate::abi::AbiValue::Uint256(bytes) => Ok(*bytes),
+        _ => Err(UniversalNftError::InvalidMessage.into()),
+    }
+}
+
+fn expect_string(value: &crate::abi::AbiValue) -> Result<String> {
+    match value {
+        crate::abi::AbiValue::String(s) => Ok(s.clone()),
+        _ => Err(UniversalNftError::InvalidMessage.into()),
+    }
 }
 
 #[cfg(test)]
@@ -176,4 +217,77 @@ mod tests {
         assert_eq!(&data[40..42], &message[..]);
         assert_eq!(data[42], 0u8); // None
     }
+
+    #[test]
+    fn test_decode_evm_nft_message_round_trips() {
+        let destination = [0x01u8; 20];
+        let receiver = [0x02u8; 20];
+        let mut token_id_be32 = [0u8; 32];
+        token_id_be32[31] = 42;
+        let uri = "ipfs://example-metadata";
+        let sender = [0x03u8; 20];
+
+        let encoded = encode_evm_nft_message(destination, receiver, token_id_be32, uri, sender);
+        let (d, r, t, u, s) = decode_evm_nft_message(&encoded).unwrap();
+        assert_eq!(d, destination);
+        assert_eq!(r, receiver);
+        assert_eq!(t, token_id_be32);
+        assert_eq!(u, uri);
+        assert_eq!(s, sender);
+    }
+
+    #[test]
+    fn test_decode_evm_oncall_message_round_trips() {
+        let receiver = [0x04u8; 20];
+        let mut token_id_be32 = [0u8; 32];
+        token_id_be32[31] = 7;
+        let uri = "";
+        let gas_amount = 150_000u64;
+        let sender = [0x05u8; 20];
+
+        let encoded = encode_evm_oncall_message(receiver, token_id_be32, uri, gas_amount, sender);
+        let (r, t, u, g, s) = decode_evm_oncall_message(&encoded).unwrap();
+        assert_eq!(r, receiver);
+        assert_eq!(t, token_id_be32);
+        assert_eq!(u, uri);
+        assert_eq!(g, gas_amount);
+        assert_eq!(s, sender);
+    }
+
+    #[test]
+    fn test_decode_evm_nft_message_rejects_offset_past_buffer() {
+        let mut data = vec![0u8; 32 * 5];
+        // Overwrite the uri offset word (index 3) with a value past the buffer.
+        data[3 * 32 + 24..3 * 32 + 32].copy_from_slice(&(10_000u64).to_be_bytes());
+        assert!(decode_evm_nft_message(&data).is_err());
+    }
+
+    #[test]
+    fn test_decode_evm_nft_message_rejects_length_exceeding_remaining_bytes() {
+        let destination = [0x01u8; 20];
+        let receiver = [0x02u8; 20];
+        let token_id_be32 = [0u8; 32];
+        let sender = [0x03u8; 20];
+        let mut encoded = encode_evm_nft_message(destination, receiver, token_id_be32, "short", sender);
+        // Corrupt the string length word (right after the 5-word head) to claim more
+        // bytes than actually follow.
+        let len_word_start = 32 * 5;
+        encoded[len_word_start..len_word_start + 32].copy_from_slice(&[0u8; 32]);
+        encoded[len_word_start + 24..len_word_start + 32].copy_from_slice(&(10_000u64).to_be_bytes());
+        assert!(decode_evm_nft_message(&encoded).is_err());
+    }
+
+    #[test]
+    fn test_decode_evm_nft_message_rejects_non_utf8_uri() {
+        let destination = [0x01u8; 20];
+        let receiver = [0x02u8; 20];
+        let token_id_be32 = [0u8; 32];
+        let sender = [0x03u8; 20];
+        let mut encoded = encode_evm_nft_message(destination, receiver, token_id_be32, "ok", sender);
+        let len_word_start = 32 * 5;
+        // uri bytes start right after the length word; corrupt them with invalid UTF-8.
+        encoded[len_word_start + 32] = 0xFF;
+        encoded[len_word_start + 33] = 0xFE;
+        assert!(decode_evm_nft_message(&encoded).is_err());
+    }
 }
@@ -1,17 +1,95 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::{
-    program::invoke,
     account_info::AccountInfo,
+    program::{invoke, invoke_signed},
+    system_instruction,
 };
-use crate::error::UniversalNftError;
-use crate::util::constants::DEFAULT_SELLER_FEE_BASIS_POINTS;
+use crate::UniversalNftError;
 use mpl_token_metadata::instructions::{
     CreateMetadataAccountV3Builder,
     CreateMasterEditionV3Builder,
+    MintNewEditionFromMasterEditionViaToken,
+    MintNewEditionFromMasterEditionViaTokenInstructionArgs,
+    UpdateMetadataAccountV2,
+    UpdateMetadataAccountV2InstructionArgs,
+    VerifyCollectionBuilder,
 };
-use mpl_token_metadata::types::DataV2;
+use mpl_token_metadata::types::{Collection, Creator, DataV2, MintNewEditionFromMasterEditionViaTokenArgs};
 
-/// Create metadata account using CPI to Metaplex Token Metadata program
+/// Metaplex's Token Metadata program silently enforces these bounds and fails deep
+/// inside its own instruction handler, after the CPI's compute cost is already spent, if
+/// violated. Checking them here first - mirroring Metaplex's own `assert_data_valid` -
+/// gives callers a precise error instead of a generic `MetadataCreationFailed`, which
+/// matters since these bytes often arrive untrusted from a cross-chain message payload.
+fn assert_metadata_valid(name: &str, symbol: &str, uri: &str, seller_fee_basis_points: u16) -> Result<()> {
+    require!(name.len() <= 32, UniversalNftError::NameTooLong);
+    require!(symbol.len() <= 10, UniversalNftError::SymbolTooLong);
+    require!(uri.len() <= 200, UniversalNftError::UriTooLong);
+    require!(seller_fee_basis_points <= 10000, UniversalNftError::InvalidSellerFeeBasisPoints);
+    Ok(())
+}
+
+// Approximate on-chain sizes for a Metaplex `Metadata`/`MasterEditionV2` account.
+const METADATA_ACCOUNT_SIZE: usize = 679;
+const MASTER_EDITION_ACCOUNT_SIZE: usize = 282;
+
+fn rent_shortfall(current_lamports: u64, required_lamports: u64) -> u64 {
+    required_lamports.saturating_sub(current_lamports)
+}
+
+/// Top up `target` to the rent-exempt minimum for `required_space`, transferring only the
+/// shortfall - or nothing at all if it's already funded - rather than unconditionally
+/// dispatching a full-amount transfer. Mirrors the account-funding half of Metaplex's own
+/// (now-historic) `create_or_allocate_account_raw`: skipping the System Program invoke
+/// entirely on the common already-funded path (e.g. a PDA that received lamports from an
+/// earlier step in the same instruction) saves the compute of building and dispatching a
+/// transfer that would move zero lamports.
+fn fund_rent_exempt_if_needed<'a>(
+    target: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    required_space: usize,
+    payer_signer_seeds: Option<&[&[&[u8]]]>,
+) -> Result<()> {
+    let required_lamports = Rent::get()?.minimum_balance(required_space);
+    let shortfall = rent_shortfall(target.lamports(), required_lamports);
+    if shortfall == 0 {
+        return Ok(());
+    }
+
+    let transfer_ix = system_instruction::transfer(payer.key, target.key, shortfall);
+    let accounts = [payer.clone(), target.clone(), system_program.clone()];
+    match payer_signer_seeds {
+        Some(seeds) => invoke_signed(&transfer_ix, &accounts, seeds),
+        None => invoke(&transfer_ix, &accounts),
+    }
+    .map_err(|_| UniversalNftError::MetadataCreationFailed.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rent_shortfall_is_zero_when_already_funded() {
+        assert_eq!(rent_shortfall(2_000_000, 1_500_000), 0);
+        assert_eq!(rent_shortfall(1_500_000, 1_500_000), 0);
+    }
+
+    #[test]
+    fn rent_shortfall_covers_only_the_gap() {
+        assert_eq!(rent_shortfall(500_000, 1_500_000), 1_000_000);
+        assert_eq!(rent_shortfall(0, 1_500_000), 1_500_000);
+    }
+}
+
+/// Create metadata account using CPI to Metaplex Token Metadata program.
+///
+/// `creators` carries the origin-chain royalty split across verbatim rather than
+/// dropping it, so a bridged NFT keeps its real creators instead of landing with none.
+/// Metaplex only marks a creator `verified` if that creator signs the instruction, so
+/// only the entry matching `authority` (the signer here) is flagged verified; everyone
+/// else is recorded unverified, same as a direct `mint_nft` creator list.
 pub fn create_metadata_account<'a>(
     metadata: &AccountInfo<'a>,
     mint: &AccountInfo<'a>,
@@ -22,16 +100,41 @@ pub fn create_metadata_account<'a>(
     name: &str,
     symbol: &str,
     uri: &str,
+    seller_fee_basis_points: u16,
+    creators: Vec<Creator>,
+    collection_mint: Option<Pubkey>,
     authority_signer_seeds: Option<&[&[&[u8]]]>,
 ) -> Result<()> {
-    // Build CreateMetadataAccountV3 using builder API
+    assert_metadata_valid(name, symbol, uri, seller_fee_basis_points)?;
+    // `payer` funds this transfer directly as a transaction signer, not via a PDA, so no
+    // signer seeds are needed here even when `authority_signer_seeds` is set for the CPI below.
+    fund_rent_exempt_if_needed(metadata, payer, system_program, METADATA_ACCOUNT_SIZE, None)?;
+
+    let creators = if creators.is_empty() {
+        None
+    } else {
+        Some(
+            creators
+                .into_iter()
+                .map(|c| Creator {
+                    verified: c.address == *authority.key,
+                    ..c
+                })
+                .collect(),
+        )
+    };
+
+    // Build CreateMetadataAccountV3 using builder API. `collection` is recorded
+    // unverified here - `verify_collection_item` below runs a separate CPI to flip it
+    // once this metadata account actually exists, since collection verification needs
+    // accounts (the collection's master edition/authority) this call doesn't touch.
     let data = DataV2 {
         name: name.to_string(),
         symbol: symbol.to_string(),
         uri: uri.to_string(),
-        seller_fee_basis_points: DEFAULT_SELLER_FEE_BASIS_POINTS,
-        creators: None,
-        collection: None,
+        seller_fee_basis_points,
+        creators,
+        collection: collection_mint.map(|key| Collection { verified: false, key }),
         uses: None,
     };
 
@@ -76,7 +179,89 @@ pub fn create_metadata_account<'a>(
     Ok(())
 }
 
-/// Create master edition account using CPI to Metaplex Token Metadata program
+/// Verify this NFT's membership in a Metaplex Certified Collection via a
+/// `VerifyCollection` CPI, signed by the collection authority PDA. Must run after
+/// `create_metadata_account` has created the NFT's metadata (with `DataV2.collection`
+/// already pointing at `collection_mint`, unverified) since verification itself needs
+/// the collection's own master edition and authority accounts.
+pub fn verify_collection_item<'a>(
+    metadata: &AccountInfo<'a>,
+    collection_authority: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    collection_mint: &AccountInfo<'a>,
+    collection_metadata: &AccountInfo<'a>,
+    collection_master_edition: &AccountInfo<'a>,
+    authority_signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let ix = VerifyCollectionBuilder::new()
+        .metadata(*metadata.key)
+        .collection_authority(*collection_authority.key)
+        .payer(*payer.key)
+        .collection_mint(*collection_mint.key)
+        .collection(*collection_metadata.key)
+        .collection_master_edition_account(*collection_master_edition.key)
+        .instruction();
+
+    invoke_signed(
+        &ix,
+        &[
+            metadata.clone(),
+            collection_authority.clone(),
+            payer.clone(),
+            collection_mint.clone(),
+            collection_metadata.clone(),
+            collection_master_edition.clone(),
+        ],
+        authority_signer_seeds,
+    ).map_err(|_| UniversalNftError::MetadataCreationFailed.into())
+}
+
+/// Update an existing metadata account via CPI to Metaplex's `UpdateMetadataAccountV2`.
+/// The natural counterpart to `create_metadata_account` (created with `is_mutable: true`):
+/// used when a universal NFT's name/uri changes on its home chain and that change is
+/// re-synced to Solana. Each argument is passed through as `Some(..)`/`None` as-is so
+/// Metaplex only overwrites the fields actually supplied, leaving the rest untouched.
+pub fn update_metadata_account<'a>(
+    metadata: &AccountInfo<'a>,
+    update_authority: &AccountInfo<'a>,
+    data: Option<DataV2>,
+    new_update_authority: Option<Pubkey>,
+    primary_sale_happened: Option<bool>,
+    is_mutable: Option<bool>,
+    authority_signer_seeds: Option<&[&[&[u8]]]>,
+) -> Result<()> {
+    let update_ix = UpdateMetadataAccountV2 {
+        metadata: *metadata.key,
+        update_authority: *update_authority.key,
+    };
+    let ix = update_ix.instruction(UpdateMetadataAccountV2InstructionArgs {
+        data,
+        new_update_authority,
+        primary_sale_happened,
+        is_mutable,
+    });
+
+    match authority_signer_seeds {
+        Some(seeds) => invoke_signed(
+            &ix,
+            &[metadata.clone(), update_authority.clone()],
+            seeds,
+        ).map_err(|_| UniversalNftError::MetadataUpdateFailed)?,
+        None => invoke(
+            &ix,
+            &[metadata.clone(), update_authority.clone()],
+        ).map_err(|_| UniversalNftError::MetadataUpdateFailed)?,
+    }
+
+    Ok(())
+}
+
+/// Create master edition account using CPI to Metaplex Token Metadata program.
+///
+/// `max_supply` controls how many numbered prints `mint_new_edition_from_master_edition`
+/// may later mint from this master: `Some(0)` (or `None` passed through as `Some(0)` by
+/// callers that want a plain 1-of-1) forbids prints entirely, while `Some(n)` for `n > 0`
+/// caps prints at `n` - Metaplex itself enforces the cap via the edition marker PDA.
 pub fn create_master_edition_account<'a>(
     master_edition: &AccountInfo<'a>,
     mint: &AccountInfo<'a>,
@@ -86,8 +271,11 @@ pub fn create_master_edition_account<'a>(
     token_program: &AccountInfo<'a>,
     system_program: &AccountInfo<'a>,
     rent: &AccountInfo<'a>,
+    max_supply: Option<u64>,
     authority_signer_seeds: Option<&[&[&[u8]]]>,
 ) -> Result<()> {
+    fund_rent_exempt_if_needed(master_edition, payer, system_program, MASTER_EDITION_ACCOUNT_SIZE, None)?;
+
     // Build CreateMasterEditionV3 using builder API
     let ix = CreateMasterEditionV3Builder::new()
         .edition(*master_edition.key)
@@ -99,7 +287,7 @@ pub fn create_master_edition_account<'a>(
         .token_program(*token_program.key)
         .system_program(*system_program.key)
         .rent(Some(*rent.key))
-        .max_supply(0u64)
+        .max_supply(max_supply.unwrap_or(0))
         .instruction();
 
     match authority_signer_seeds {
@@ -134,3 +322,72 @@ pub fn create_master_edition_account<'a>(
 
     Ok(())
 }
+
+/// Mint a numbered print from an existing master edition via CPI to Metaplex's
+/// `MintNewEditionFromMasterEditionViaToken`. The caller is responsible for minting the
+/// print's token beforehand (via `mint_to`, signed by `new_mint_authority`) and for
+/// deriving/allocating `edition_marker` - the PDA Metaplex uses to track which edition
+/// numbers have already been printed and to enforce the master's `max_supply`.
+#[allow(clippy::too_many_arguments)]
+pub fn mint_new_edition_from_master_edition<'a>(
+    new_metadata: &AccountInfo<'a>,
+    new_edition: &AccountInfo<'a>,
+    master_edition: &AccountInfo<'a>,
+    new_mint: &AccountInfo<'a>,
+    edition_marker: &AccountInfo<'a>,
+    new_mint_authority: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    token_account_owner: &AccountInfo<'a>,
+    token_account: &AccountInfo<'a>,
+    new_metadata_update_authority: &AccountInfo<'a>,
+    master_metadata: &AccountInfo<'a>,
+    token_program: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    rent: &AccountInfo<'a>,
+    edition_number: u64,
+    authority_signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let ix = MintNewEditionFromMasterEditionViaToken {
+        new_metadata: *new_metadata.key,
+        new_edition: *new_edition.key,
+        master_edition: *master_edition.key,
+        new_mint: *new_mint.key,
+        edition_mark_pda: *edition_marker.key,
+        new_mint_authority: *new_mint_authority.key,
+        payer: *payer.key,
+        token_account_owner: *token_account_owner.key,
+        token_account: *token_account.key,
+        new_metadata_update_authority: *new_metadata_update_authority.key,
+        metadata: *master_metadata.key,
+        token_program: *token_program.key,
+        system_program: *system_program.key,
+        rent: Some(*rent.key),
+    }
+    .instruction(MintNewEditionFromMasterEditionViaTokenInstructionArgs {
+        mint_new_edition_from_master_edition_via_token_args: MintNewEditionFromMasterEditionViaTokenArgs {
+            edition: edition_number,
+        },
+    });
+
+    invoke_signed(
+        &ix,
+        &[
+            new_metadata.clone(),
+            new_edition.clone(),
+            master_edition.clone(),
+            new_mint.clone(),
+            edition_marker.clone(),
+            new_mint_authority.clone(),
+            payer.clone(),
+            token_account_owner.clone(),
+            token_account.clone(),
+            new_metadata_update_authority.clone(),
+            master_metadata.clone(),
+            token_program.clone(),
+            system_program.clone(),
+            rent.clone(),
+        ],
+        authority_signer_seeds,
+    )
+    .map_err(|_| UniversalNftError::MetadataCreationFailed.into())
+}
@@ -3,9 +3,12 @@ pub mod metaplex_helpers;
 pub mod cross_chain_helpers;
 pub mod mint_helpers;
 pub mod gateway_helpers;
+pub mod data_decoder;
+pub mod bridge_constants;
 
 pub use constants::*;
 pub use metaplex_helpers::*;
 pub use cross_chain_helpers::*;
 pub use mint_helpers::*;
 pub use gateway_helpers::*;
+pub use data_decoder::*;
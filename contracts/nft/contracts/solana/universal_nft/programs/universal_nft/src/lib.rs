@@ -8,17 +8,19 @@ use anchor_lang::solana_program::{
 };
 use anchor_spl::{
     associated_token::{self, AssociatedToken},
-    token::{burn, mint_to, Burn, Mint, MintTo, Token, TokenAccount},
+    token_interface::{burn, mint_to, Burn, Mint, MintTo, TokenAccount, TokenInterface},
 };
 use borsh::{BorshDeserialize, BorshSerialize};
 use mpl_token_metadata::{
     instruction::{
         builders::{
-            CreateMasterEditionV3Builder, CreateMetadataAccountV3Builder, SetAndVerifyCollectionBuilder,
+            CreateMasterEditionV3Builder, CreateMetadataAccountV3Builder,
+            MintNewEditionFromMasterEditionViaTokenBuilder, SetAndVerifyCollectionBuilder,
+            UpdateMetadataAccountV2Builder, UtilizeBuilder,
         },
-        InstructionBuilder,
+        InstructionBuilder, MintNewEditionFromMasterEditionViaTokenArgs, UtilizeArgs,
     },
-    state::{Collection, Creator, DataV2, Metadata},
+    state::{Collection, Creator, DataV2, Metadata, TokenMetadataAccount, UseMethod, Uses},
     ID as MetadataID,
 };
 
@@ -38,6 +40,10 @@ mod state {
         pub collection_mint: Pubkey,
         pub bumps: ConfigBumps,
         pub next_token_id: u64,
+        /// Whether NFT metadata created under this config can later be updated via
+        /// `update_metadata`/`on_call_update_metadata`. Set once by `initialize`; no instruction
+        /// in this program changes it afterwards, so a collection cannot switch to immutable later.
+        pub is_mutable: bool,
     }
 
     #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
@@ -52,6 +58,17 @@ mod state {
         pub token_id: [u8; 32],
         pub original_solana_mint: Option<Pubkey>,
         pub mint_count: u64,
+        /// The SPL token program (legacy Token or Token-2022) that owns this NFT's mint,
+        /// so later instructions operating on the mint know which program to pass through.
+        pub token_program: Pubkey,
+        /// Master edition `max_supply`, i.e. how many numbered prints `print_edition` may
+        /// mint from this NFT. `0` means the master edition is a plain one-of-one.
+        pub max_supply: u64,
+        /// The chain this NFT's canonical record originates from. `0` means it was minted
+        /// natively on Solana via `mint_local`; any other value is the chain id of the gateway
+        /// message that first brought it here via `on_call`, and gates which chain is allowed
+        /// to push metadata updates for it through `on_call_update_metadata`.
+        pub origin_chain_id: u32,
     }
 
     #[derive(BorshSerialize, BorshDeserialize)]
@@ -59,6 +76,16 @@ mod state {
         pub sender: [u8; 20],
         pub token_id: [u8; 32],
         pub nonce: u64,
+        pub origin_chain_id: u32,
+        pub sequence: u64,
+    }
+
+    /// Tracks the highest processed `sequence` per `(origin_chain_id, sender)` emitter, so an
+    /// out-of-order or rolled-back nonce from a given emitter is rejected even if it hasn't been
+    /// seen before - a `ProcessedMessage` claim stub alone only catches exact-tuple replays.
+    #[derive(BorshSerialize, BorshDeserialize)]
+    pub struct EmitterSequence {
+        pub highest_sequence: u64,
     }
 }
 
@@ -75,12 +102,99 @@ mod payload {
         pub origin_chain_id: u32,
         pub nonce: u64,
         pub original_solana_mint: Option<Pubkey>,
+        pub seller_fee_basis_points: u16,
+        /// (creator address, royalty share, verified) - mirrors Metaplex's `Creator`.
+        pub creators: Vec<(Pubkey, u8, bool)>,
+        /// (use_method: 0=Burn/1=Multiple/2=Single, total uses) for ticket/voucher-style NFTs.
+        pub uses: Option<(u8, u64)>,
+    }
+
+    /// Carried in a gateway revert call so a locally-burned NFT can be restored to its
+    /// original owner if the destination-chain leg of a cross-chain transfer failed.
+    #[derive(BorshSerialize, BorshDeserialize)]
+    pub struct RevertPayload {
+        pub token_id: [u8; 32],
+        pub origin_chain_id: u32,
+        pub name: String,
+        pub symbol: String,
+        pub uri: String,
+        pub recipient: Pubkey,
+    }
+
+    /// Carried in a gateway call to push a post-mint metadata update (e.g. a reveal) from the
+    /// chain that owns an NFT's canonical record.
+    #[derive(BorshSerialize, BorshDeserialize)]
+    pub struct UpdateMetadataPayload {
+        pub token_id: [u8; 32],
+        pub origin_chain_id: u32,
+        pub name: String,
+        pub symbol: String,
+        pub uri: String,
     }
 }
 
 mod utils {
     use super::*;
 
+    /// Matches Metaplex's own `MAX_CREATOR_LIMIT` for a `DataV2` creators array.
+    const MAX_CREATOR_LIMIT: usize = 5;
+
+    /// Validate `name`/`symbol`/`uri` lengths before they reach the `CreateMetadataAccountV3`
+    /// CPI, mirroring Metaplex's own `assert_data_valid`, so an oversized string from a caller
+    /// argument or a decoded cross-chain payload is rejected cleanly up front instead of
+    /// failing deep inside the token-metadata program after other state has already changed.
+    pub fn validate_metadata_lengths(name: &str, symbol: &str, uri: &str) -> Result<()> {
+        require!(name.len() <= 32, ErrorCode::NameTooLong);
+        require!(symbol.len() <= 10, ErrorCode::SymbolTooLong);
+        require!(uri.len() <= 200, ErrorCode::UriTooLong);
+        Ok(())
+    }
+
+    /// Validate royalty info before it reaches the `CreateMetadataAccountV3` CPI, mirroring
+    /// what Metaplex's own `assert_data_valid` checks, so a malformed cross-chain payload or
+    /// caller argument is rejected cleanly instead of failing deep inside the CPI.
+    pub fn validate_royalty_info(
+        seller_fee_basis_points: u16,
+        creators: &[(Pubkey, u8, bool)],
+    ) -> Result<()> {
+        require!(
+            seller_fee_basis_points <= 10000,
+            ErrorCode::InvalidSellerFeeBasisPoints
+        );
+        require!(
+            creators.len() <= MAX_CREATOR_LIMIT,
+            ErrorCode::TooManyCreators
+        );
+        if !creators.is_empty() {
+            let total_share: u16 = creators.iter().map(|(_, share, _)| *share as u16).sum();
+            require!(total_share == 100, ErrorCode::InvalidCreatorShares);
+        }
+        Ok(())
+    }
+
+    /// Convert the wire representation of a creator list into Metaplex's `Creator` type.
+    ///
+    /// The `verified` bit on the wire is never trusted as-is: none of these creators sign the
+    /// mint transaction (it's a caller-supplied arg in `mint_local`, or decoded straight out of
+    /// a cross-chain payload in `on_call`), so a creator is only marked `verified` here if its
+    /// address matches `authority` - the one party that actually does sign the Metaplex CPI.
+    /// Otherwise any caller could stamp an arbitrary address as a "verified" creator.
+    pub fn to_metaplex_creators(creators: &[(Pubkey, u8, bool)], authority: &Pubkey) -> Option<Vec<Creator>> {
+        if creators.is_empty() {
+            return None;
+        }
+        Some(
+            creators
+                .iter()
+                .map(|(address, share, _verified)| Creator {
+                    address: *address,
+                    verified: address == authority,
+                    share: *share,
+                })
+                .collect(),
+        )
+    }
+
     pub fn check_gateway(instructions_sysvar: &AccountInfo) -> Result<()> {
         let ix_sysvar = Instructions::from_account_info(instructions_sysvar)?;
         let current_ix = Instructions::get_instruction_at(&ix_sysvar, 0)?;
@@ -151,7 +265,7 @@ mod utils {
                 payer.key,
                 wallet.key,
                 mint.key,
-                &anchor_spl::token::ID,
+                token_program.key,
             ),
             &[
                 payer.clone(),
@@ -310,6 +424,199 @@ mod utils {
 
         Ok(())
     }
+
+    /// Mint a numbered print from an existing master edition via CPI to Metaplex's
+    /// `MintNewEditionFromMasterEditionViaToken`. `token_account`/`token_account_owner` are the
+    /// account (and its owner) holding a token of the *master* mint - proof of the right to
+    /// print - not the new edition's recipient. `edition_marker` is the PDA Metaplex uses to
+    /// track which edition numbers have been printed and to enforce the master's `max_supply`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn print_edition_from_master(
+        new_metadata: &AccountInfo,
+        new_edition: &AccountInfo,
+        master_edition: &AccountInfo,
+        new_mint: &AccountInfo,
+        edition_marker: &AccountInfo,
+        new_mint_authority: &AccountInfo,
+        payer: &AccountInfo,
+        token_account_owner: &AccountInfo,
+        token_account: &AccountInfo,
+        new_metadata_update_authority: &AccountInfo,
+        master_metadata: &AccountInfo,
+        master_mint: &AccountInfo,
+        token_program: &AccountInfo,
+        system_program: &AccountInfo,
+        rent: &AccountInfo,
+        edition_number: u64,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let ix = MintNewEditionFromMasterEditionViaTokenBuilder::new()
+            .new_metadata(new_metadata.key())
+            .new_edition(new_edition.key())
+            .master_edition(master_edition.key())
+            .new_mint(new_mint.key())
+            .edition_mark_pda(edition_marker.key())
+            .new_mint_authority(new_mint_authority.key())
+            .payer(payer.key())
+            .token_account_owner(token_account_owner.key())
+            .token_account(token_account.key())
+            .new_metadata_update_authority(new_metadata_update_authority.key())
+            .metadata(master_metadata.key())
+            .metadata_mint(master_mint.key())
+            .mint_new_edition_from_master_edition_via_token_args(
+                MintNewEditionFromMasterEditionViaTokenArgs { edition: edition_number },
+            )
+            .build()
+            .unwrap()
+            .instruction();
+
+        invoke_signed(
+            &ix,
+            &[
+                new_metadata.clone(),
+                new_edition.clone(),
+                master_edition.clone(),
+                new_mint.clone(),
+                edition_marker.clone(),
+                new_mint_authority.clone(),
+                payer.clone(),
+                token_account_owner.clone(),
+                token_account.clone(),
+                new_metadata_update_authority.clone(),
+                master_metadata.clone(),
+                master_mint.clone(),
+                token_program.clone(),
+                system_program.clone(),
+                rent.clone(),
+            ],
+            signer_seeds,
+        )?;
+
+        Ok(())
+    }
+
+    /// Number of edition numbers tracked by a single `edition_marker` PDA - matches Metaplex's
+    /// own `EDITION_MARKER_BIT_SIZE`. Edition `n`'s marker lives at PDA index `n / 248`, and its
+    /// bit within that marker's 31-byte ledger is derived from `n % 248`.
+    const EDITION_MARKER_BIT_SIZE: u64 = 248;
+
+    /// Byte offset (into the marker's 31-byte ledger, which starts right after the 1-byte
+    /// `Key` discriminant) and bit mask for a given `edition_number`'s slot in its marker.
+    fn edition_marker_bit(edition_number: u64) -> (usize, u8) {
+        let offset = edition_number % EDITION_MARKER_BIT_SIZE;
+        let byte_index = 1 + (offset / 8) as usize;
+        let bit_mask = 1u8 << (offset % 8);
+        (byte_index, bit_mask)
+    }
+
+    /// Returns true if `edition_number` has already been printed according to an existing
+    /// `edition_marker` account's ledger bit. Metaplex's own CPI enforces this too, but checking
+    /// it here first lets us fail with our own error instead of a generic CPI failure.
+    pub fn is_edition_already_printed(edition_marker: &AccountInfo, edition_number: u64) -> Result<bool> {
+        if edition_marker.data_is_empty() {
+            return Ok(false);
+        }
+        let (byte_index, bit_mask) = edition_marker_bit(edition_number);
+        let data = edition_marker.try_borrow_data()?;
+        if byte_index >= data.len() {
+            return Ok(false);
+        }
+        Ok(data[byte_index] & bit_mask != 0)
+    }
+
+    /// Rewrite `name`/`symbol`/`uri` on an existing metadata account via Metaplex's
+    /// `UpdateMetadataAccountV2`, signed by the `mint_auth` PDA that originally created it.
+    pub fn update_metadata(
+        metadata_account: &AccountInfo,
+        update_authority: &AccountInfo,
+        name: String,
+        symbol: String,
+        uri: String,
+        seller_fee_basis_points: u16,
+        creators: Option<Vec<Creator>>,
+        collection: Option<Collection>,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let data_v2 = DataV2 {
+            name,
+            symbol,
+            uri,
+            seller_fee_basis_points,
+            creators,
+            collection,
+            uses: None,
+        };
+
+        let ix = UpdateMetadataAccountV2Builder::new()
+            .metadata(metadata_account.key())
+            .update_authority(update_authority.key())
+            .data(Some(data_v2))
+            .build()
+            .unwrap()
+            .instruction();
+
+        invoke_signed(
+            &ix,
+            &[metadata_account.clone(), update_authority.clone()],
+            signer_seeds,
+        )?;
+
+        Ok(())
+    }
+
+    /// Convert the wire `(use_method, total)` tuple into Metaplex's `Uses`, starting a freshly
+    /// minted NFT off with `remaining == total`.
+    pub fn to_metaplex_uses(uses: Option<(u8, u64)>) -> Result<Option<Uses>> {
+        let Some((use_method, total)) = uses else {
+            return Ok(None);
+        };
+        let use_method = match use_method {
+            0 => UseMethod::Burn,
+            1 => UseMethod::Multiple,
+            2 => UseMethod::Single,
+            _ => return err!(ErrorCode::InvalidUseMethod),
+        };
+        Ok(Some(Uses { use_method, remaining: total, total }))
+    }
+
+    /// Consume one use of an NFT via Metaplex's `Utilize` CPI, signed directly by the token
+    /// owner (no delegated use-authority support, mirroring how every other handler in this
+    /// program treats the token owner as the sole authority over their own NFT).
+    pub fn utilize(
+        metadata: &AccountInfo,
+        token_account: &AccountInfo,
+        mint: &AccountInfo,
+        owner: &AccountInfo,
+        system_program: &AccountInfo,
+        rent: &AccountInfo,
+    ) -> Result<()> {
+        let ix = UtilizeBuilder::new()
+            .metadata(metadata.key())
+            .token_account(token_account.key())
+            .mint(mint.key())
+            .use_authority(owner.key())
+            .owner(owner.key())
+            .system_program(system_program.key())
+            .rent(Some(rent.key()))
+            .utilize_args(UtilizeArgs { number_of_uses: 1 })
+            .build()
+            .unwrap()
+            .instruction();
+
+        invoke(
+            &ix,
+            &[
+                metadata.clone(),
+                token_account.clone(),
+                mint.clone(),
+                owner.clone(),
+                system_program.clone(),
+                rent.clone(),
+            ],
+        )?;
+
+        Ok(())
+    }
 }
 
 #[error_code]
@@ -330,6 +637,32 @@ pub enum ErrorCode {
     InvalidOwner,
     #[msg("Account Not Initialized")]
     AccountNotInitialized,
+    #[msg("Seller fee basis points exceeds 10000 (100%)")]
+    InvalidSellerFeeBasisPoints,
+    #[msg("Creators list exceeds the maximum of 5")]
+    TooManyCreators,
+    #[msg("Creator shares must sum to 100")]
+    InvalidCreatorShares,
+    #[msg("Name exceeds 32 bytes")]
+    NameTooLong,
+    #[msg("Symbol exceeds 10 bytes")]
+    SymbolTooLong,
+    #[msg("URI exceeds 200 bytes")]
+    UriTooLong,
+    #[msg("Edition number exceeds the master's configured max supply")]
+    EditionLimitExceeded,
+    #[msg("Payload origin chain id does not match the gateway-supplied chain id")]
+    OriginChainMismatch,
+    #[msg("Sequence number has already been processed or is stale for this emitter")]
+    StaleSequence,
+    #[msg("Invalid edition marker PDA")]
+    InvalidEditionMarker,
+    #[msg("Edition number has already been printed")]
+    EditionAlreadyPrinted,
+    #[msg("Metadata for this NFT is immutable")]
+    MetadataImmutable,
+    #[msg("Use method must be 0 (Burn), 1 (Multiple), or 2 (Single)")]
+    InvalidUseMethod,
 }
 
 #[event]
@@ -347,6 +680,19 @@ pub struct InboundReceived {
     pub origin_chain_id: u32,
 }
 
+#[event]
+pub struct EditionPrinted {
+    pub parent_token_id: [u8; 32],
+    pub edition_mint: Pubkey,
+    pub edition_number: u64,
+}
+
+#[event]
+pub struct UseRecorded {
+    pub mint: Pubkey,
+    pub remaining: u64,
+}
+
 #[program]
 pub mod universal_nft {
     use super::*;
@@ -357,6 +703,8 @@ pub mod universal_nft {
         collection_name: String,
         collection_symbol: String,
         collection_uri: String,
+        collection_max_supply: Option<u64>,
+        is_mutable: bool,
     ) -> Result<()> {
         let config = &mut ctx.accounts.config;
         config.admin = admin;
@@ -365,6 +713,7 @@ pub mod universal_nft {
         config.collection_mint = ctx.accounts.collection_mint.key();
         config.bumps = ctx.bumps.into();
         config.next_token_id = 1;
+        config.is_mutable = is_mutable;
 
         // Create collection mint
         let mint_auth_seeds = &[b"mint_auth", &[ctx.bumps.mint_auth]];
@@ -425,7 +774,7 @@ pub mod universal_nft {
             &ctx.accounts.system_program.to_account_info(),
             &ctx.accounts.rent.to_account_info(),
             &ctx.accounts.token_program.to_account_info(),
-            Some(0),
+            Some(collection_max_supply.unwrap_or(0)),
             mint_auth_signer,
         )?;
 
@@ -437,9 +786,17 @@ pub mod universal_nft {
         name: String,
         symbol: String,
         uri: String,
+        seller_fee_basis_points: u16,
+        creators: Vec<(Pubkey, u8, bool)>,
+        max_supply: Option<u64>,
+        uses: Option<(u8, u64)>,
     ) -> Result<()> {
+        utils::validate_metadata_lengths(&name, &symbol, &uri)?;
+        utils::validate_royalty_info(seller_fee_basis_points, &creators)?;
+        let metaplex_uses = utils::to_metaplex_uses(uses)?;
+
         let config = &mut ctx.accounts.config;
-        
+
         // Generate token_id from mint pubkey, slot, and next_token_id
         let slot = Clock::get()?.slot;
         let mut token_id_data = Vec::new();
@@ -458,6 +815,9 @@ pub mod universal_nft {
             token_id,
             original_solana_mint: Some(ctx.accounts.mint.key()),
             mint_count: 1,
+            token_program: ctx.accounts.token_program.key(),
+            max_supply: max_supply.unwrap_or(0),
+            origin_chain_id: 0,
         };
         
         utils::create_pda_account(
@@ -505,20 +865,20 @@ pub mod universal_nft {
             name,
             symbol,
             uri,
-            None,
-            0,
+            utils::to_metaplex_creators(&creators, &ctx.accounts.mint_auth.key()),
+            seller_fee_basis_points,
             false,
             true,
             Some(collection),
-            None,
+            metaplex_uses,
             mint_auth_signer,
         )?;
 
         // Create master edition
         let edition_seeds = &[
-            b"metadata", 
-            MetadataID.as_ref(), 
-            ctx.accounts.mint.key().as_ref(), 
+            b"metadata",
+            MetadataID.as_ref(),
+            ctx.accounts.mint.key().as_ref(),
             b"edition"
         ];
         let (edition_key, _) = Pubkey::find_program_address(edition_seeds, &MetadataID);
@@ -534,7 +894,7 @@ pub mod universal_nft {
             &ctx.accounts.system_program.to_account_info(),
             &ctx.accounts.rent.to_account_info(),
             &ctx.accounts.token_program.to_account_info(),
-            Some(0),
+            Some(max_supply.unwrap_or(0)),
             mint_auth_signer,
         )?;
 
@@ -554,6 +914,161 @@ pub mod universal_nft {
         Ok(())
     }
 
+    pub fn print_edition(ctx: Context<PrintEdition>, edition_number: u64) -> Result<()> {
+        let parent_origin_data = ctx.accounts.master_nft_origin.try_borrow_data()?;
+        let parent_origin = state::NftOrigin::try_from_slice(&parent_origin_data)?;
+        drop(parent_origin_data);
+
+        require!(
+            parent_origin.original_solana_mint == Some(ctx.accounts.master_mint.key()),
+            ErrorCode::InvalidMint
+        );
+        require!(
+            parent_origin.max_supply > 0 && edition_number <= parent_origin.max_supply,
+            ErrorCode::EditionLimitExceeded
+        );
+
+        // Derive and validate the edition PDA for the new mint
+        let new_edition_seeds = &[
+            b"metadata",
+            MetadataID.as_ref(),
+            ctx.accounts.new_mint.key().as_ref(),
+            b"edition",
+        ];
+        let (new_edition_key, _) = Pubkey::find_program_address(new_edition_seeds, &MetadataID);
+        require!(new_edition_key == ctx.accounts.new_edition.key(), ErrorCode::InvalidMint);
+
+        // Derive and validate the edition_marker PDA for this edition number's slot, and reject
+        // a duplicate print up front rather than letting the CPI fail deep inside Metaplex.
+        let marker_index = (edition_number / 248).to_string();
+        let edition_marker_seeds = &[
+            b"metadata",
+            MetadataID.as_ref(),
+            ctx.accounts.master_mint.key().as_ref(),
+            b"edition",
+            marker_index.as_bytes(),
+        ];
+        let (edition_marker_key, _) = Pubkey::find_program_address(edition_marker_seeds, &MetadataID);
+        require!(
+            edition_marker_key == ctx.accounts.edition_marker.key(),
+            ErrorCode::InvalidEditionMarker
+        );
+        require!(
+            !utils::is_edition_already_printed(&ctx.accounts.edition_marker, edition_number)?,
+            ErrorCode::EditionAlreadyPrinted
+        );
+
+        // Mint the new edition's token
+        let mint_auth_seeds = &[b"mint_auth", &[ctx.bumps.mint_auth]];
+        let mint_auth_signer = &[&mint_auth_seeds[..]];
+
+        let cpi_accounts = MintTo {
+            mint: ctx.accounts.new_mint.to_account_info(),
+            to: ctx.accounts.new_token_account.to_account_info(),
+            authority: ctx.accounts.mint_auth.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, mint_auth_signer);
+        mint_to(cpi_ctx, 1)?;
+
+        utils::print_edition_from_master(
+            &ctx.accounts.new_metadata,
+            &ctx.accounts.new_edition,
+            &ctx.accounts.master_edition,
+            &ctx.accounts.new_mint.to_account_info(),
+            &ctx.accounts.edition_marker,
+            &ctx.accounts.mint_auth.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.owner.to_account_info(),
+            &ctx.accounts.master_token_account.to_account_info(),
+            &ctx.accounts.mint_auth.to_account_info(),
+            &ctx.accounts.master_metadata,
+            &ctx.accounts.master_mint.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.rent.to_account_info(),
+            edition_number,
+            mint_auth_signer,
+        )?;
+
+        emit!(EditionPrinted {
+            parent_token_id: parent_origin.token_id,
+            edition_mint: ctx.accounts.new_mint.key(),
+            edition_number,
+        });
+
+        Ok(())
+    }
+
+    pub fn update_metadata(
+        ctx: Context<UpdateMetadata>,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Result<()> {
+        require!(ctx.accounts.config.is_mutable, ErrorCode::MetadataImmutable);
+        utils::validate_metadata_lengths(&name, &symbol, &uri)?;
+
+        let nft_origin_data = ctx.accounts.nft_origin.try_borrow_data()?;
+        let nft_origin = state::NftOrigin::try_from_slice(&nft_origin_data)?;
+        drop(nft_origin_data);
+
+        require!(
+            nft_origin.original_solana_mint == Some(ctx.accounts.mint.key()),
+            ErrorCode::InvalidMint
+        );
+        require!(
+            ctx.accounts.token_account.amount > 0
+                && ctx.accounts.token_account.owner == ctx.accounts.owner.key(),
+            ErrorCode::Unauthorized
+        );
+
+        let metadata_seeds = &[b"metadata", MetadataID.as_ref(), ctx.accounts.mint.key().as_ref()];
+        let (metadata_key, _) = Pubkey::find_program_address(metadata_seeds, &MetadataID);
+        require!(metadata_key == ctx.accounts.metadata.key(), ErrorCode::InvalidMint);
+
+        // Only name/symbol/uri change - preserve everything else already on the account.
+        let existing = Metadata::from_account_info(&ctx.accounts.metadata)?;
+
+        let mint_auth_seeds = &[b"mint_auth", &[ctx.bumps.mint_auth]];
+        let mint_auth_signer = &[&mint_auth_seeds[..]];
+
+        utils::update_metadata(
+            &ctx.accounts.metadata,
+            &ctx.accounts.mint_auth.to_account_info(),
+            name,
+            symbol,
+            uri,
+            existing.data.seller_fee_basis_points,
+            existing.data.creators,
+            existing.collection,
+            mint_auth_signer,
+        )?;
+
+        Ok(())
+    }
+
+    pub fn use_nft(ctx: Context<UseNft>) -> Result<()> {
+        utils::utilize(
+            &ctx.accounts.metadata,
+            &ctx.accounts.token_account.to_account_info(),
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.owner.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.rent.to_account_info(),
+        )?;
+
+        let metadata = Metadata::from_account_info(&ctx.accounts.metadata)?;
+        let remaining = metadata.uses.map(|uses| uses.remaining).unwrap_or(0);
+
+        emit!(UseRecorded {
+            mint: ctx.accounts.mint.key(),
+            remaining,
+        });
+
+        Ok(())
+    }
+
     pub fn burn_and_prepare(ctx: Context<BurnAndPrepare>) -> Result<()> {
         // Read token_id from NftOrigin
         let nft_origin_data = ctx.accounts.nft_origin.try_borrow_data()?;
@@ -561,11 +1076,18 @@ pub mod universal_nft {
         
         // Verify the mint exists in NftOrigin
         require!(
-            nft_origin.original_solana_mint.is_some() && 
+            nft_origin.original_solana_mint.is_some() &&
             nft_origin.original_solana_mint.unwrap() == ctx.accounts.mint.key(),
             ErrorCode::InvalidMint
         );
 
+        // The mint may have been created under the legacy Token program or Token-2022;
+        // make sure the caller passed the program that actually owns it.
+        require!(
+            nft_origin.token_program == ctx.accounts.token_program.key(),
+            ErrorCode::InvalidMint
+        );
+
         // Burn the token
         let cpi_accounts = Burn {
             mint: ctx.accounts.mint.to_account_info(),
@@ -590,6 +1112,7 @@ pub mod universal_nft {
         sender: [u8; 20],
         amount: u64,
         data: Vec<u8>,
+        origin_chain_id: u32,
     ) -> Result<()> {
         // Verify the previous instruction was from the Gateway program
         utils::check_gateway(&ctx.accounts.instructions.to_account_info())?;
@@ -604,29 +1127,42 @@ pub mod universal_nft {
 
         // Decode payload
         let payload = payload::Payload::try_from_slice(&data)?;
+        utils::validate_metadata_lengths(&payload.name, &payload.symbol, &payload.uri)?;
+        utils::validate_royalty_info(payload.seller_fee_basis_points, &payload.creators)?;
+        let metaplex_uses = utils::to_metaplex_uses(payload.uses)?;
 
-        // Check if message already processed
+        // The payload's claimed origin chain must match the chain the gateway actually
+        // delivered this call from, or a forged payload could claim a different emitter.
+        require!(
+            payload.origin_chain_id == origin_chain_id,
+            ErrorCode::OriginChainMismatch
+        );
+
+        // Check if message already processed. Binding the PDA to `origin_chain_id` keeps the
+        // same `(sender, token_id, nonce)` tuple from two different source chains from
+        // colliding on the same claim account.
         let processed_message_seeds = &[
-            b"processed", 
-            &sender, 
+            b"processed",
+            &sender,
+            &origin_chain_id.to_le_bytes(),
             &payload.token_id,
             &payload.nonce.to_le_bytes()
         ];
-        let (processed_message_key, processed_message_bump) = 
+        let (processed_message_key, processed_message_bump) =
             Pubkey::find_program_address(processed_message_seeds, &crate::ID);
-        
+
         require!(
             processed_message_key == ctx.accounts.processed_message.key(),
             ErrorCode::InvalidMint
         );
-        
+
         let is_processed_empty = ctx.accounts.processed_message.data_is_empty();
-        
+
         if !is_processed_empty {
             // Read existing processed message and verify it's not a replay
             let processed_data = ctx.accounts.processed_message.try_borrow_data()?;
             let processed = state::ProcessedMessage::try_from_slice(&processed_data)?;
-            
+
             require!(
                 processed.sender != sender ||
                 processed.token_id != payload.token_id ||
@@ -634,14 +1170,52 @@ pub mod universal_nft {
                 ErrorCode::MessageAlreadyProcessed
             );
         }
-        
-        // Create processed message record
-        let processed_message_data = state::ProcessedMessage {
-            sender,
-            token_id: payload.token_id,
-            nonce: payload.nonce,
+
+        // Track the highest sequence seen from this emitter (origin_chain_id, sender), so an
+        // out-of-order or rolled-back nonce is rejected even the first time it's seen.
+        let emitter_sequence_seeds = &[b"emitter", &origin_chain_id.to_le_bytes(), &sender];
+        let (emitter_sequence_key, emitter_sequence_bump) =
+            Pubkey::find_program_address(emitter_sequence_seeds, &crate::ID);
+        require!(
+            emitter_sequence_key == ctx.accounts.emitter_sequence.key(),
+            ErrorCode::InvalidMint
+        );
+
+        let is_emitter_sequence_empty = ctx.accounts.emitter_sequence.data_is_empty();
+        let highest_sequence = if is_emitter_sequence_empty {
+            0
+        } else {
+            let emitter_data = ctx.accounts.emitter_sequence.try_borrow_data()?;
+            state::EmitterSequence::try_from_slice(&emitter_data)?.highest_sequence
         };
-        
+        require!(payload.nonce > highest_sequence, ErrorCode::StaleSequence);
+
+        let emitter_sequence_data = state::EmitterSequence { highest_sequence: payload.nonce };
+        if is_emitter_sequence_empty {
+            utils::create_pda_account(
+                &ctx.accounts.payer.to_account_info(),
+                &ctx.accounts.emitter_sequence,
+                std::mem::size_of::<state::EmitterSequence>(),
+                emitter_sequence_seeds,
+                emitter_sequence_bump,
+                &crate::ID,
+                &ctx.accounts.system_program.to_account_info(),
+                emitter_sequence_data,
+            )?;
+        } else {
+            let mut emitter_ref = ctx.accounts.emitter_sequence.try_borrow_mut_data()?;
+            emitter_sequence_data.serialize(&mut *emitter_ref)?;
+        }
+
+        // Create processed message record
+        let processed_message_data = state::ProcessedMessage {
+            sender,
+            token_id: payload.token_id,
+            nonce: payload.nonce,
+            origin_chain_id,
+            sequence: payload.nonce,
+        };
+
         if is_processed_empty {
             utils::create_pda_account(
                 &ctx.accounts.payer.to_account_info(),
@@ -673,18 +1247,22 @@ pub mod universal_nft {
                 token_id: payload.token_id,
                 original_solana_mint: payload.original_solana_mint,
                 mint_count: 1,
+                token_program: ctx.accounts.token_program.key(),
+                max_supply: 0,
+                origin_chain_id: payload.origin_chain_id,
             }
         } else {
             // Read and update existing NFT origin
             let nft_origin_existing = ctx.accounts.nft_origin.try_borrow_data()?;
             let mut nft_origin = state::NftOrigin::try_from_slice(&nft_origin_existing)?;
-            
+
             require!(
                 nft_origin.token_id == payload.token_id,
                 ErrorCode::InvalidTokenId
             );
-            
+
             nft_origin.mint_count += 1;
+            nft_origin.token_program = ctx.accounts.token_program.key();
             nft_origin
         };
         
@@ -753,20 +1331,20 @@ pub mod universal_nft {
             payload.name,
             payload.symbol,
             payload.uri,
-            None,
-            0,
+            utils::to_metaplex_creators(&payload.creators, &ctx.accounts.mint_auth.key()),
+            payload.seller_fee_basis_points,
             false,
             true,
             Some(collection),
-            None,
+            metaplex_uses,
             mint_auth_signer,
         )?;
 
         // Create master edition
         let edition_seeds = &[
-            b"metadata", 
-            MetadataID.as_ref(), 
-            ctx.accounts.mint.key().as_ref(), 
+            b"metadata",
+            MetadataID.as_ref(),
+            ctx.accounts.mint.key().as_ref(),
             b"edition"
         ];
         let (edition_key, _) = Pubkey::find_program_address(edition_seeds, &MetadataID);
@@ -813,7 +1391,7 @@ pub mod universal_nft {
 
     pub fn on_revert(
         ctx: Context<OnRevert>,
-        sender: [u8; 20],
+        _sender: [u8; 20],
         data: Vec<u8>,
     ) -> Result<()> {
         // Verify the previous instruction was from the Gateway program
@@ -827,7 +1405,221 @@ pub mod universal_nft {
             ErrorCode::InvalidGatewayMeta
         );
 
-        // Handle revert logic if needed
+        // Decode the revert payload and restore the NFT that was burned on the outbound leg
+        let revert = payload::RevertPayload::try_from_slice(&data)?;
+        utils::validate_metadata_lengths(&revert.name, &revert.symbol, &revert.uri)?;
+
+        require!(
+            revert.recipient == ctx.accounts.recipient.key(),
+            ErrorCode::Unauthorized
+        );
+
+        // Re-initialize or update the NFT origin PDA to reflect that the token is local again
+        let nft_origin_seeds = &[b"nft_origin", &revert.token_id];
+        let (nft_origin_key, nft_origin_bump) =
+            Pubkey::find_program_address(nft_origin_seeds, &crate::ID);
+        require!(
+            nft_origin_key == ctx.accounts.nft_origin.key(),
+            ErrorCode::InvalidMint
+        );
+
+        let is_nft_origin_empty = ctx.accounts.nft_origin.data_is_empty();
+        let nft_origin_data = if is_nft_origin_empty {
+            state::NftOrigin {
+                token_id: revert.token_id,
+                original_solana_mint: Some(ctx.accounts.mint.key()),
+                mint_count: 1,
+                token_program: ctx.accounts.token_program.key(),
+                max_supply: 0,
+                origin_chain_id: revert.origin_chain_id,
+            }
+        } else {
+            let nft_origin_existing = ctx.accounts.nft_origin.try_borrow_data()?;
+            let mut nft_origin = state::NftOrigin::try_from_slice(&nft_origin_existing)?;
+
+            require!(
+                nft_origin.token_id == revert.token_id,
+                ErrorCode::InvalidTokenId
+            );
+
+            nft_origin.original_solana_mint = Some(ctx.accounts.mint.key());
+            nft_origin.token_program = ctx.accounts.token_program.key();
+            nft_origin.mint_count += 1;
+            nft_origin
+        };
+
+        if is_nft_origin_empty {
+            utils::create_pda_account(
+                &ctx.accounts.payer.to_account_info(),
+                &ctx.accounts.nft_origin,
+                std::mem::size_of::<state::NftOrigin>(),
+                nft_origin_seeds,
+                nft_origin_bump,
+                &crate::ID,
+                &ctx.accounts.system_program.to_account_info(),
+                nft_origin_data,
+            )?;
+        } else {
+            let mut nft_origin_ref = ctx.accounts.nft_origin.try_borrow_mut_data()?;
+            nft_origin_data.serialize(&mut *nft_origin_ref)?;
+        }
+
+        // Create the recipient ATA if it doesn't exist
+        if ctx.accounts.token_account.data_is_empty() {
+            utils::create_ata(
+                &ctx.accounts.payer.to_account_info(),
+                &ctx.accounts.recipient.to_account_info(),
+                &ctx.accounts.mint.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                &ctx.accounts.token_program.to_account_info(),
+                &ctx.accounts.associated_token_program.to_account_info(),
+                &ctx.accounts.rent.to_account_info(),
+            )?;
+        }
+
+        // Mint the restored token back to its original owner
+        let mint_auth_seeds = &[b"mint_auth", &[ctx.bumps.mint_auth]];
+        let mint_auth_signer = &[&mint_auth_seeds[..]];
+
+        let cpi_accounts = MintTo {
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.token_account.to_account_info(),
+            authority: ctx.accounts.mint_auth.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, mint_auth_signer);
+        mint_to(cpi_ctx, 1)?;
+
+        // Re-create metadata
+        let metadata_seeds = &[b"metadata", MetadataID.as_ref(), ctx.accounts.mint.key().as_ref()];
+        let (metadata_key, _) = Pubkey::find_program_address(metadata_seeds, &MetadataID);
+        require!(metadata_key == ctx.accounts.metadata.key(), ErrorCode::InvalidMint);
+
+        let collection = Collection {
+            verified: false,
+            key: ctx.accounts.config.collection_mint,
+        };
+
+        utils::create_metadata(
+            &ctx.accounts.metadata,
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.mint_auth.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.mint_auth.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.rent.to_account_info(),
+            revert.name,
+            revert.symbol,
+            revert.uri,
+            None,
+            0,
+            false,
+            true,
+            Some(collection),
+            None,
+            mint_auth_signer,
+        )?;
+
+        // Re-create master edition
+        let edition_seeds = &[
+            b"metadata",
+            MetadataID.as_ref(),
+            ctx.accounts.mint.key().as_ref(),
+            b"edition"
+        ];
+        let (edition_key, _) = Pubkey::find_program_address(edition_seeds, &MetadataID);
+        require!(edition_key == ctx.accounts.master_edition.key(), ErrorCode::InvalidMint);
+
+        utils::create_master_edition(
+            &ctx.accounts.master_edition,
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.mint_auth.to_account_info(),
+            &ctx.accounts.mint_auth.to_account_info(),
+            &ctx.accounts.metadata,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.rent.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            Some(0),
+            mint_auth_signer,
+        )?;
+
+        // Set and verify collection
+        utils::set_and_verify_collection(
+            &ctx.accounts.metadata,
+            &ctx.accounts.mint_auth.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.mint_auth.to_account_info(),
+            &ctx.accounts.collection_mint.to_account_info(),
+            &ctx.accounts.collection_metadata,
+            &ctx.accounts.collection_master_edition,
+            &ctx.accounts.system_program.to_account_info(),
+            mint_auth_signer,
+        )?;
+
+        Ok(())
+    }
+
+    pub fn on_call_update_metadata(
+        ctx: Context<OnCallUpdateMetadata>,
+        _sender: [u8; 20],
+        data: Vec<u8>,
+        origin_chain_id: u32,
+    ) -> Result<()> {
+        utils::check_gateway(&ctx.accounts.instructions.to_account_info())?;
+
+        let gateway_meta_seeds = &[b"meta"];
+        let (expected_gateway_meta, _) = Pubkey::find_program_address(gateway_meta_seeds, &GATEWAY_PROGRAM_ID);
+        require!(
+            ctx.accounts.gateway_meta.key() == expected_gateway_meta,
+            ErrorCode::InvalidGatewayMeta
+        );
+
+        require!(ctx.accounts.config.is_mutable, ErrorCode::MetadataImmutable);
+
+        let update = payload::UpdateMetadataPayload::try_from_slice(&data)?;
+        utils::validate_metadata_lengths(&update.name, &update.symbol, &update.uri)?;
+
+        let nft_origin_data = ctx.accounts.nft_origin.try_borrow_data()?;
+        let nft_origin = state::NftOrigin::try_from_slice(&nft_origin_data)?;
+        drop(nft_origin_data);
+
+        require!(
+            nft_origin.token_id == update.token_id,
+            ErrorCode::InvalidTokenId
+        );
+        require!(
+            nft_origin.original_solana_mint == Some(ctx.accounts.mint.key()),
+            ErrorCode::InvalidMint
+        );
+        // Only the chain that originally brought this NFT here is allowed to push updates for it.
+        require!(
+            nft_origin.origin_chain_id == origin_chain_id
+                && nft_origin.origin_chain_id == update.origin_chain_id,
+            ErrorCode::OriginChainMismatch
+        );
+
+        let metadata_seeds = &[b"metadata", MetadataID.as_ref(), ctx.accounts.mint.key().as_ref()];
+        let (metadata_key, _) = Pubkey::find_program_address(metadata_seeds, &MetadataID);
+        require!(metadata_key == ctx.accounts.metadata.key(), ErrorCode::InvalidMint);
+
+        let existing = Metadata::from_account_info(&ctx.accounts.metadata)?;
+
+        let mint_auth_seeds = &[b"mint_auth", &[ctx.bumps.mint_auth]];
+        let mint_auth_signer = &[&mint_auth_seeds[..]];
+
+        utils::update_metadata(
+            &ctx.accounts.metadata,
+            &ctx.accounts.mint_auth.to_account_info(),
+            update.name,
+            update.symbol,
+            update.uri,
+            existing.data.seller_fee_basis_points,
+            existing.data.creators,
+            existing.collection,
+            mint_auth_signer,
+        )?;
+
         Ok(())
     }
 }
@@ -867,21 +1659,23 @@ pub struct Initialize<'info> {
         payer = payer,
         mint::decimals = 0,
         mint::authority = mint_auth,
+        mint::token_program = token_program,
     )]
-    pub collection_mint: Account<'info, Mint>,
+    pub collection_mint: InterfaceAccount<'info, Mint>,
 
     #[account(
         init,
         payer = payer,
         associated_token::mint = collection_mint,
         associated_token::authority = treasury,
+        associated_token::token_program = token_program,
     )]
-    pub treasury_token_account: Account<'info, TokenAccount>,
-    
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
     /// CHECK: Metadata account for collection
     #[account(mut)]
     pub collection_metadata: UncheckedAccount<'info>,
-    
+
     /// CHECK: Master edition account for collection
     #[account(mut)]
     pub collection_master_edition: UncheckedAccount<'info>,
@@ -890,7 +1684,7 @@ pub struct Initialize<'info> {
     pub payer: Signer<'info>,
 
     pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub rent: Sysvar<'info, Rent>,
 }
@@ -916,35 +1710,37 @@ pub struct MintLocal<'info> {
         payer = payer,
         mint::decimals = 0,
         mint::authority = mint_auth,
+        mint::token_program = token_program,
     )]
-    pub mint: Account<'info, Mint>,
+    pub mint: InterfaceAccount<'info, Mint>,
 
     #[account(
         init,
         payer = payer,
         associated_token::mint = mint,
         associated_token::authority = recipient,
+        associated_token::token_program = token_program,
     )]
-    pub token_account: Account<'info, TokenAccount>,
-    
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
     /// CHECK: Metadata account for NFT
     #[account(mut)]
     pub metadata: UncheckedAccount<'info>,
-    
+
     /// CHECK: Master edition account for NFT
     #[account(mut)]
     pub master_edition: UncheckedAccount<'info>,
-    
+
     /// CHECK: Collection mint from config
     #[account(
         constraint = collection_mint.key() == config.collection_mint
     )]
-    pub collection_mint: Account<'info, Mint>,
-    
+    pub collection_mint: InterfaceAccount<'info, Mint>,
+
     /// CHECK: Collection metadata
     #[account(mut)]
     pub collection_metadata: UncheckedAccount<'info>,
-    
+
     /// CHECK: Collection master edition
     #[account(mut)]
     pub collection_master_edition: UncheckedAccount<'info>,
@@ -956,32 +1752,161 @@ pub struct MintLocal<'info> {
     pub payer: Signer<'info>,
 
     pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub rent: Sysvar<'info, Rent>,
 }
 
+#[derive(Accounts)]
+#[instruction(edition_number: u64)]
+pub struct PrintEdition<'info> {
+    #[account(mut)]
+    pub config: Account<'info, state::Config>,
+
+    #[account(
+        seeds = [b"mint_auth"],
+        bump = config.bumps.mint_auth,
+    )]
+    pub mint_auth: SystemAccount<'info>,
+
+    /// CHECK: NftOrigin of the master NFT being printed from; validated in handler
+    pub master_nft_origin: UncheckedAccount<'info>,
+
+    pub master_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Metaplex metadata account of the master edition
+    pub master_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex master edition account, mutated by the print CPI
+    #[account(mut)]
+    pub master_edition: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex edition marker PDA tracking printed edition numbers
+    #[account(mut)]
+    pub edition_marker: UncheckedAccount<'info>,
+
+    /// Token account proving `owner` holds the master edition's token - the right to print.
+    #[account(
+        associated_token::mint = master_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub master_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        mint::decimals = 0,
+        mint::authority = mint_auth,
+        mint::token_program = token_program,
+    )]
+    pub new_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = new_mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program,
+    )]
+    pub new_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Metadata account for the new edition
+    #[account(mut)]
+    pub new_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Edition account for the new edition
+    #[account(mut)]
+    pub new_edition: UncheckedAccount<'info>,
+
+    /// CHECK: Recipient of the printed edition
+    pub recipient: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateMetadata<'info> {
+    pub config: Account<'info, state::Config>,
+
+    #[account(
+        seeds = [b"mint_auth"],
+        bump = config.bumps.mint_auth,
+    )]
+    pub mint_auth: SystemAccount<'info>,
+
+    /// CHECK: NftOrigin of the NFT being updated; validated in handler
+    pub nft_origin: UncheckedAccount<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Metaplex metadata account for the NFT, rewritten by the update CPI
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+
+    #[account(
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct UseNft<'info> {
+    /// CHECK: Metaplex metadata account, decremented by the utilize CPI
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
 #[derive(Accounts)]
 pub struct BurnAndPrepare<'info> {
     /// CHECK: NFT origin PDA
     pub nft_origin: UncheckedAccount<'info>,
 
     #[account(mut)]
-    pub mint: Account<'info, Mint>,
+    pub mint: InterfaceAccount<'info, Mint>,
 
     #[account(
         mut,
         associated_token::mint = mint,
         associated_token::authority = owner,
+        associated_token::token_program = token_program,
     )]
-    pub token_account: Account<'info, TokenAccount>,
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
 
     pub owner: Signer<'info>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 #[derive(Accounts)]
-#[instruction(sender: [u8; 20], amount: u64, data: Vec<u8>)]
+#[instruction(sender: [u8; 20], amount: u64, data: Vec<u8>, origin_chain_id: u32)]
 pub struct OnCall<'info> {
     #[account(mut)]
     pub config: Account<'info, state::Config>,
@@ -1000,36 +1925,41 @@ pub struct OnCall<'info> {
     #[account(mut)]
     pub processed_message: UncheckedAccount<'info>,
 
+    /// CHECK: Per-emitter (origin_chain_id, sender) highest-sequence PDA, validated in handler
+    #[account(mut)]
+    pub emitter_sequence: UncheckedAccount<'info>,
+
     #[account(
         init,
         payer = payer,
         mint::decimals = 0,
         mint::authority = mint_auth,
+        mint::token_program = token_program,
     )]
-    pub mint: Account<'info, Mint>,
+    pub mint: InterfaceAccount<'info, Mint>,
 
     /// CHECK: Token account, will be initialized if needed
     #[account(mut)]
     pub token_account: UncheckedAccount<'info>,
-    
+
     /// CHECK: Metadata account for NFT
     #[account(mut)]
     pub metadata: UncheckedAccount<'info>,
-    
+
     /// CHECK: Master edition account for NFT
     #[account(mut)]
     pub master_edition: UncheckedAccount<'info>,
-    
+
     /// CHECK: Collection mint from config
     #[account(
         constraint = collection_mint.key() == config.collection_mint
     )]
-    pub collection_mint: Account<'info, Mint>,
-    
+    pub collection_mint: InterfaceAccount<'info, Mint>,
+
     /// CHECK: Collection metadata
     #[account(mut)]
     pub collection_metadata: UncheckedAccount<'info>,
-    
+
     /// CHECK: Collection master edition
     #[account(mut)]
     pub collection_master_edition: UncheckedAccount<'info>,
@@ -1045,7 +1975,7 @@ pub struct OnCall<'info> {
     pub payer: Signer<'info>,
 
     pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub rent: Sysvar<'info, Rent>,
     pub instructions: Sysvar<'info, Instructions>,
@@ -1057,6 +1987,54 @@ pub struct OnRevert<'info> {
     #[account(mut)]
     pub config: Account<'info, state::Config>,
 
+    #[account(
+        seeds = [b"mint_auth"],
+        bump = config.bumps.mint_auth,
+    )]
+    pub mint_auth: SystemAccount<'info>,
+
+    /// CHECK: NFT origin PDA, will be initialized or updated in handler
+    #[account(mut)]
+    pub nft_origin: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        mint::decimals = 0,
+        mint::authority = mint_auth,
+        mint::token_program = token_program,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Token account, will be initialized if needed
+    #[account(mut)]
+    pub token_account: UncheckedAccount<'info>,
+
+    /// CHECK: Metadata account for the restored NFT
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Master edition account for the restored NFT
+    #[account(mut)]
+    pub master_edition: UncheckedAccount<'info>,
+
+    /// CHECK: Collection mint from config
+    #[account(
+        constraint = collection_mint.key() == config.collection_mint
+    )]
+    pub collection_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Collection metadata
+    #[account(mut)]
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Collection master edition
+    #[account(mut)]
+    pub collection_master_edition: UncheckedAccount<'info>,
+
+    /// CHECK: Recipient the NFT is being restored to
+    pub recipient: UncheckedAccount<'info>,
+
     /// CHECK: Gateway meta account verified in instruction
     #[account(owner = GATEWAY_PROGRAM_ID)]
     pub gateway_meta: UncheckedAccount<'info>,
@@ -1065,5 +2043,35 @@ pub struct OnRevert<'info> {
     pub payer: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+    pub instructions: Sysvar<'info, Instructions>,
+}
+
+#[derive(Accounts)]
+#[instruction(sender: [u8; 20], data: Vec<u8>, origin_chain_id: u32)]
+pub struct OnCallUpdateMetadata<'info> {
+    pub config: Account<'info, state::Config>,
+
+    #[account(
+        seeds = [b"mint_auth"],
+        bump = config.bumps.mint_auth,
+    )]
+    pub mint_auth: SystemAccount<'info>,
+
+    /// CHECK: NftOrigin of the NFT being updated; validated in handler
+    pub nft_origin: UncheckedAccount<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Metaplex metadata account for the NFT, rewritten by the update CPI
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Gateway meta account verified in instruction
+    #[account(owner = GATEWAY_PROGRAM_ID)]
+    pub gateway_meta: UncheckedAccount<'info>,
+
     pub instructions: Sysvar<'info, Instructions>,
 }
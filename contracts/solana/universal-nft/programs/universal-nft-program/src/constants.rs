@@ -6,6 +6,8 @@ pub const MAX_ATTRIBUTES_COUNT: usize = 20;
 pub const MAX_ATTRIBUTE_NAME_LENGTH: usize = 32;
 pub const MAX_ATTRIBUTE_VALUE_LENGTH: usize = 64;
 pub const MAX_DESTINATION_ADDRESS_LENGTH: usize = 64;
+pub const MAX_RECIPIENT_ADDRESS_LENGTH: usize = 64;
+pub const MAX_TRANSFER_PAYLOAD_LENGTH: usize = 512;
 pub const MAX_REVERT_MESSAGE_LENGTH: usize = 256;
 
 pub const SIGNATURE_LENGTH: usize = 64;
@@ -94,10 +94,34 @@ pub enum UniversalNftError {
     
     #[msg("Creator verification failed")]
     CreatorVerificationFailed,
-    
+
+    #[msg("Royalty basis points must not exceed 10000")]
+    InvalidRoyaltyBasisPoints,
+
+    #[msg("Creator shares must sum to exactly 100")]
+    InvalidCreatorShares,
+
+    #[msg("Duplicate creator address")]
+    DuplicateCreator,
+
     #[msg("Attributes limit exceeded")]
     AttributesLimitExceeded,
     
     #[msg("Invalid attribute data")]
     InvalidAttributeData,
+
+    #[msg("Destination chain is not registered as a trusted endpoint")]
+    EndpointNotRegistered,
+
+    #[msg("Destination chain endpoint is disabled")]
+    UnsupportedChain,
+
+    #[msg("Remote contract address exceeds maximum length")]
+    RemoteAddressTooLong,
+
+    #[msg("Transfer payload exceeds maximum length")]
+    PayloadTooLong,
+
+    #[msg("Payload transfers must target a contract destination")]
+    PayloadRequiresContractDestination,
 }
\ No newline at end of file
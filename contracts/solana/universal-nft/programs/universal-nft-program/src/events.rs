@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::state::NftAttribute;
+
+/// Emitted when a new NFT is minted, carrying its on-chain attributes so the cross-chain
+/// relayer can reconstruct the same metadata on the destination chain without re-fetching
+/// IPFS.
+#[event]
+pub struct NftMinted {
+    pub token_id: u64,
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub name: String,
+    pub uri: String,
+    pub attributes: Vec<NftAttribute>,
+}
+
+/// Emitted when an outbound transfer carries an application payload (see
+/// `burn_for_cross_chain_with_payload`), recording the authenticated Solana sender and the
+/// opaque payload so off-chain indexers can correlate it with the destination contract call.
+#[event]
+pub struct InterChainTransferStarted {
+    pub token_id: u64,
+    pub destination_chain_id: u64,
+    pub destination_address: Vec<u8>,
+    pub sender: Pubkey,
+    pub payload: Vec<u8>,
+}
+
+/// Emitted when `on_revert` restores an NFT after the gateway reports its outbound
+/// transfer failed, so off-chain indexers can reconcile supply/location without
+/// re-deriving whether the asset came back via re-mint or a custody release.
+#[event]
+pub struct NftRecovered {
+    pub token_id: u64,
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub original_chain_id: u64,
+    pub was_released_from_custody: bool,
+    pub timestamp: i64,
+}
@@ -1,13 +1,25 @@
 pub mod initialize_program;
+pub mod initialize_program_token2022;
 pub mod mint_nft;
+pub mod mint_nft_v2;
+pub mod verify_nft_collection;
 pub mod burn_for_cross_chain;
 pub mod mint_from_cross_chain;
 pub mod gateway_handlers;
 pub mod update_config;
+pub mod lock_for_cross_chain;
+pub mod chain_endpoint;
+pub mod update_metadata;
 
 pub use initialize_program::*;
+pub use initialize_program_token2022::*;
 pub use mint_nft::*;
+pub use mint_nft_v2::*;
+pub use verify_nft_collection::*;
 pub use burn_for_cross_chain::*;
 pub use mint_from_cross_chain::*;
 pub use gateway_handlers::*;
-pub use update_config::*;
\ No newline at end of file
+pub use update_config::*;
+pub use lock_for_cross_chain::*;
+pub use chain_endpoint::*;
+pub use update_metadata::*;
\ No newline at end of file
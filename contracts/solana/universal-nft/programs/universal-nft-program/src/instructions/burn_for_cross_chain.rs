@@ -4,7 +4,7 @@ use anchor_spl::{
     token::{burn, Burn, Mint, Token, TokenAccount},
 };
 
-use crate::{constants::*, errors::*, state::*, utils::*};
+use crate::{constants::*, errors::*, events::*, state::*, utils::*};
 
 pub fn burn_for_cross_chain(
     ctx: Context<BurnForCrossChain>,
@@ -37,7 +37,9 @@ pub fn burn_for_cross_chain(
         destination_chain_id != SOLANA_CHAIN_ID,
         UniversalNftError::InvalidChainId
     );
-    
+
+    let remote_contract = require_registered_endpoint(&ctx.accounts.endpoint, destination_chain_id)?;
+
     validate_destination_address(&destination_address)?;
     
     require!(
@@ -105,6 +107,8 @@ pub fn burn_for_cross_chain(
         data.extend_from_slice(&(destination_address.len() as u32).to_le_bytes());
         data.extend_from_slice(&destination_address);
         data.extend_from_slice(&message_hash);
+        data.extend_from_slice(&(remote_contract.len() as u32).to_le_bytes());
+        data.extend_from_slice(&remote_contract);
         data
     };
 
@@ -128,6 +132,7 @@ pub fn burn_for_cross_chain(
 }
 
 #[derive(Accounts)]
+#[instruction(destination_chain_id: u64)]
 pub struct BurnForCrossChain<'info> {
     #[account(
         mut,
@@ -136,6 +141,12 @@ pub struct BurnForCrossChain<'info> {
     )]
     pub program_config: Account<'info, ProgramConfig>,
 
+    #[account(
+        seeds = [ENDPOINT_SEED, &destination_chain_id.to_le_bytes()],
+        bump = endpoint.bump,
+    )]
+    pub endpoint: Account<'info, ChainEndpoint>,
+
     #[account(
         mut,
         seeds = [NFT_STATE_SEED, nft_mint.key().as_ref()],
@@ -174,4 +185,144 @@ pub struct BurnForCrossChain<'info> {
 
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
+}
+
+/// Payload-3-style variant of `burn_for_cross_chain`: attaches an opaque application payload
+/// and records the authenticated Solana signer, so the destination contract can implement
+/// composable receive-and-act logic instead of a plain mint-on-arrival. `destination_is_contract`
+/// must be asserted true by the caller whenever `payload` is non-empty, since a wallet address
+/// on the destination chain can't execute the payload.
+pub fn burn_for_cross_chain_with_payload(
+    ctx: Context<BurnForCrossChain>,
+    destination_chain_id: u64,
+    destination_address: Vec<u8>,
+    payload: Vec<u8>,
+    destination_is_contract: bool,
+) -> Result<()> {
+    require!(
+        payload.len() <= MAX_TRANSFER_PAYLOAD_LENGTH,
+        UniversalNftError::PayloadTooLong
+    );
+    require!(
+        payload.is_empty() || destination_is_contract,
+        UniversalNftError::PayloadRequiresContractDestination
+    );
+
+    let is_initialized;
+    let current_nonce;
+    {
+        let program_config = &ctx.accounts.program_config;
+        is_initialized = program_config.is_initialized;
+        current_nonce = program_config.nonce;
+    }
+
+    let nft_state = &mut ctx.accounts.nft_state;
+    let clock = Clock::get()?;
+
+    require!(is_initialized, UniversalNftError::ProgramNotInitialized);
+
+    require!(
+        !nft_state.is_cross_chain_locked,
+        UniversalNftError::NftLockedForCrossChain
+    );
+
+    require!(
+        destination_chain_id != SOLANA_CHAIN_ID,
+        UniversalNftError::InvalidChainId
+    );
+
+    let remote_contract = require_registered_endpoint(&ctx.accounts.endpoint, destination_chain_id)?;
+
+    validate_destination_address(&destination_address)?;
+
+    require!(
+        ctx.accounts.nft_token_account.amount == NFT_SUPPLY,
+        UniversalNftError::InvalidTokenAccount
+    );
+
+    require!(
+        nft_state.cross_chain_history.len() < NftState::MAX_CROSS_CHAIN_HISTORY,
+        UniversalNftError::CrossChainHistoryLimitExceeded
+    );
+
+    let burn_cpi_accounts = Burn {
+        mint: ctx.accounts.nft_mint.to_account_info(),
+        from: ctx.accounts.nft_token_account.to_account_info(),
+        authority: ctx.accounts.owner.to_account_info(),
+    };
+    let burn_cpi_context = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        burn_cpi_accounts,
+    );
+    burn(burn_cpi_context, NFT_SUPPLY)?;
+
+    nft_state.cross_chain_history.push(CrossChainTransfer {
+        destination_chain_id,
+        destination_address: destination_address.clone(),
+        transfer_timestamp: clock.unix_timestamp,
+        transaction_hash: [0u8; 32], // Will be filled by gateway
+        transfer_type: TransferType::Outbound,
+    });
+    nft_state.is_cross_chain_locked = true;
+
+    let program_config = &mut ctx.accounts.program_config;
+    program_config.total_cross_chain_transfers = safe_add_u64(
+        program_config.total_cross_chain_transfers,
+        1,
+    )?;
+    let new_nonce = safe_add_u64(current_nonce, 1)?;
+    program_config.nonce = new_nonce;
+
+    let message_type = CrossChainMessageType::BurnConfirmation {
+        token_id: nft_state.token_id,
+        burned_amount: NFT_SUPPLY,
+    };
+
+    let message_hash = create_cross_chain_message_hash(
+        destination_chain_id,
+        new_nonce,
+        &message_type,
+    )?;
+
+    let owner_key = ctx.accounts.owner.key();
+
+    // Canonical layout, extending the plain burn message with a length-prefixed payload and
+    // the authenticated sender, per Wormhole's payload-3 transfer-with-payload design.
+    let cross_chain_message = {
+        let mut data = Vec::new();
+        data.extend_from_slice(&nft_state.token_id.to_le_bytes());
+        data.extend_from_slice(&destination_chain_id.to_le_bytes());
+        data.extend_from_slice(&(destination_address.len() as u32).to_le_bytes());
+        data.extend_from_slice(&destination_address);
+        data.extend_from_slice(&message_hash);
+        data.extend_from_slice(&(remote_contract.len() as u32).to_le_bytes());
+        data.extend_from_slice(&remote_contract);
+        data.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+        data.extend_from_slice(&payload);
+        data.extend_from_slice(owner_key.as_ref());
+        data
+    };
+
+    crate::instructions::gateway_handlers::call_gateway_deposit_and_call(
+        ctx.accounts.gateway_program.to_account_info(),
+        ctx.accounts.owner.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        0,
+        destination_address.clone(),
+        cross_chain_message,
+    ).map_err(|_| UniversalNftError::GatewayCallFailed)?;
+
+    emit!(InterChainTransferStarted {
+        token_id: nft_state.token_id,
+        destination_chain_id,
+        destination_address,
+        sender: owner_key,
+        payload,
+    });
+
+    msg!("NFT burned for cross-chain transfer with payload");
+    msg!("Token ID: {}", nft_state.token_id);
+    msg!("Destination Chain: {}", destination_chain_id);
+
+    Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, state::*};
+
+/// Register a trusted remote contract address for `chain_id`. Only `program_config.authority`
+/// may call this; outbound transfers to unregistered or disabled chains are rejected.
+pub fn register_chain(
+    ctx: Context<RegisterChain>,
+    chain_id: u64,
+    remote_address: Vec<u8>,
+) -> Result<()> {
+    require!(
+        remote_address.len() <= MAX_RECIPIENT_ADDRESS_LENGTH && !remote_address.is_empty(),
+        UniversalNftError::RemoteAddressTooLong
+    );
+    require!(chain_id != SOLANA_CHAIN_ID, UniversalNftError::InvalidChainId);
+
+    let endpoint = &mut ctx.accounts.endpoint;
+    endpoint.chain_id = chain_id;
+    endpoint.remote_address = remote_address;
+    endpoint.enabled = true;
+    endpoint.bump = ctx.bumps.endpoint;
+
+    msg!("Registered trusted endpoint for chain {}", chain_id);
+
+    Ok(())
+}
+
+pub fn update_chain(
+    ctx: Context<UpdateChain>,
+    _chain_id: u64,
+    remote_address: Vec<u8>,
+) -> Result<()> {
+    require!(
+        remote_address.len() <= MAX_RECIPIENT_ADDRESS_LENGTH && !remote_address.is_empty(),
+        UniversalNftError::RemoteAddressTooLong
+    );
+
+    ctx.accounts.endpoint.remote_address = remote_address;
+
+    msg!("Updated trusted endpoint for chain {}", ctx.accounts.endpoint.chain_id);
+
+    Ok(())
+}
+
+pub fn disable_chain(ctx: Context<UpdateChain>, _chain_id: u64) -> Result<()> {
+    ctx.accounts.endpoint.enabled = false;
+
+    msg!("Disabled trusted endpoint for chain {}", ctx.accounts.endpoint.chain_id);
+
+    Ok(())
+}
+
+/// Fail unless `chain_id` has an enabled registered endpoint, returning its remote address.
+pub fn require_registered_endpoint(endpoint: &ChainEndpoint, chain_id: u64) -> Result<Vec<u8>> {
+    require!(endpoint.chain_id == chain_id, UniversalNftError::EndpointNotRegistered);
+    require!(endpoint.enabled, UniversalNftError::UnsupportedChain);
+    Ok(endpoint.remote_address.clone())
+}
+
+#[derive(Accounts)]
+#[instruction(chain_id: u64)]
+pub struct RegisterChain<'info> {
+    #[account(
+        seeds = [PROGRAM_SEED],
+        bump = program_config.bump,
+        has_one = authority @ UniversalNftError::InvalidAuthority,
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ChainEndpoint::LEN,
+        seeds = [ENDPOINT_SEED, &chain_id.to_le_bytes()],
+        bump
+    )]
+    pub endpoint: Account<'info, ChainEndpoint>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(chain_id: u64)]
+pub struct UpdateChain<'info> {
+    #[account(
+        seeds = [PROGRAM_SEED],
+        bump = program_config.bump,
+        has_one = authority @ UniversalNftError::InvalidAuthority,
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [ENDPOINT_SEED, &chain_id.to_le_bytes()],
+        bump = endpoint.bump,
+        constraint = endpoint.chain_id == chain_id @ UniversalNftError::EndpointNotRegistered,
+    )]
+    pub endpoint: Account<'info, ChainEndpoint>,
+
+    pub authority: Signer<'info>,
+}
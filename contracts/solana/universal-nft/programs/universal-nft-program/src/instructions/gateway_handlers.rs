@@ -1,42 +1,64 @@
 use anchor_lang::prelude::*;
-use borsh::BorshDeserialize;
+use anchor_lang::solana_program::keccak::hash as keccak_hash;
+use borsh::{BorshDeserialize, BorshSerialize};
 
 use crate::{errors::*, state::*, utils::*};
 
-pub fn on_call(ctx: Context<OnCall>, sender: [u8; 20], message: Vec<u8>) -> Result<()> {
+pub fn on_call(
+    ctx: Context<OnCall>,
+    sender: [u8; 20],
+    message: Vec<u8>,
+    signature: [u8; 64],
+    recovery_id: u8,
+) -> Result<()> {
     // Extract values from program_config early to avoid borrow conflicts
     let is_initialized;
     let gateway_program_id;
     let current_nonce;
+    let tss_address;
     {
         let program_config = &ctx.accounts.program_config;
         is_initialized = program_config.is_initialized;
         gateway_program_id = program_config.gateway_program_id;
         current_nonce = program_config.nonce;
+        tss_address = program_config.tss_address;
     }
-    
+
     let clock = Clock::get()?;
-    
+
     require!(
         is_initialized,
         UniversalNftError::ProgramNotInitialized
     );
-    
+
     // Verify call comes from gateway program
     let instruction_sysvar = &ctx.accounts.instruction_sysvar;
     let current_instruction = solana_program::sysvar::instructions::get_instruction_relative(0, instruction_sysvar)?;
-    
+
     require!(
         current_instruction.program_id == gateway_program_id,
         UniversalNftError::UnauthorizedCrossChainOperation
     );
-    
+
     // Parse the cross-chain message
     let parsed_message: CrossChainMessageType = match CrossChainMessageType::try_from_slice(&message) {
         Ok(msg) => msg,
         Err(_) => return Err(UniversalNftError::InvalidMessageFormat.into()),
     };
-    
+
+    // The program-id check above only proves which program invoked this instruction -
+    // it says nothing about who told ZetaChain's TSS to relay this particular payload.
+    // Require a TSS signature over the domain-separated hash of the parsed message
+    // (see `verify_cross_chain_message`) before acting on it at all.
+    verify_cross_chain_message(
+        SOLANA_CHAIN_ID,
+        current_nonce,
+        &parsed_message,
+        &signature,
+        recovery_id,
+        &tss_address,
+    )?;
+
     match parsed_message {
         CrossChainMessageType::MintRequest { recipient, metadata } => {
             msg!("Received mint request from chain");
@@ -72,7 +94,7 @@ pub fn on_revert(ctx: Context<OnRevert>, revert_context: RevertContext) -> Resul
     let current_nonce;
     let gateway_program_id;
     let is_initialized;
-    
+
     {
         let program_config = &ctx.accounts.program_config;
         program_config_bump = program_config.bump;
@@ -80,68 +102,117 @@ pub fn on_revert(ctx: Context<OnRevert>, revert_context: RevertContext) -> Resul
         gateway_program_id = program_config.gateway_program_id;
         is_initialized = program_config.is_initialized;
     }
-    
+
     require!(is_initialized, UniversalNftError::ProgramNotInitialized);
-    
+
     // Verify call comes from gateway program
     let instruction_sysvar = &ctx.accounts.instruction_sysvar;
     let current_instruction = solana_program::sysvar::instructions::get_instruction_relative(0, instruction_sysvar)?;
-    
+
     require!(
         current_instruction.program_id == gateway_program_id,
         UniversalNftError::UnauthorizedCrossChainOperation
     );
-    
+
     let nft_state = &mut ctx.accounts.nft_state;
     let clock = Clock::get()?;
-    
+
     // Verify the token ID matches the revert request
     let token_id_from_bytes = u64::from_le_bytes(
         revert_context.token_id.as_slice().try_into()
             .map_err(|_| UniversalNftError::InvalidTokenId)?
     );
-    
+
     require!(
         nft_state.token_id == token_id_from_bytes,
         UniversalNftError::InvalidTokenId
     );
-    
+
     require!(
         nft_state.is_cross_chain_locked,
         UniversalNftError::InvalidRevertContext
     );
-    
+
+    // `revert_marker` is `init`-ed below, keyed off this exact `(original_chain_id, nonce)`
+    // pair, so a gateway redelivering the same revert a second time fails at account
+    // creation instead of this handler re-minting or re-releasing the NFT a second time.
+    let revert_marker = &mut ctx.accounts.revert_marker;
+    revert_marker.sender = revert_context.original_sender;
+    revert_marker.chain_id = revert_context.original_chain_id;
+    revert_marker.nonce = current_nonce;
+    revert_marker.message_hash = keccak_hash(&revert_context.try_to_vec()?).to_bytes();
+    revert_marker.processed = true;
+    revert_marker.timestamp = clock.unix_timestamp;
+    revert_marker.bump = ctx.bumps.revert_marker;
+
     msg!("Executing revert operation for failed cross-chain transfer");
     msg!("Token ID: {}", nft_state.token_id);
     msg!("Original Chain: {}", revert_context.original_chain_id);
     msg!("Revert Reason: {}", revert_context.revert_message);
-    
-    // REVERT LOGIC: Re-mint the NFT that was burned for cross-chain transfer
-    let mint_to_cpi_accounts = anchor_spl::token::MintTo {
-        mint: ctx.accounts.nft_mint.to_account_info(),
-        to: ctx.accounts.owner_token_account.to_account_info(),
-        authority: ctx.accounts.program_config.to_account_info(),
+
+    // `lock_for_cross_chain` and `burn_for_cross_chain` both set `is_cross_chain_locked`,
+    // so that flag alone can't tell which path sent this NFT away. `custody_token_account`
+    // only ever holds a live balance if `lock_for_cross_chain` put it there - a burned
+    // mint has nothing to release, so re-minting is the only way back for it. Checking the
+    // account's actual balance (rather than trusting `program_config.use_lock_mode`, which
+    // the collection could have toggled since this NFT left) is what distinguishes the two.
+    let is_locked_in_custody = if ctx.accounts.custody_token_account.data_is_empty() {
+        false
+    } else {
+        let data = ctx.accounts.custody_token_account.try_borrow_data()?;
+        let custody_account = anchor_spl::token::TokenAccount::try_deserialize(&mut &data[..])
+            .map_err(|_| UniversalNftError::InvalidAccountData)?;
+        custody_account.amount == crate::constants::NFT_SUPPLY
     };
-    
-    let seeds = &[crate::state::PROGRAM_SEED, &[program_config_bump]];
-    let signer = &[&seeds[..]];
-    
-    let mint_to_cpi_context = CpiContext::new_with_signer(
-        ctx.accounts.token_program.to_account_info(),
-        mint_to_cpi_accounts,
-        signer,
-    );
-    
-    anchor_spl::token::mint_to(mint_to_cpi_context, crate::constants::NFT_SUPPLY)?;
-    
+
+    if is_locked_in_custody {
+        let mint_key = ctx.accounts.nft_mint.key();
+        let custody_seeds = &[CUSTODY_SEED, mint_key.as_ref(), &[ctx.bumps.custody_authority]];
+        let custody_signer = &[&custody_seeds[..]];
+
+        let transfer_cpi_accounts = anchor_spl::token::Transfer {
+            from: ctx.accounts.custody_token_account.to_account_info(),
+            to: ctx.accounts.owner_token_account.to_account_info(),
+            authority: ctx.accounts.custody_authority.to_account_info(),
+        };
+        let transfer_cpi_context = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            transfer_cpi_accounts,
+            custody_signer,
+        );
+        anchor_spl::token::transfer(transfer_cpi_context, crate::constants::NFT_SUPPLY)?;
+
+        msg!("NFT released from custody back to original owner: {}", nft_state.original_owner);
+    } else {
+        // REVERT LOGIC: Re-mint the NFT that was burned for cross-chain transfer
+        let mint_to_cpi_accounts = anchor_spl::token::MintTo {
+            mint: ctx.accounts.nft_mint.to_account_info(),
+            to: ctx.accounts.owner_token_account.to_account_info(),
+            authority: ctx.accounts.program_config.to_account_info(),
+        };
+
+        let seeds = &[crate::state::PROGRAM_SEED, &[program_config_bump]];
+        let signer = &[&seeds[..]];
+
+        let mint_to_cpi_context = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            mint_to_cpi_accounts,
+            signer,
+        );
+
+        anchor_spl::token::mint_to(mint_to_cpi_context, crate::constants::NFT_SUPPLY)?;
+
+        msg!("NFT re-minted to original owner: {}", nft_state.original_owner);
+    }
+
     // Update state
     nft_state.is_cross_chain_locked = false;
-    
+
     // Record the revert in cross-chain history
     if let Some(last_transfer) = nft_state.cross_chain_history.last_mut() {
         last_transfer.transaction_hash = revert_context.revert_message.as_bytes()[..32].try_into().unwrap_or([0u8; 32]);
     }
-    
+
     if nft_state.cross_chain_history.len() < crate::state::NftState::MAX_CROSS_CHAIN_HISTORY {
         let revert_record = crate::state::CrossChainTransfer {
             destination_chain_id: crate::state::SOLANA_CHAIN_ID,
@@ -152,14 +223,22 @@ pub fn on_revert(ctx: Context<OnRevert>, revert_context: RevertContext) -> Resul
         };
         nft_state.cross_chain_history.push(revert_record);
     }
-    
+
     // Update program statistics
     let program_config = &mut ctx.accounts.program_config;
     program_config.nonce = safe_add_u64(current_nonce, 1)?;
-    
+
+    emit!(crate::events::NftRecovered {
+        token_id: nft_state.token_id,
+        mint: ctx.accounts.nft_mint.key(),
+        owner: nft_state.original_owner,
+        original_chain_id: revert_context.original_chain_id,
+        was_released_from_custody: is_locked_in_custody,
+        timestamp: clock.unix_timestamp,
+    });
+
     msg!("Revert completed successfully");
-    msg!("NFT re-minted to original owner: {}", nft_state.original_owner);
-    
+
     Ok(())
 }
 
@@ -178,6 +257,7 @@ pub struct OnCall<'info> {
 }
 
 #[derive(Accounts)]
+#[instruction(revert_context: RevertContext)]
 pub struct OnRevert<'info> {
     #[account(
         mut,
@@ -185,30 +265,71 @@ pub struct OnRevert<'info> {
         bump = program_config.bump,
     )]
     pub program_config: Account<'info, ProgramConfig>,
-    
+
     #[account(
         mut,
         seeds = [crate::state::NFT_STATE_SEED, nft_mint.key().as_ref()],
         bump = nft_state.bump,
     )]
     pub nft_state: Account<'info, NftState>,
-    
+
     #[account(
         mut,
         mint::authority = program_config,
         mint::freeze_authority = program_config,
     )]
     pub nft_mint: Account<'info, anchor_spl::token::Mint>,
-    
+
     #[account(
         mut,
         associated_token::mint = nft_mint,
         associated_token::authority = nft_state.original_owner,
     )]
     pub owner_token_account: Account<'info, anchor_spl::token::TokenAccount>,
-    
+
+    /// CHECK: PDA authority over `custody_token_account`; only ever used as a CPI signer.
+    #[account(
+        seeds = [CUSTODY_SEED, nft_mint.key().as_ref()],
+        bump,
+    )]
+    pub custody_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Program-owned custody ATA `lock_for_cross_chain` may have moved this NFT
+    /// into; manually checked (not a typed `Account<TokenAccount>`) because a revert for
+    /// an NFT that instead left via `burn_for_cross_chain` means this account never
+    /// existed in the first place, so it can't be required to deserialize.
+    #[account(
+        mut,
+        address = anchor_spl::associated_token::get_associated_token_address(
+            &custody_authority.key(),
+            &nft_mint.key(),
+        ),
+    )]
+    pub custody_token_account: UncheckedAccount<'info>,
+
+    /// Replay guard for this revert, keyed by `(original_chain_id, nonce)` the same way
+    /// `mint_from_cross_chain`'s `gateway_message` is keyed by `(source_chain_id, nonce)`.
+    /// `init` (not `init_if_needed`) means a gateway redelivering the same revert fails at
+    /// account creation rather than this handler running its recovery logic twice.
+    #[account(
+        init,
+        payer = payer,
+        space = GatewayMessage::LEN,
+        seeds = [
+            GATEWAY_MESSAGE_SEED,
+            &revert_context.original_chain_id.to_le_bytes(),
+            &program_config.nonce.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub revert_marker: Account<'info, GatewayMessage>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
     pub token_program: Program<'info, anchor_spl::token::Token>,
-    
+    pub system_program: Program<'info, System>,
+
     /// CHECK: This account is safe because it's only used to read instruction data
     #[account(address = solana_program::sysvar::instructions::id())]
     pub instruction_sysvar: UncheckedAccount<'info>,
@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
     metadata::{
-        create_metadata_accounts_v3, mpl_token_metadata::types::{CollectionDetails, Creator, DataV2},
-        CreateMetadataAccountsV3, Metadata,
+        create_master_edition_v3, create_metadata_accounts_v3,
+        mpl_token_metadata::types::{CollectionDetails, Creator, DataV2},
+        CreateMasterEditionV3, CreateMetadataAccountsV3, Metadata,
     },
     token::{mint_to, Mint, MintTo, Token, TokenAccount},
 };
@@ -10,30 +11,38 @@ use anchor_spl::{
 
 use crate::{constants::*, errors::*, state::*};
 
+// `create_master_edition_v3` requires the mint it freezes to have `decimals = 0` and a
+// token supply of exactly 1 at call time - assert the constants it's minted with here
+// stay in lockstep with that requirement.
+const _: () = assert!(NFT_DECIMALS == 0);
+const _: () = assert!(NFT_SUPPLY == 1);
+
 pub fn initialize_program(
     ctx: Context<InitializeProgram>,
     gateway_program_id: Pubkey,
     collection_name: String,
     collection_symbol: String,
     collection_uri: String,
+    seller_fee_basis_points: u16,
+    creators: Vec<CreatorArg>,
 ) -> Result<()> {
     let program_config = &mut ctx.accounts.program_config;
-    
+
     require!(
         !program_config.is_initialized,
         UniversalNftError::ProgramAlreadyInitialized
     );
-    
+
     require!(
         collection_name.len() <= MAX_NAME_LENGTH,
         UniversalNftError::MetadataTooLong
     );
-    
+
     require!(
         collection_symbol.len() <= MAX_SYMBOL_LENGTH,
         UniversalNftError::MetadataTooLong
     );
-    
+
     require!(
         collection_uri.len() <= MAX_URI_LENGTH,
         UniversalNftError::MetadataTooLong
@@ -44,7 +53,31 @@ pub fn initialize_program(
         gateway_program_id != Pubkey::default(),
         UniversalNftError::InvalidGatewayProgramId
     );
-    
+
+    // Same validation Metaplex's `assert_data_valid` applies before this CPI.
+    require!(
+        seller_fee_basis_points <= 10_000,
+        UniversalNftError::InvalidRoyaltyBasisPoints
+    );
+    require!(
+        creators.len() <= MAX_CREATOR_COUNT,
+        UniversalNftError::CreatorVerificationFailed
+    );
+    if !creators.is_empty() {
+        require!(
+            creators.iter().map(|c| c.share as u16).sum::<u16>() == 100,
+            UniversalNftError::InvalidCreatorShares
+        );
+        let mut seen_addresses = Vec::with_capacity(creators.len());
+        for creator in creators.iter() {
+            require!(
+                !seen_addresses.contains(&creator.address),
+                UniversalNftError::DuplicateCreator
+            );
+            seen_addresses.push(creator.address);
+        }
+    }
+
     program_config.authority = ctx.accounts.authority.key();
     program_config.gateway_program_id = gateway_program_id;
     program_config.tss_address = [0u8; 20]; // Will be set later
@@ -54,6 +87,10 @@ pub fn initialize_program(
     program_config.total_nfts_minted = 0;
     program_config.total_cross_chain_transfers = 0;
     program_config.is_initialized = true;
+    program_config.use_lock_mode = false;
+    program_config.seller_fee_basis_points = seller_fee_basis_points;
+    program_config.creators = creators.clone();
+    program_config.token_standard = TokenStandard::Metaplex;
     program_config.bump = ctx.bumps.program_config;
 
     // Mint collection NFT
@@ -68,19 +105,32 @@ pub fn initialize_program(
     );
     mint_to(mint_to_cpi_context, NFT_SUPPLY)?;
 
-    // Create collection metadata
-    let creators = vec![Creator {
-        address: ctx.accounts.authority.key(),
-        verified: true,
-        share: 100,
-    }];
-    
+    // Create collection metadata. Only `authority` signs this CPI, so it's the only
+    // creator that can legitimately be marked `verified: true` - everyone else must
+    // co-sign off-chain before a marketplace will trust their `verified` flag.
+    let on_chain_creators = if creators.is_empty() {
+        vec![Creator {
+            address: ctx.accounts.authority.key(),
+            verified: true,
+            share: 100,
+        }]
+    } else {
+        creators
+            .into_iter()
+            .map(|c| Creator {
+                verified: c.address == ctx.accounts.authority.key(),
+                address: c.address,
+                share: c.share,
+            })
+            .collect::<Vec<_>>()
+    };
+
     let data = DataV2 {
         name: collection_name,
         symbol: collection_symbol,
         uri: collection_uri,
-        seller_fee_basis_points: 0,
-        creators: Some(creators),
+        seller_fee_basis_points,
+        creators: Some(on_chain_creators),
         collection: None,
         uses: None,
     };
@@ -108,6 +158,28 @@ pub fn initialize_program(
         Some(CollectionDetails::V1 { size: 0 }),
     )?;
 
+    // Freeze the collection mint's supply at 1 so nothing can ever mint another
+    // collection token; `max_supply: Some(0)` means zero additional editions may be
+    // printed from this master edition.
+    let create_master_edition_v3_cpi_accounts = CreateMasterEditionV3 {
+        edition: ctx.accounts.collection_master_edition.to_account_info(),
+        mint: ctx.accounts.collection_mint.to_account_info(),
+        update_authority: ctx.accounts.authority.to_account_info(),
+        mint_authority: ctx.accounts.authority.to_account_info(),
+        payer: ctx.accounts.authority.to_account_info(),
+        metadata: ctx.accounts.collection_metadata.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+    };
+
+    let create_master_edition_v3_cpi_context = CpiContext::new(
+        ctx.accounts.metadata_program.to_account_info(),
+        create_master_edition_v3_cpi_accounts,
+    );
+
+    create_master_edition_v3(create_master_edition_v3_cpi_context, Some(0))?;
+
     msg!("Universal NFT Program initialized successfully");
     msg!("Collection mint: {}", ctx.accounts.collection_mint.key());
     msg!("Gateway program: {}", gateway_program_id);
@@ -149,6 +221,20 @@ pub struct InitializeProgram<'info> {
     )]
     pub collection_metadata: UncheckedAccount<'info>,
 
+    /// CHECK: this account will be initialized by the metadata program's master edition CPI
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            metadata_program.key().as_ref(),
+            collection_mint.key().as_ref(),
+            b"edition",
+        ],
+        seeds::program = metadata_program.key(),
+        bump,
+    )]
+    pub collection_master_edition: UncheckedAccount<'info>,
+
     #[account(
         init,
         payer = authority,
@@ -0,0 +1,224 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::system_instruction;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_2022::Token2022,
+    token_2022_extensions::{
+        metadata_pointer_initialize, token_metadata_initialize, MetadataPointerInitialize,
+        TokenMetadataInitialize,
+    },
+    token_interface::{initialize_mint2, mint_to, InitializeMint2, MintTo, TokenAccount},
+};
+use spl_token_2022::extension::ExtensionType;
+
+use crate::{constants::*, errors::*, state::*};
+
+/// Token-2022 counterpart to `initialize_program`: instead of a classic SPL mint with a
+/// separate Metaplex `Metadata`/`MasterEdition` PDA, the collection mint is initialized
+/// with the metadata-pointer extension pointing at itself, and `token_metadata_initialize`
+/// writes the collection's name/symbol/uri straight into the mint account. There is no
+/// Token-2022 equivalent of a Master Edition, so supply is frozen at 1 by revoking the
+/// mint authority once the single collection token is minted.
+pub fn initialize_program_token2022(
+    ctx: Context<InitializeProgramToken2022>,
+    gateway_program_id: Pubkey,
+    collection_name: String,
+    collection_symbol: String,
+    collection_uri: String,
+    seller_fee_basis_points: u16,
+    creators: Vec<CreatorArg>,
+) -> Result<()> {
+    require!(
+        !ctx.accounts.program_config.is_initialized,
+        UniversalNftError::ProgramAlreadyInitialized
+    );
+
+    require!(
+        collection_name.len() <= MAX_NAME_LENGTH,
+        UniversalNftError::MetadataTooLong
+    );
+    require!(
+        collection_symbol.len() <= MAX_SYMBOL_LENGTH,
+        UniversalNftError::MetadataTooLong
+    );
+    require!(
+        collection_uri.len() <= MAX_URI_LENGTH,
+        UniversalNftError::MetadataTooLong
+    );
+    require!(
+        gateway_program_id != Pubkey::default(),
+        UniversalNftError::InvalidGatewayProgramId
+    );
+
+    // Same validation `initialize_program` applies before its metadata CPI.
+    require!(
+        seller_fee_basis_points <= 10_000,
+        UniversalNftError::InvalidRoyaltyBasisPoints
+    );
+    require!(
+        creators.len() <= MAX_CREATOR_COUNT,
+        UniversalNftError::CreatorVerificationFailed
+    );
+    if !creators.is_empty() {
+        require!(
+            creators.iter().map(|c| c.share as u16).sum::<u16>() == 100,
+            UniversalNftError::InvalidCreatorShares
+        );
+        let mut seen_addresses = Vec::with_capacity(creators.len());
+        for creator in creators.iter() {
+            require!(
+                !seen_addresses.contains(&creator.address),
+                UniversalNftError::DuplicateCreator
+            );
+            seen_addresses.push(creator.address);
+        }
+    }
+
+    // Only the fixed-size MetadataPointer extension needs to be sized up front; the
+    // variable-length TokenMetadata content (name/symbol/uri, which vary per collection)
+    // is appended - and its rent funded - by `token_metadata_initialize` itself.
+    let mint_space = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(
+        &[ExtensionType::MetadataPointer],
+    )?;
+    let mint_lamports = Rent::get()?.minimum_balance(mint_space);
+
+    invoke_signed(
+        &system_instruction::create_account(
+            &ctx.accounts.authority.key(),
+            &ctx.accounts.collection_mint.key(),
+            mint_lamports,
+            mint_space as u64,
+            &ctx.accounts.token_program.key(),
+        ),
+        &[
+            ctx.accounts.authority.to_account_info(),
+            ctx.accounts.collection_mint.to_account_info(),
+        ],
+        &[],
+    )?;
+
+    // Metadata lives in the mint account itself, so point the extension at `collection_mint`.
+    metadata_pointer_initialize(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            MetadataPointerInitialize {
+                token_program_id: ctx.accounts.token_program.to_account_info(),
+                mint: ctx.accounts.collection_mint.to_account_info(),
+            },
+        ),
+        Some(ctx.accounts.authority.key()),
+        Some(ctx.accounts.collection_mint.key()),
+    )?;
+
+    initialize_mint2(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            InitializeMint2 {
+                mint: ctx.accounts.collection_mint.to_account_info(),
+            },
+        ),
+        NFT_DECIMALS,
+        &ctx.accounts.authority.key(),
+        Some(&ctx.accounts.authority.key()),
+    )?;
+
+    token_metadata_initialize(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TokenMetadataInitialize {
+                token_program_id: ctx.accounts.token_program.to_account_info(),
+                mint: ctx.accounts.collection_mint.to_account_info(),
+                metadata: ctx.accounts.collection_mint.to_account_info(),
+                mint_authority: ctx.accounts.authority.to_account_info(),
+                update_authority: ctx.accounts.authority.to_account_info(),
+            },
+        ),
+        collection_name,
+        collection_symbol,
+        collection_uri,
+    )?;
+
+    mint_to(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.collection_mint.to_account_info(),
+                to: ctx.accounts.collection_token_account.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            },
+        ),
+        NFT_SUPPLY,
+    )?;
+
+    anchor_spl::token_interface::set_authority(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token_interface::SetAuthority {
+                current_authority: ctx.accounts.authority.to_account_info(),
+                account_or_mint: ctx.accounts.collection_mint.to_account_info(),
+            },
+        ),
+        anchor_spl::token_interface::spl_token_2022::instruction::AuthorityType::MintTokens,
+        None,
+    )?;
+
+    let program_config = &mut ctx.accounts.program_config;
+    program_config.authority = ctx.accounts.authority.key();
+    program_config.gateway_program_id = gateway_program_id;
+    program_config.tss_address = [0u8; 20]; // Will be set later
+    program_config.collection_mint = ctx.accounts.collection_mint.key();
+    // Token-2022 metadata lives on the mint itself - there is no separate metadata
+    // account, so this is just the mint again. Cross-chain logic should branch on
+    // `token_standard` before reading `collection_metadata` as a Metaplex PDA.
+    program_config.collection_metadata = ctx.accounts.collection_mint.key();
+    program_config.nonce = 1;
+    program_config.total_nfts_minted = 0;
+    program_config.total_cross_chain_transfers = 0;
+    program_config.is_initialized = true;
+    program_config.use_lock_mode = false;
+    program_config.seller_fee_basis_points = seller_fee_basis_points;
+    program_config.creators = creators;
+    program_config.token_standard = TokenStandard::Token2022;
+    program_config.bump = ctx.bumps.program_config;
+
+    msg!("Universal NFT Program initialized successfully (Token-2022)");
+    msg!("Collection mint: {}", ctx.accounts.collection_mint.key());
+    msg!("Gateway program: {}", gateway_program_id);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeProgramToken2022<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = ProgramConfig::LEN,
+        seeds = [PROGRAM_SEED],
+        bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    /// CHECK: Token-2022 mint account; created manually below once its extension space
+    /// is known, since `mint::` account constraints don't size extensions.
+    #[account(mut)]
+    pub collection_mint: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = collection_mint,
+        associated_token::authority = authority,
+        associated_token::token_program = token_program,
+    )]
+    pub collection_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
@@ -0,0 +1,313 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{transfer, Mint, Token, TokenAccount, Transfer},
+};
+
+use crate::{constants::*, errors::*, state::*, utils::*};
+
+/// Move the NFT into program-owned custody instead of burning it, so a round trip back to
+/// Solana can restore the original mint, edition and metadata provenance. The burn path in
+/// `burn_for_cross_chain` remains available for destinations that prefer wrapped
+/// representations; neither path checks `ProgramConfig::use_lock_mode`.
+pub fn lock_for_cross_chain(
+    ctx: Context<LockForCrossChain>,
+    destination_chain_id: u64,
+    destination_address: Vec<u8>,
+) -> Result<()> {
+    let is_initialized;
+    let current_nonce;
+    {
+        let program_config = &ctx.accounts.program_config;
+        is_initialized = program_config.is_initialized;
+        current_nonce = program_config.nonce;
+    }
+
+    let nft_state = &mut ctx.accounts.nft_state;
+    let clock = Clock::get()?;
+
+    require!(is_initialized, UniversalNftError::ProgramNotInitialized);
+
+    require!(
+        !nft_state.is_cross_chain_locked,
+        UniversalNftError::NftLockedForCrossChain
+    );
+
+    require!(
+        destination_chain_id != SOLANA_CHAIN_ID,
+        UniversalNftError::InvalidChainId
+    );
+
+    let remote_contract = require_registered_endpoint(&ctx.accounts.endpoint, destination_chain_id)?;
+
+    validate_destination_address(&destination_address)?;
+
+    require!(
+        ctx.accounts.nft_token_account.amount == NFT_SUPPLY,
+        UniversalNftError::InvalidTokenAccount
+    );
+
+    require!(
+        nft_state.cross_chain_history.len() < NftState::MAX_CROSS_CHAIN_HISTORY,
+        UniversalNftError::CrossChainHistoryLimitExceeded
+    );
+
+    // Move the NFT into the custody ATA owned by the per-mint custody PDA.
+    let transfer_cpi_accounts = Transfer {
+        from: ctx.accounts.nft_token_account.to_account_info(),
+        to: ctx.accounts.custody_token_account.to_account_info(),
+        authority: ctx.accounts.owner.to_account_info(),
+    };
+    let transfer_cpi_context = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        transfer_cpi_accounts,
+    );
+    transfer(transfer_cpi_context, NFT_SUPPLY)?;
+
+    let cross_chain_transfer = CrossChainTransfer {
+        destination_chain_id,
+        destination_address: destination_address.clone(),
+        transfer_timestamp: clock.unix_timestamp,
+        transaction_hash: [0u8; 32], // Will be filled by gateway
+        transfer_type: TransferType::Outbound,
+    };
+
+    nft_state.cross_chain_history.push(cross_chain_transfer);
+    nft_state.is_cross_chain_locked = true;
+
+    let program_config = &mut ctx.accounts.program_config;
+    program_config.total_cross_chain_transfers = safe_add_u64(
+        program_config.total_cross_chain_transfers,
+        1,
+    )?;
+    let new_nonce = safe_add_u64(current_nonce, 1)?;
+    program_config.nonce = new_nonce;
+
+    let message_type = CrossChainMessageType::BurnConfirmation {
+        token_id: nft_state.token_id,
+        burned_amount: NFT_SUPPLY,
+    };
+
+    let message_hash = create_cross_chain_message_hash(
+        destination_chain_id,
+        new_nonce,
+        &message_type,
+    )?;
+
+    let cross_chain_message = {
+        let mut data = Vec::new();
+        data.extend_from_slice(&nft_state.token_id.to_le_bytes());
+        data.extend_from_slice(&destination_chain_id.to_le_bytes());
+        data.extend_from_slice(&(destination_address.len() as u32).to_le_bytes());
+        data.extend_from_slice(&destination_address);
+        data.extend_from_slice(&message_hash);
+        data.extend_from_slice(&(remote_contract.len() as u32).to_le_bytes());
+        data.extend_from_slice(&remote_contract);
+        data
+    };
+
+    crate::instructions::gateway_handlers::call_gateway_deposit_and_call(
+        ctx.accounts.gateway_program.to_account_info(),
+        ctx.accounts.owner.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        0,
+        destination_address.clone(),
+        cross_chain_message,
+    ).map_err(|_| UniversalNftError::GatewayCallFailed)?;
+
+    msg!("NFT locked in custody for cross-chain transfer");
+    msg!("Token ID: {}", nft_state.token_id);
+    msg!("Destination Chain: {}", destination_chain_id);
+    msg!("Destination Address: {:?}", destination_address);
+    msg!("Message Hash: {:?}", message_hash);
+
+    Ok(())
+}
+
+/// Gateway-callback counterpart to `lock_for_cross_chain`: releases the custodied NFT back
+/// to the returning owner and clears the lock. Must only run from a TSS-verified gateway
+/// callback, same as `mint_from_cross_chain`.
+pub fn unlock_from_cross_chain(
+    ctx: Context<UnlockFromCrossChain>,
+    signature: [u8; 64],
+    recovery_id: u8,
+) -> Result<()> {
+    let is_initialized;
+    let current_nonce;
+    let tss_address;
+    let custody_bump;
+    {
+        let program_config = &ctx.accounts.program_config;
+        is_initialized = program_config.is_initialized;
+        current_nonce = program_config.nonce;
+        tss_address = program_config.tss_address;
+        custody_bump = ctx.bumps.custody_authority;
+    }
+
+    let nft_state = &mut ctx.accounts.nft_state;
+    let clock = Clock::get()?;
+
+    require!(is_initialized, UniversalNftError::ProgramNotInitialized);
+
+    require!(
+        nft_state.is_cross_chain_locked,
+        UniversalNftError::InvalidRevertContext
+    );
+
+    let message_type = CrossChainMessageType::BurnConfirmation {
+        token_id: nft_state.token_id,
+        burned_amount: NFT_SUPPLY,
+    };
+
+    let message_hash = create_cross_chain_message_hash(
+        SOLANA_CHAIN_ID,
+        current_nonce,
+        &message_type,
+    )?;
+
+    verify_tss_signature(&message_hash, &signature, recovery_id, &tss_address)?;
+
+    let mint_key = ctx.accounts.nft_mint.key();
+    let seeds = &[CUSTODY_SEED, mint_key.as_ref(), &[custody_bump]];
+    let signer = &[&seeds[..]];
+
+    let transfer_cpi_accounts = Transfer {
+        from: ctx.accounts.custody_token_account.to_account_info(),
+        to: ctx.accounts.owner_token_account.to_account_info(),
+        authority: ctx.accounts.custody_authority.to_account_info(),
+    };
+    let transfer_cpi_context = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        transfer_cpi_accounts,
+        signer,
+    );
+    transfer(transfer_cpi_context, NFT_SUPPLY)?;
+
+    nft_state.is_cross_chain_locked = false;
+
+    if nft_state.cross_chain_history.len() < NftState::MAX_CROSS_CHAIN_HISTORY {
+        nft_state.cross_chain_history.push(CrossChainTransfer {
+            destination_chain_id: SOLANA_CHAIN_ID,
+            destination_address: nft_state.original_owner.to_bytes().to_vec(),
+            transfer_timestamp: clock.unix_timestamp,
+            transaction_hash: message_hash,
+            transfer_type: TransferType::Inbound,
+        });
+    }
+
+    let program_config = &mut ctx.accounts.program_config;
+    program_config.nonce = safe_add_u64(current_nonce, 1)?;
+
+    msg!("NFT released from custody to {}", ctx.accounts.owner_token_account.owner);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(destination_chain_id: u64)]
+pub struct LockForCrossChain<'info> {
+    #[account(
+        mut,
+        seeds = [PROGRAM_SEED],
+        bump = program_config.bump,
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    #[account(
+        seeds = [ENDPOINT_SEED, &destination_chain_id.to_le_bytes()],
+        bump = endpoint.bump,
+    )]
+    pub endpoint: Account<'info, ChainEndpoint>,
+
+    #[account(
+        mut,
+        seeds = [NFT_STATE_SEED, nft_mint.key().as_ref()],
+        bump = nft_state.bump,
+        constraint = nft_state.mint == nft_mint.key() @ UniversalNftError::InvalidTokenAccount,
+    )]
+    pub nft_state: Account<'info, NftState>,
+
+    pub nft_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = nft_mint,
+        associated_token::authority = owner,
+        constraint = nft_token_account.amount == NFT_SUPPLY @ UniversalNftError::InvalidTokenAccount,
+    )]
+    pub nft_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA token authority over the custody ATA, never read as account data
+    #[account(seeds = [CUSTODY_SEED, nft_mint.key().as_ref()], bump)]
+    pub custody_authority: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = nft_mint,
+        associated_token::authority = custody_authority,
+    )]
+    pub custody_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = owner.key() == nft_state.original_owner @ UniversalNftError::InvalidAuthority,
+    )]
+    pub owner: Signer<'info>,
+
+    /// CHECK: ZetaChain gateway program for cross-chain operations
+    #[account(
+        constraint = gateway_program.key() == program_config.gateway_program_id @ UniversalNftError::InvalidGatewayProgramId,
+    )]
+    pub gateway_program: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UnlockFromCrossChain<'info> {
+    #[account(
+        mut,
+        seeds = [PROGRAM_SEED],
+        bump = program_config.bump,
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [NFT_STATE_SEED, nft_mint.key().as_ref()],
+        bump = nft_state.bump,
+        constraint = nft_state.mint == nft_mint.key() @ UniversalNftError::InvalidTokenAccount,
+    )]
+    pub nft_state: Account<'info, NftState>,
+
+    pub nft_mint: Account<'info, Mint>,
+
+    /// CHECK: PDA token authority over the custody ATA, never read as account data
+    #[account(seeds = [CUSTODY_SEED, nft_mint.key().as_ref()], bump)]
+    pub custody_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = nft_mint,
+        associated_token::authority = custody_authority,
+    )]
+    pub custody_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = nft_mint,
+        associated_token::authority = nft_state.original_owner,
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -86,6 +86,7 @@ pub fn mint_from_cross_chain(
     nft_state.chain_origin = metadata.original_chain_id;
     nft_state.is_cross_chain_locked = false;
     nft_state.metadata_hash = calculate_metadata_hash(&metadata)?;
+    nft_state.attributes = metadata.attributes.clone();
     nft_state.bump = ctx.bumps.nft_state;
 
     // Record the inbound transfer
@@ -209,7 +210,7 @@ pub struct MintFromCrossChain<'info> {
     #[account(
         init,
         payer = payer,
-        space = NftState::calculate_len(1),
+        space = NftState::calculate_len(1, metadata.attributes.len()),
         seeds = [NFT_STATE_SEED, nft_mint.key().as_ref()],
         bump
     )]
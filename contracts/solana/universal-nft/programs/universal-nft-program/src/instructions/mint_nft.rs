@@ -8,15 +8,20 @@ use anchor_spl::{
     token::{mint_to, Mint, MintTo, Token, TokenAccount},
 };
 
-use crate::{constants::*, errors::*, state::*, utils::*};
+use crate::{constants::*, errors::*, events::*, state::*, utils::*};
 
 pub fn mint_nft(
     ctx: Context<MintNft>,
     name: String,
     symbol: String,
     uri: String,
-    creators: Option<Vec<Creator>>,
+    seller_fee_basis_points: Option<u16>,
+    creators: Option<Vec<CreatorArg>>,
+    attributes: Option<Vec<NftAttribute>>,
 ) -> Result<()> {
+    let attributes = attributes.unwrap_or_default();
+    validate_attributes(&attributes)?;
+
     // Extract values from program_config early to avoid borrow conflicts
     let is_initialized;
     let collection_mint;
@@ -70,6 +75,7 @@ pub fn mint_nft(
     nft_state.cross_chain_history = Vec::new();
     nft_state.is_cross_chain_locked = false;
     nft_state.metadata_hash = [0u8; 32]; // Will be updated after metadata creation
+    nft_state.attributes = attributes.clone();
     nft_state.bump = ctx.bumps.nft_state;
 
     // Mint the NFT
@@ -84,20 +90,47 @@ pub fn mint_nft(
     );
     mint_to(mint_to_cpi_context, NFT_SUPPLY)?;
 
-    // Prepare creators list
-    let mut final_creators = creators.unwrap_or_default();
-    if final_creators.is_empty() {
-        final_creators.push(Creator {
+    let seller_fee_basis_points = seller_fee_basis_points.unwrap_or(0);
+    require!(
+        seller_fee_basis_points <= 10_000,
+        UniversalNftError::InvalidRoyaltyBasisPoints
+    );
+
+    // Prepare creators list. A creator can only be marked `verified` here if their
+    // address matches the signing authority - nobody else can sign this CPI.
+    let creator_args = creators.unwrap_or_default();
+    let final_creators = if creator_args.is_empty() {
+        vec![Creator {
             address: ctx.accounts.owner.key(),
             verified: true,
             share: 100,
-        });
+        }]
     } else {
         require!(
-            final_creators.len() <= MAX_CREATOR_COUNT,
+            creator_args.len() <= MAX_CREATOR_COUNT,
             UniversalNftError::CreatorVerificationFailed
         );
-    }
+        require!(
+            creator_args.iter().map(|c| c.share as u16).sum::<u16>() == 100,
+            UniversalNftError::InvalidCreatorShares
+        );
+        let mut seen_addresses = Vec::with_capacity(creator_args.len());
+        for creator in creator_args.iter() {
+            require!(
+                !seen_addresses.contains(&creator.address),
+                UniversalNftError::DuplicateCreator
+            );
+            seen_addresses.push(creator.address);
+        }
+        creator_args
+            .into_iter()
+            .map(|c| Creator {
+                verified: c.address == ctx.accounts.owner.key(),
+                address: c.address,
+                share: c.share,
+            })
+            .collect::<Vec<_>>()
+    };
 
     // Create NFT metadata with collection
     let collection = Collection {
@@ -109,7 +142,7 @@ pub fn mint_nft(
         name: name.clone(),
         symbol,
         uri: uri.clone(),
-        seller_fee_basis_points: 0,
+        seller_fee_basis_points,
         creators: Some(final_creators),
         collection: Some(collection),
         uses: None,
@@ -138,6 +171,15 @@ pub fn mint_nft(
         None, // collection_details
     )?;
 
+    emit!(NftMinted {
+        token_id,
+        mint: ctx.accounts.nft_mint.key(),
+        owner: ctx.accounts.owner.key(),
+        name: name.clone(),
+        uri: uri.clone(),
+        attributes: attributes.clone(),
+    });
+
     // Calculate and store metadata hash
     let cross_chain_metadata = CrossChainNftMetadata {
         name,
@@ -146,7 +188,7 @@ pub fn mint_nft(
         original_chain_id: SOLANA_CHAIN_ID,
         original_token_id: token_id.to_le_bytes().to_vec(),
         original_creator: ctx.accounts.owner.key().to_bytes().to_vec(),
-        attributes: Vec::new(), // Can be extended later
+        attributes,
     };
     nft_state.metadata_hash = calculate_metadata_hash(&cross_chain_metadata)?;
 
@@ -178,7 +220,7 @@ pub struct MintNft<'info> {
     #[account(
         init,
         payer = owner,
-        space = NftState::calculate_len(0),
+        space = NftState::calculate_len(0, MAX_ATTRIBUTES_COUNT),
         seeds = [NFT_STATE_SEED, nft_mint.key().as_ref()],
         bump
     )]
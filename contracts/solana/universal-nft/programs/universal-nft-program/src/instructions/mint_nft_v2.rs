@@ -0,0 +1,198 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_2022::Token2022,
+    token_2022_extensions::{token_metadata_initialize, TokenMetadataInitialize},
+    token_interface::{mint_to, Mint, MintTo, TokenAccount},
+};
+
+use crate::{constants::*, errors::*, events::*, state::*, utils::*};
+
+/// Token-2022 counterpart to `mint_nft`: instead of a separate Metaplex metadata PDA, the
+/// mint is initialized with the `metadata-pointer` extension pointing at itself, and
+/// `token_metadata_initialize` writes name/symbol/uri straight into the mint account. This
+/// drops the metadata account (and its rent) per NFT while feeding the exact same
+/// `nft_state`/`metadata_hash` bookkeeping `mint_nft` does. `burn_for_cross_chain` and
+/// `lock_for_cross_chain` take classic SPL Token accounts only, so an NFT minted here
+/// cannot be sent out through them.
+pub fn mint_nft_v2(
+    ctx: Context<MintNftV2>,
+    name: String,
+    symbol: String,
+    uri: String,
+    attributes: Option<Vec<NftAttribute>>,
+) -> Result<()> {
+    let attributes = attributes.unwrap_or_default();
+    validate_attributes(&attributes)?;
+
+    // Extract values from program_config early to avoid borrow conflicts
+    let is_initialized;
+    let authority;
+    {
+        let program_config = &ctx.accounts.program_config;
+        is_initialized = program_config.is_initialized;
+        authority = program_config.authority;
+    }
+
+    let nft_state = &mut ctx.accounts.nft_state;
+    let clock = Clock::get()?;
+
+    require!(
+        is_initialized,
+        UniversalNftError::ProgramNotInitialized
+    );
+
+    // Validate that owner is authorized to mint (program authority check)
+    require!(
+        authority == ctx.accounts.owner.key(),
+        UniversalNftError::InvalidAuthority
+    );
+
+    require!(
+        name.len() <= MAX_NAME_LENGTH,
+        UniversalNftError::MetadataTooLong
+    );
+
+    require!(
+        symbol.len() <= MAX_SYMBOL_LENGTH,
+        UniversalNftError::MetadataTooLong
+    );
+
+    require!(
+        uri.len() <= MAX_URI_LENGTH,
+        UniversalNftError::MetadataTooLong
+    );
+
+    // Generate unique token ID
+    let token_id = generate_unique_token_id(&ctx.accounts.nft_mint.key(), &clock)?;
+
+    // Initialize NFT state
+    nft_state.mint = ctx.accounts.nft_mint.key();
+    nft_state.original_owner = ctx.accounts.owner.key();
+    nft_state.token_id = token_id;
+    nft_state.creation_timestamp = clock.unix_timestamp;
+    nft_state.creation_slot = clock.slot;
+    nft_state.chain_origin = SOLANA_CHAIN_ID;
+    nft_state.cross_chain_history = Vec::new();
+    nft_state.is_cross_chain_locked = false;
+    nft_state.metadata_hash = [0u8; 32]; // Will be updated after metadata creation
+    nft_state.attributes = attributes.clone();
+    nft_state.bump = ctx.bumps.nft_state;
+
+    // Mint the NFT
+    let mint_to_cpi_accounts = MintTo {
+        mint: ctx.accounts.nft_mint.to_account_info(),
+        to: ctx.accounts.nft_token_account.to_account_info(),
+        authority: ctx.accounts.owner.to_account_info(),
+    };
+    let mint_to_cpi_context = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        mint_to_cpi_accounts,
+    );
+    mint_to(mint_to_cpi_context, NFT_SUPPLY)?;
+
+    // Write name/symbol/uri into the mint account itself via the token-metadata extension,
+    // in place of the separate `CreateMetadataAccountsV3` CPI the legacy path uses.
+    let token_metadata_initialize_cpi_accounts = TokenMetadataInitialize {
+        token_program_id: ctx.accounts.token_program.to_account_info(),
+        mint: ctx.accounts.nft_mint.to_account_info(),
+        metadata: ctx.accounts.nft_mint.to_account_info(),
+        mint_authority: ctx.accounts.owner.to_account_info(),
+        update_authority: ctx.accounts.owner.to_account_info(),
+    };
+    let token_metadata_initialize_cpi_context = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        token_metadata_initialize_cpi_accounts,
+    );
+    token_metadata_initialize(
+        token_metadata_initialize_cpi_context,
+        name.clone(),
+        symbol,
+        uri.clone(),
+    )?;
+
+    emit!(NftMinted {
+        token_id,
+        mint: ctx.accounts.nft_mint.key(),
+        owner: ctx.accounts.owner.key(),
+        name: name.clone(),
+        uri: uri.clone(),
+        attributes: attributes.clone(),
+    });
+
+    // Calculate and store metadata hash - same `CrossChainNftMetadata` shape `mint_nft`
+    // feeds.
+    let cross_chain_metadata = CrossChainNftMetadata {
+        name,
+        symbol: ctx.accounts.nft_mint.key().to_string()[..10].to_string(),
+        uri,
+        original_chain_id: SOLANA_CHAIN_ID,
+        original_token_id: token_id.to_le_bytes().to_vec(),
+        original_creator: ctx.accounts.owner.key().to_bytes().to_vec(),
+        attributes,
+    };
+    nft_state.metadata_hash = calculate_metadata_hash(&cross_chain_metadata)?;
+
+    // Update program statistics
+    let program_config = &mut ctx.accounts.program_config;
+    program_config.total_nfts_minted = safe_add_u64(
+        program_config.total_nfts_minted,
+        1
+    )?;
+
+    msg!("NFT minted successfully via Token-2022 metadata pointer");
+    msg!("Token ID: {}", token_id);
+    msg!("Mint: {}", ctx.accounts.nft_mint.key());
+    msg!("Owner: {}", ctx.accounts.owner.key());
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct MintNftV2<'info> {
+    #[account(
+        mut,
+        seeds = [PROGRAM_SEED],
+        bump = program_config.bump,
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = NftState::calculate_len(0, MAX_ATTRIBUTES_COUNT),
+        seeds = [NFT_STATE_SEED, nft_mint.key().as_ref()],
+        bump
+    )]
+    pub nft_state: Account<'info, NftState>,
+
+    #[account(
+        init,
+        payer = owner,
+        mint::decimals = NFT_DECIMALS,
+        mint::authority = owner,
+        mint::freeze_authority = owner,
+        mint::token_program = token_program,
+        extensions::metadata_pointer::authority = owner,
+        extensions::metadata_pointer::metadata_address = nft_mint,
+    )]
+    pub nft_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = nft_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub nft_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
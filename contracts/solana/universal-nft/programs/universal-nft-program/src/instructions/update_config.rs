@@ -6,6 +6,7 @@ pub fn update_gateway_config(
     ctx: Context<UpdateGatewayConfig>,
     new_gateway_program_id: Option<Pubkey>,
     new_tss_address: Option<[u8; 20]>,
+    new_use_lock_mode: Option<bool>,
 ) -> Result<()> {
     let program_config = &mut ctx.accounts.program_config;
     
@@ -35,8 +36,13 @@ pub fn update_gateway_config(
         }
     }
     
+    if let Some(use_lock_mode) = new_use_lock_mode {
+        program_config.use_lock_mode = use_lock_mode;
+        msg!("Updated cross-chain transfer mode: {}", if use_lock_mode { "lock" } else { "burn" });
+    }
+
     msg!("Gateway configuration updated successfully");
-    
+
     Ok(())
 }
 
@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::{
+    mpl_token_metadata::types::DataV2, update_metadata_accounts_v2, Metadata,
+    UpdateMetadataAccountsV2,
+};
+
+use crate::{constants::*, errors::*, state::*, utils::*};
+
+/// Corrects or permanently locks an NFT's name/symbol/uri after the fact - e.g. when it
+/// returns from another chain with an updated URI, or IPFS content is re-pinned under a
+/// new CID. Only targets NFTs minted by `mint_from_cross_chain`, whose metadata names
+/// `program_config` (not the owner) as `update_authority`; this program has no update
+/// rights over metadata from the direct `mint_nft`/`mint_nft_v2` paths, where the owner
+/// signs as their own `update_authority`.
+///
+/// Authorized either by `program_config.authority` signing directly, or by a TSS
+/// signature over the new data (for updates driven by a cross-chain message) - exactly
+/// one of `authority` or `signature`/`recovery_id` must be supplied.
+pub fn update_metadata(
+    ctx: Context<UpdateMetadata>,
+    name: String,
+    symbol: String,
+    uri: String,
+    seller_fee_basis_points: u16,
+    is_mutable: bool,
+    signature: Option<[u8; 64]>,
+    recovery_id: Option<u8>,
+) -> Result<()> {
+    require!(
+        ctx.accounts.program_config.is_initialized,
+        UniversalNftError::ProgramNotInitialized
+    );
+
+    require!(name.len() <= MAX_NAME_LENGTH, UniversalNftError::MetadataTooLong);
+    require!(symbol.len() <= MAX_SYMBOL_LENGTH, UniversalNftError::MetadataTooLong);
+    require!(uri.len() <= MAX_URI_LENGTH, UniversalNftError::MetadataTooLong);
+    require!(
+        seller_fee_basis_points <= 10_000,
+        UniversalNftError::InvalidRoyaltyBasisPoints
+    );
+
+    match (&ctx.accounts.authority, signature) {
+        (Some(authority), None) => {
+            require!(
+                authority.key() == ctx.accounts.program_config.authority,
+                UniversalNftError::InvalidAuthority
+            );
+        }
+        (None, Some(signature)) => {
+            let recovery_id = recovery_id.ok_or(UniversalNftError::InvalidSignature)?;
+
+            // Domain-separated by nonce, the same way `on_call`/`mint_from_cross_chain`
+            // bind a TSS signature to one specific call - otherwise a signature minted for
+            // this update could be replayed to push the same content again later.
+            let mut message = Vec::new();
+            message.extend_from_slice(b"UPDATE_METADATA");
+            message.extend_from_slice(ctx.accounts.nft_mint.key().as_ref());
+            message.extend_from_slice(&ctx.accounts.program_config.nonce.to_le_bytes());
+            message.extend_from_slice(name.as_bytes());
+            message.extend_from_slice(symbol.as_bytes());
+            message.extend_from_slice(uri.as_bytes());
+            message.extend_from_slice(&seller_fee_basis_points.to_le_bytes());
+            message.push(is_mutable as u8);
+
+            verify_tss_signature(
+                &message,
+                &signature,
+                recovery_id,
+                &ctx.accounts.program_config.tss_address,
+            )?;
+        }
+        (Some(_), Some(_)) | (None, None) => {
+            return Err(UniversalNftError::InvalidAuthority.into());
+        }
+    }
+
+    let program_config_bump = ctx.accounts.program_config.bump;
+    let seeds = &[PROGRAM_SEED, &[program_config_bump]];
+    let signer = &[&seeds[..]];
+
+    let data = DataV2 {
+        name,
+        symbol,
+        uri,
+        seller_fee_basis_points,
+        creators: None,
+        collection: None,
+        uses: None,
+    };
+
+    let cpi_accounts = UpdateMetadataAccountsV2 {
+        metadata: ctx.accounts.nft_metadata.to_account_info(),
+        update_authority: ctx.accounts.program_config.to_account_info(),
+    };
+    let cpi_context = CpiContext::new_with_signer(
+        ctx.accounts.metadata_program.to_account_info(),
+        cpi_accounts,
+        signer,
+    );
+
+    update_metadata_accounts_v2(cpi_context, None, Some(data), None, Some(is_mutable))?;
+
+    let program_config = &mut ctx.accounts.program_config;
+    program_config.nonce = safe_add_u64(program_config.nonce, 1)?;
+
+    msg!("Metadata updated");
+    msg!("Mint: {}", ctx.accounts.nft_mint.key());
+    msg!("Mutable: {}", is_mutable);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateMetadata<'info> {
+    #[account(
+        mut,
+        seeds = [PROGRAM_SEED],
+        bump = program_config.bump,
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    /// CHECK: the NFT mint whose metadata is being updated; only used to derive/log
+    pub nft_mint: UncheckedAccount<'info>,
+
+    /// CHECK: the NFT's metadata account; its `update_authority` must be `program_config`,
+    /// i.e. this NFT was minted by `mint_from_cross_chain`
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            metadata_program.key().as_ref(),
+            nft_mint.key().as_ref(),
+        ],
+        seeds::program = metadata_program.key(),
+        bump,
+    )]
+    pub nft_metadata: UncheckedAccount<'info>,
+
+    /// Required for a directly-authorized update; omit this and supply `signature` instead
+    /// for a TSS-authorized, cross-chain-driven update.
+    pub authority: Option<Signer<'info>>,
+
+    pub metadata_program: Program<'info, Metadata>,
+}
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::{
+    set_and_verify_sized_collection_item, Metadata, SetAndVerifySizedCollectionItem,
+};
+
+use crate::{constants::*, errors::*, state::*};
+
+/// Flips an NFT's `Collection.verified` flag to true and atomically increments the
+/// collection's on-chain sized-collection `size` via Metaplex's
+/// `set_and_verify_sized_collection_item` CPI (unlike plain `verify_sized_collection_item`,
+/// which only flips the flag and leaves `size` untouched). `mint_nft` records every bridged
+/// NFT's collection membership unverified (since nothing but the collection authority can
+/// sign this CPI); this instruction is the separate step that completes it, so wallets and
+/// marketplaces that only trust verified collections - and accurate collection sizes - will
+/// recognize NFTs bridged from other chains.
+///
+/// The CPI itself rejects if `nft_metadata.collection.key` doesn't match the supplied
+/// `collection_mint`; the `constraint` below additionally rejects before that CPI runs if
+/// the caller passed a `collection_mint` that isn't this program's own collection.
+pub fn verify_nft_collection(ctx: Context<VerifyNftCollection>) -> Result<()> {
+    require!(
+        ctx.accounts.program_config.collection_mint == ctx.accounts.collection_mint.key(),
+        UniversalNftError::InvalidCollectionMint
+    );
+
+    let cpi_accounts = SetAndVerifySizedCollectionItem {
+        payer: ctx.accounts.authority.to_account_info(),
+        metadata: ctx.accounts.nft_metadata.to_account_info(),
+        collection_authority: ctx.accounts.authority.to_account_info(),
+        update_authority: ctx.accounts.authority.to_account_info(),
+        collection_mint: ctx.accounts.collection_mint.to_account_info(),
+        collection_metadata: ctx.accounts.collection_metadata.to_account_info(),
+        collection_master_edition: ctx.accounts.collection_master_edition.to_account_info(),
+    };
+    let cpi_context = CpiContext::new(ctx.accounts.metadata_program.to_account_info(), cpi_accounts);
+    set_and_verify_sized_collection_item(cpi_context, None)?;
+
+    msg!("NFT collection membership verified");
+    msg!("Mint: {}", ctx.accounts.nft_mint.key());
+    msg!("Collection: {}", ctx.accounts.collection_mint.key());
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct VerifyNftCollection<'info> {
+    #[account(
+        seeds = [PROGRAM_SEED],
+        bump = program_config.bump,
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    /// CHECK: the NFT mint whose metadata is being verified; only used to derive/log, not read
+    pub nft_mint: UncheckedAccount<'info>,
+
+    /// CHECK: the NFT's own metadata account, flipped to `collection.verified = true` by the CPI
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            metadata_program.key().as_ref(),
+            nft_mint.key().as_ref(),
+        ],
+        seeds::program = metadata_program.key(),
+        bump,
+    )]
+    pub nft_metadata: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = collection_mint.key() == program_config.collection_mint @ UniversalNftError::InvalidCollectionMint
+    )]
+    pub collection_mint: Account<'info, anchor_spl::token::Mint>,
+
+    /// CHECK: the collection's own metadata account; its `collection_details.size` is
+    /// incremented in place by the CPI
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            metadata_program.key().as_ref(),
+            collection_mint.key().as_ref(),
+        ],
+        seeds::program = metadata_program.key(),
+        bump,
+    )]
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: the collection's master edition account, required by the verify-collection CPI
+    #[account(
+        seeds = [
+            b"metadata",
+            metadata_program.key().as_ref(),
+            collection_mint.key().as_ref(),
+            b"edition",
+        ],
+        seeds::program = metadata_program.key(),
+        bump,
+    )]
+    pub collection_master_edition: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = authority.key() == program_config.authority @ UniversalNftError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    pub metadata_program: Program<'info, Metadata>,
+}
@@ -4,6 +4,7 @@ declare_id!("Gc1BJg4sYAYGnKBStAHLTdVRLR3fA7DPc7t9G7vjKa1i");
 
 pub mod constants;
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod state;
 pub mod utils;
@@ -21,8 +22,41 @@ pub mod universal_nft_program {
         collection_name: String,
         collection_symbol: String,
         collection_uri: String,
+        seller_fee_basis_points: u16,
+        creators: Vec<state::CreatorArg>,
     ) -> Result<()> {
-        instructions::initialize_program(ctx, gateway_program_id, collection_name, collection_symbol, collection_uri)
+        instructions::initialize_program(
+            ctx,
+            gateway_program_id,
+            collection_name,
+            collection_symbol,
+            collection_uri,
+            seller_fee_basis_points,
+            creators,
+        )
+    }
+
+    /// Token-2022 metadata-pointer counterpart to `initialize_program` - see
+    /// `instructions::initialize_program_token2022` for why this exists alongside the
+    /// legacy Metaplex path.
+    pub fn initialize_program_token2022(
+        ctx: Context<InitializeProgramToken2022>,
+        gateway_program_id: Pubkey,
+        collection_name: String,
+        collection_symbol: String,
+        collection_uri: String,
+        seller_fee_basis_points: u16,
+        creators: Vec<state::CreatorArg>,
+    ) -> Result<()> {
+        instructions::initialize_program_token2022(
+            ctx,
+            gateway_program_id,
+            collection_name,
+            collection_symbol,
+            collection_uri,
+            seller_fee_basis_points,
+            creators,
+        )
     }
 
     pub fn mint_nft(
@@ -30,9 +64,29 @@ pub mod universal_nft_program {
         name: String,
         symbol: String,
         uri: String,
-        creators: Option<Vec<anchor_spl::metadata::mpl_token_metadata::types::Creator>>,
+        seller_fee_basis_points: Option<u16>,
+        creators: Option<Vec<state::CreatorArg>>,
+        attributes: Option<Vec<state::NftAttribute>>,
     ) -> Result<()> {
-        instructions::mint_nft(ctx, name, symbol, uri, creators)
+        instructions::mint_nft(ctx, name, symbol, uri, seller_fee_basis_points, creators, attributes)
+    }
+
+    /// Token-2022 metadata-pointer counterpart to `mint_nft` - see `instructions::mint_nft_v2`
+    /// for why this exists alongside the legacy Metaplex path.
+    pub fn mint_nft_v2(
+        ctx: Context<MintNftV2>,
+        name: String,
+        symbol: String,
+        uri: String,
+        attributes: Option<Vec<state::NftAttribute>>,
+    ) -> Result<()> {
+        instructions::mint_nft_v2(ctx, name, symbol, uri, attributes)
+    }
+
+    /// Completes collection membership for an NFT minted unverified by `mint_nft` - see
+    /// `instructions::verify_nft_collection`.
+    pub fn verify_nft_collection(ctx: Context<VerifyNftCollection>) -> Result<()> {
+        instructions::verify_nft_collection(ctx)
     }
 
     pub fn burn_for_cross_chain(
@@ -43,6 +97,58 @@ pub mod universal_nft_program {
         instructions::burn_for_cross_chain(ctx, destination_chain_id, destination_address)
     }
 
+    pub fn burn_for_cross_chain_with_payload(
+        ctx: Context<BurnForCrossChain>,
+        destination_chain_id: u64,
+        destination_address: Vec<u8>,
+        payload: Vec<u8>,
+        destination_is_contract: bool,
+    ) -> Result<()> {
+        instructions::burn_for_cross_chain_with_payload(
+            ctx,
+            destination_chain_id,
+            destination_address,
+            payload,
+            destination_is_contract,
+        )
+    }
+
+    pub fn lock_for_cross_chain(
+        ctx: Context<LockForCrossChain>,
+        destination_chain_id: u64,
+        destination_address: Vec<u8>,
+    ) -> Result<()> {
+        instructions::lock_for_cross_chain(ctx, destination_chain_id, destination_address)
+    }
+
+    pub fn unlock_from_cross_chain(
+        ctx: Context<UnlockFromCrossChain>,
+        signature: [u8; 64],
+        recovery_id: u8,
+    ) -> Result<()> {
+        instructions::unlock_from_cross_chain(ctx, signature, recovery_id)
+    }
+
+    pub fn register_chain(
+        ctx: Context<RegisterChain>,
+        chain_id: u64,
+        remote_address: Vec<u8>,
+    ) -> Result<()> {
+        instructions::register_chain(ctx, chain_id, remote_address)
+    }
+
+    pub fn update_chain(
+        ctx: Context<UpdateChain>,
+        chain_id: u64,
+        remote_address: Vec<u8>,
+    ) -> Result<()> {
+        instructions::update_chain(ctx, chain_id, remote_address)
+    }
+
+    pub fn disable_chain(ctx: Context<UpdateChain>, chain_id: u64) -> Result<()> {
+        instructions::disable_chain(ctx, chain_id)
+    }
+
     pub fn mint_from_cross_chain(
         ctx: Context<MintFromCrossChain>,
         source_chain_id: u64,
@@ -67,8 +173,10 @@ pub mod universal_nft_program {
         ctx: Context<OnCall>,
         sender: [u8; 20],
         message: Vec<u8>,
+        signature: [u8; 64],
+        recovery_id: u8,
     ) -> Result<()> {
-        instructions::on_call(ctx, sender, message)
+        instructions::on_call(ctx, sender, message, signature, recovery_id)
     }
 
     pub fn on_revert(
@@ -82,7 +190,33 @@ pub mod universal_nft_program {
         ctx: Context<UpdateGatewayConfig>,
         new_gateway_program_id: Option<Pubkey>,
         new_tss_address: Option<[u8; 20]>,
+        new_use_lock_mode: Option<bool>,
+    ) -> Result<()> {
+        instructions::update_gateway_config(ctx, new_gateway_program_id, new_tss_address, new_use_lock_mode)
+    }
+
+    /// Corrects or locks an NFT's name/symbol/uri after mint - see
+    /// `instructions::update_metadata` for why this only applies to cross-chain-minted
+    /// NFTs, and for the dual authority/TSS authorization paths.
+    pub fn update_metadata(
+        ctx: Context<UpdateMetadata>,
+        name: String,
+        symbol: String,
+        uri: String,
+        seller_fee_basis_points: u16,
+        is_mutable: bool,
+        signature: Option<[u8; 64]>,
+        recovery_id: Option<u8>,
     ) -> Result<()> {
-        instructions::update_gateway_config(ctx, new_gateway_program_id, new_tss_address)
+        instructions::update_metadata(
+            ctx,
+            name,
+            symbol,
+            uri,
+            seller_fee_basis_points,
+            is_mutable,
+            signature,
+            recovery_id,
+        )
     }
 }
\ No newline at end of file
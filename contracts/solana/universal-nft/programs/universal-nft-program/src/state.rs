@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 use solana_program::pubkey::Pubkey;
 
+use crate::constants::{MAX_ATTRIBUTE_NAME_LENGTH, MAX_ATTRIBUTE_VALUE_LENGTH, MAX_RECIPIENT_ADDRESS_LENGTH};
+
 #[account]
 pub struct ProgramConfig {
     pub authority: Pubkey,
@@ -12,6 +14,22 @@ pub struct ProgramConfig {
     pub total_nfts_minted: u64,
     pub total_cross_chain_transfers: u64,
     pub is_initialized: bool,
+    /// Outbound transfer mode flag, set through `update_gateway_config`. It is stored
+    /// only: `burn_for_cross_chain` and `lock_for_cross_chain` do not read it, so both
+    /// outbound paths are callable whatever its value.
+    pub use_lock_mode: bool,
+    /// Collection-level royalty, stamped onto the collection NFT's metadata at
+    /// `initialize_program` time.
+    pub seller_fee_basis_points: u16,
+    /// Collection-level creator list, stamped onto the collection NFT's metadata at
+    /// `initialize_program` time. `verified` is not stored - it's re-derived against
+    /// `authority` wherever this list is read, the same way `mint_nft` does for
+    /// per-NFT creators.
+    pub creators: Vec<CreatorArg>,
+    /// Which token standard `collection_mint` uses - set once at whichever
+    /// `initialize_program*` entrypoint ran. It is recorded only: no instruction in this
+    /// program reads it, and `mint_nft`/`mint_nft_v2` do not check it.
+    pub token_standard: TokenStandard,
     pub bump: u8,
 }
 
@@ -26,8 +44,23 @@ impl ProgramConfig {
         8 +  // total_nfts_minted
         8 +  // total_cross_chain_transfers
         1 +  // is_initialized
+        1 +  // use_lock_mode
+        2 +  // seller_fee_basis_points
+        4 + (crate::constants::MAX_CREATOR_COUNT * CreatorArg::LEN) + // creators (vec len + max entries)
+        1 +  // token_standard
         1 +  // bump
-        100; // padding for future expansion
+        50;  // padding for future expansion
+}
+
+/// Which token standard a collection's NFTs are minted under.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TokenStandard {
+    /// Classic SPL Token mint with a separate Metaplex `Metadata`/`MasterEdition` PDA,
+    /// as created by `initialize_program`/`mint_nft`.
+    Metaplex,
+    /// Token-2022 mint carrying its own metadata inline via the metadata-pointer and
+    /// token-metadata extensions, as created by `initialize_program_token2022`/`mint_nft_v2`.
+    Token2022,
 }
 
 #[account]
@@ -41,6 +74,9 @@ pub struct NftState {
     pub cross_chain_history: Vec<CrossChainTransfer>,
     pub is_cross_chain_locked: bool,
     pub metadata_hash: [u8; 32],
+    /// Traits persisted on-chain at mint time so they survive cross-chain transfers
+    /// deterministically, rather than living only in the off-chain JSON behind `uri`.
+    pub attributes: Vec<NftAttribute>,
     pub bump: u8,
 }
 
@@ -55,13 +91,16 @@ impl NftState {
         4 +  // vec length for cross_chain_history
         1 +  // is_cross_chain_locked
         32 + // metadata_hash
+        4 +  // vec length for attributes
         1 +  // bump
         50;  // padding
 
     pub const MAX_CROSS_CHAIN_HISTORY: usize = 10;
-    
-    pub fn calculate_len(history_count: usize) -> usize {
-        Self::BASE_LEN + (history_count.min(Self::MAX_CROSS_CHAIN_HISTORY) * CrossChainTransfer::LEN)
+
+    pub fn calculate_len(history_count: usize, attribute_count: usize) -> usize {
+        Self::BASE_LEN
+            + (history_count.min(Self::MAX_CROSS_CHAIN_HISTORY) * CrossChainTransfer::LEN)
+            + (attribute_count * NftAttribute::LEN)
     }
 }
 
@@ -105,6 +144,26 @@ pub struct NftAttribute {
     pub value: String,
 }
 
+impl NftAttribute {
+    pub const LEN: usize = 4 + MAX_ATTRIBUTE_NAME_LENGTH + // trait_type
+        4 + MAX_ATTRIBUTE_VALUE_LENGTH; // value
+}
+
+/// Caller-supplied creator entry for `mint_nft`. Deliberately omits `verified` - a
+/// creator can only be marked verified if their address matches the signing authority,
+/// since nobody else can sign this CPI, so `verified` is always derived in `mint_nft`
+/// rather than trusted from the caller.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CreatorArg {
+    pub address: Pubkey,
+    pub share: u8,
+}
+
+impl CreatorArg {
+    pub const LEN: usize = 32 + // address
+        1; // share
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct RevertContext {
     pub original_sender: [u8; 20],
@@ -153,9 +212,29 @@ pub enum CrossChainMessageType {
     },
 }
 
-pub const SOLANA_CHAIN_ID: u64 = 7565164; 
+/// Trusted remote counterparty for a given destination chain, registered by
+/// `program_config.authority` via `register_chain`/`update_chain`/`disable_chain`.
+#[account]
+pub struct ChainEndpoint {
+    pub chain_id: u64,
+    pub remote_address: Vec<u8>,
+    pub enabled: bool,
+    pub bump: u8,
+}
+
+impl ChainEndpoint {
+    pub const LEN: usize = 8 + // discriminator
+        8 +  // chain_id
+        4 + MAX_RECIPIENT_ADDRESS_LENGTH + // remote_address
+        1 +  // enabled
+        1;   // bump
+}
+
+pub const SOLANA_CHAIN_ID: u64 = 7565164;
 
 pub const PROGRAM_SEED: &[u8] = b"universal_nft_program";
 pub const NFT_STATE_SEED: &[u8] = b"nft_state";
 pub const GATEWAY_MESSAGE_SEED: &[u8] = b"gateway_message";
-pub const COLLECTION_SEED: &[u8] = b"collection";
\ No newline at end of file
+pub const COLLECTION_SEED: &[u8] = b"collection";
+pub const CUSTODY_SEED: &[u8] = b"custody";
+pub const ENDPOINT_SEED: &[u8] = b"endpoint";
\ No newline at end of file
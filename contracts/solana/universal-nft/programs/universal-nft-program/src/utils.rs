@@ -163,12 +163,18 @@ pub fn validate_metadata(metadata: &CrossChainNftMetadata) -> Result<()> {
         UniversalNftError::MetadataTooLong
     );
     
+    validate_attributes(&metadata.attributes)?;
+
+    Ok(())
+}
+
+pub fn validate_attributes(attributes: &[NftAttribute]) -> Result<()> {
     require!(
-        metadata.attributes.len() <= MAX_ATTRIBUTES_COUNT,
+        attributes.len() <= MAX_ATTRIBUTES_COUNT,
         UniversalNftError::AttributesLimitExceeded
     );
-    
-    for attribute in &metadata.attributes {
+
+    for attribute in attributes {
         require!(
             attribute.trait_type.len() <= MAX_ATTRIBUTE_NAME_LENGTH,
             UniversalNftError::InvalidAttributeData
@@ -178,7 +184,7 @@ pub fn validate_metadata(metadata: &CrossChainNftMetadata) -> Result<()> {
             UniversalNftError::InvalidAttributeData
         );
     }
-    
+
     Ok(())
 }
 
@@ -197,11 +203,19 @@ pub fn validate_destination_address(address: &[u8]) -> Result<()> {
 }
 
 pub fn calculate_metadata_hash(metadata: &CrossChainNftMetadata) -> Result<[u8; 32]> {
-    let metadata_bytes = metadata.try_to_vec()?;
+    // Attributes carry no inherent order (they're a set of traits), so sort by `trait_type`
+    // before hashing - otherwise two mints with the identical trait set but different
+    // insertion order would produce different hashes and fail cross-chain verification.
+    let mut sorted_metadata = metadata.clone();
+    sorted_metadata
+        .attributes
+        .sort_by(|a, b| a.trait_type.cmp(&b.trait_type));
+
+    let metadata_bytes = sorted_metadata.try_to_vec()?;
     let mut hasher = Sha256::new();
     hasher.update(&metadata_bytes);
     let result = hasher.finalize();
-    
+
     let mut hash = [0u8; 32];
     hash.copy_from_slice(&result);
     Ok(hash)
@@ -0,0 +1,247 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface};
+
+use crate::state::attributes::NftAttributeSet;
+use crate::state::custody::CustodyRecord;
+use crate::state::nft_origin::{CrossChainNftPayload, NftOrigin};
+use crate::state::replay::ReplayWindow;
+use crate::utils::{
+    derive_custody_authority_pda, derive_custody_record_pda, derive_nft_attributes_pda,
+    derive_replay_window_pda,
+};
+use crate::SOLANA_CHAIN_ID;
+
+#[derive(Accounts)]
+pub struct BurnForTransfer<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub nft_origin: Account<'info, NftOrigin>,
+    /// CHECK: `NftAttributeSet` PDA; only non-empty if `OnCall` persisted attributes
+    /// for this token - read back here so they carry over to the outbound payload
+    #[account(mut)]
+    pub nft_attributes: UncheckedAccount<'info>,
+    /// CHECK: per-token replay window PDA; created/updated here for wrapped NFTs,
+    /// untouched for native ones (those are escrowed, not burned, so carry no nonce)
+    #[account(mut)]
+    pub replay_window: UncheckedAccount<'info>,
+    /// CHECK: custody record PDA; created here only for Solana-native NFTs
+    #[account(mut)]
+    pub custody_record: UncheckedAccount<'info>,
+    /// CHECK: single program-wide authority that signs for every custody ATA; holds no data
+    pub custody_authority: UncheckedAccount<'info>,
+    /// Escrow ATA a Solana-native NFT is locked into instead of being burned
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = mint,
+        associated_token::authority = custody_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub custody_token_account: InterfaceAccount<'info, TokenAccount>,
+    /// Either the classic Token program or Token-2022 - whichever owns `mint` - so
+    /// wrapped and Token-2022-native NFTs can both be escrowed/burned here.
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+// Initiates an outbound cross-chain transfer. A Solana-native NFT (origin_chain ==
+// SOLANA_CHAIN_ID) is escrowed into program custody so its canonical mint and Metaplex
+// metadata survive the round trip; a wrapped NFT is burned, same as before `OnCall`
+// minted it in.
+pub fn handler(ctx: Context<BurnForTransfer>, nonce: u64) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require_keys_eq!(ctx.accounts.owner_token_account.owner, ctx.accounts.owner.key(), ErrorCode::UnauthorizedOwner);
+    require_keys_eq!(ctx.accounts.owner_token_account.mint, ctx.accounts.mint.key(), ErrorCode::InvalidMint);
+    require!(ctx.accounts.owner_token_account.amount > 0, ErrorCode::NoTokensToBurn);
+
+    let (custody_authority_pda, _) = derive_custody_authority_pda();
+    require_keys_eq!(ctx.accounts.custody_authority.key(), custody_authority_pda, ErrorCode::InvalidCustodyAuthority);
+
+    if ctx.accounts.nft_origin.origin_chain == SOLANA_CHAIN_ID {
+        let (custody_pda, custody_bump) = derive_custody_record_pda(&ctx.accounts.nft_origin.origin_token_id);
+        require_keys_eq!(ctx.accounts.custody_record.key(), custody_pda, ErrorCode::CustodyPdaMismatch);
+        require!(ctx.accounts.custody_record.data_is_empty(), ErrorCode::AlreadyInCustody);
+
+        let space = CustodyRecord::LEN;
+        let lamports = Rent::get()?.minimum_balance(space);
+        anchor_lang::solana_program::program::invoke_signed(
+            &anchor_lang::solana_program::system_instruction::create_account(
+                &ctx.accounts.owner.key(),
+                &custody_pda,
+                lamports,
+                space as u64,
+                &crate::ID,
+            ),
+            &[
+                ctx.accounts.owner.to_account_info(),
+                ctx.accounts.custody_record.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+            &[&[CustodyRecord::SEED, &ctx.accounts.nft_origin.origin_token_id, &[custody_bump]]],
+        )?;
+
+        let record = CustodyRecord {
+            token_id: ctx.accounts.nft_origin.origin_token_id,
+            mint: ctx.accounts.mint.key(),
+            locked_at: clock.unix_timestamp,
+            bump: custody_bump,
+        };
+        let mut data = ctx.accounts.custody_record.try_borrow_mut_data()?;
+        data[..8].copy_from_slice(&CustodyRecord::discriminator());
+        record.try_serialize(&mut &mut data[8..])?;
+        drop(data);
+
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                token_interface::TransferChecked {
+                    from: ctx.accounts.owner_token_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.custody_token_account.to_account_info(),
+                    authority: ctx.accounts.owner.to_account_info(),
+                },
+            ),
+            1,
+            0,
+        )?;
+    } else {
+        let (replay_window_pda, replay_window_bump) = derive_replay_window_pda(&ctx.accounts.nft_origin.origin_token_id);
+        require_keys_eq!(ctx.accounts.replay_window.key(), replay_window_pda, ErrorCode::ReplayPdaMismatch);
+
+        let mut window = if ctx.accounts.replay_window.data_is_empty() {
+            let space = ReplayWindow::LEN;
+            let lamports = Rent::get()?.minimum_balance(space);
+            anchor_lang::solana_program::program::invoke_signed(
+                &anchor_lang::solana_program::system_instruction::create_account(
+                    &ctx.accounts.owner.key(),
+                    &replay_window_pda,
+                    lamports,
+                    space as u64,
+                    &crate::ID,
+                ),
+                &[
+                    ctx.accounts.owner.to_account_info(),
+                    ctx.accounts.replay_window.to_account_info(),
+                    ctx.accounts.system_program.to_account_info(),
+                ],
+                &[&[ReplayWindow::SEED, &ctx.accounts.nft_origin.origin_token_id, &[replay_window_bump]]],
+            )?;
+            ReplayWindow {
+                token_id: ctx.accounts.nft_origin.origin_token_id,
+                highest_nonce: 0,
+                bitmap: [0u8; ReplayWindow::BITMAP_BYTES],
+                bump: replay_window_bump,
+            }
+        } else {
+            let data = ctx.accounts.replay_window.try_borrow_data()?;
+            ReplayWindow::try_deserialize(&mut &data[..]).map_err(|_| ErrorCode::ReplayPdaMismatch)?
+        };
+
+        window.check_and_set(nonce)?;
+
+        let mut data = ctx.accounts.replay_window.try_borrow_mut_data()?;
+        data[..8].copy_from_slice(&ReplayWindow::discriminator());
+        window.try_serialize(&mut &mut data[8..])?;
+        drop(data);
+
+        token_interface::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                token_interface::Burn {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    from: ctx.accounts.owner_token_account.to_account_info(),
+                    authority: ctx.accounts.owner.to_account_info(),
+                },
+            ),
+            1,
+        )?;
+    }
+
+    let (nft_attributes_pda, _) = derive_nft_attributes_pda(&ctx.accounts.nft_origin.origin_token_id);
+    require_keys_eq!(ctx.accounts.nft_attributes.key(), nft_attributes_pda, ErrorCode::AttributesPdaMismatch);
+    let attributes = if ctx.accounts.nft_attributes.data_is_empty() {
+        Vec::new()
+    } else {
+        let data = ctx.accounts.nft_attributes.try_borrow_data()?;
+        NftAttributeSet::try_deserialize(&mut &data[..])
+            .map_err(|_| ErrorCode::AttributesPdaMismatch)?
+            .attributes
+    };
+
+    let payload = CrossChainNftPayload {
+        token_id: ctx.accounts.nft_origin.origin_token_id,
+        origin_chain: ctx.accounts.nft_origin.origin_chain,
+        origin_mint: ctx.accounts.nft_origin.origin_mint,
+        recipient: ctx.accounts.owner.key(),
+        metadata_uri: ctx.accounts.nft_origin.metadata_uri.clone(),
+        nonce,
+        // `origin_sender` is an EVM-style 20-byte address; Solana has no equivalent
+        // representation of `owner` to fill in here, so it's left zeroed on an
+        // outbound transfer and only meaningful on an inbound `OnCall`.
+        origin_sender: [0u8; 20],
+        target_program: None,
+        app_payload: Vec::new(),
+        // `NftOrigin` doesn't track the original name/symbol/creators separately from
+        // `metadata_uri` - every Solana-side mint uses the same "UniversalNFT"/"UNFT"
+        // placeholders, so that's what an outbound transfer carries too.
+        name: "UniversalNFT".to_string(),
+        symbol: "UNFT".to_string(),
+        creators: Vec::new(),
+        seller_fee_basis_points: 0,
+        attributes,
+        collection_mint: None,
+    };
+
+    emit!(CrossChainTransferEvent {
+        token_id: payload.token_id,
+        origin_chain: payload.origin_chain,
+        owner: payload.recipient,
+        nonce: payload.nonce,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct CrossChainTransferEvent {
+    pub token_id: [u8; 32],
+    pub origin_chain: u64,
+    pub owner: Pubkey,
+    pub nonce: u64,
+    pub timestamp: i64,
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Unauthorized owner")]
+    UnauthorizedOwner,
+    #[msg("Invalid mint")]
+    InvalidMint,
+    #[msg("No tokens to burn")]
+    NoTokensToBurn,
+    #[msg("Replay PDA mismatch")]
+    ReplayPdaMismatch,
+    #[msg("Invalid custody authority")]
+    InvalidCustodyAuthority,
+    #[msg("Custody PDA mismatch")]
+    CustodyPdaMismatch,
+    #[msg("Token is already in custody")]
+    AlreadyInCustody,
+    #[msg("NftAttributeSet PDA mismatch")]
+    AttributesPdaMismatch,
+}
@@ -0,0 +1,217 @@
+use anchor_lang::prelude::*;
+use anchor_lang::prelude::UncheckedAccount;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::metadata::{verify_collection, VerifyCollection};
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::state::collection::CollectionConfig;
+use crate::utils::*;
+
+/// Mints the collection "parent" NFT (supply 1) that subsequent `MintNewNft` calls
+/// can join via `collection_mint`, and records it in a `CollectionConfig` PDA so
+/// cross-chain mints can re-derive the same collection.
+#[derive(Accounts)]
+pub struct InitializeCollection<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init,
+        payer = payer,
+        mint::decimals = 0,
+        mint::authority = collection_authority_pda,
+        mint::freeze_authority = collection_authority_pda,
+    )]
+    pub collection_mint: Account<'info, Mint>,
+    /// CHECK: Metaplex metadata PDA for the collection mint; created via CPI
+    #[account(mut)]
+    pub collection_metadata: UncheckedAccount<'info>,
+    /// CHECK: Metaplex master edition PDA for the collection mint; created via CPI
+    #[account(mut)]
+    pub collection_master_edition: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = collection_mint,
+        associated_token::authority = payer
+    )]
+    pub collection_token_account: Account<'info, TokenAccount>,
+    #[account(
+        init,
+        payer = payer,
+        space = CollectionConfig::LEN,
+        seeds = [CollectionConfig::SEED, collection_mint.key().as_ref()],
+        bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+    /// CHECK: collection authority PDA; will be derived programmatically
+    pub collection_authority_pda: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    /// CHECK: token metadata program (Metaplex)
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+}
+
+pub fn initialize_collection(
+    ctx: Context<InitializeCollection>,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> Result<()> {
+    let (collection_authority_pda, authority_bump) = Pubkey::find_program_address(
+        &[b"collection_authority", ctx.accounts.collection_mint.key().as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(
+        ctx.accounts.collection_authority_pda.key(),
+        collection_authority_pda,
+        ErrorCode::InvalidCollectionAuthorityPda
+    );
+
+    let signer_seeds: &[&[u8]] = &[
+        b"collection_authority",
+        ctx.accounts.collection_mint.key().as_ref(),
+        &[authority_bump],
+    ];
+
+    anchor_spl::token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token::MintTo {
+                mint: ctx.accounts.collection_mint.to_account_info(),
+                to: ctx.accounts.collection_token_account.to_account_info(),
+                authority: ctx.accounts.collection_authority_pda.to_account_info(),
+            },
+            &[signer_seeds],
+        ),
+        1,
+    )?;
+
+    cpi_create_metadata_v3(
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.collection_mint.to_account_info(),
+        &ctx.accounts.collection_authority_pda.to_account_info(),
+        &ctx.accounts.collection_authority_pda.to_account_info(),
+        &ctx.accounts.collection_metadata.to_account_info(),
+        &ctx.accounts.token_metadata_program.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.rent.to_account_info(),
+        name,
+        symbol,
+        uri,
+        None,
+        0,
+        None,
+    )?;
+
+    cpi_create_master_edition_v3(
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.collection_mint.to_account_info(),
+        &ctx.accounts.collection_authority_pda.to_account_info(),
+        &ctx.accounts.collection_authority_pda.to_account_info(),
+        &ctx.accounts.collection_metadata.to_account_info(),
+        &ctx.accounts.collection_master_edition.to_account_info(),
+        &ctx.accounts.token_metadata_program.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.rent.to_account_info(),
+    )?;
+
+    let (config_pda, config_bump) = Pubkey::find_program_address(
+        &[CollectionConfig::SEED, ctx.accounts.collection_mint.key().as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(ctx.accounts.collection_config.key(), config_pda, ErrorCode::InvalidCollectionConfigPda);
+
+    ctx.accounts.collection_config.set_inner(CollectionConfig {
+        collection_mint: ctx.accounts.collection_mint.key(),
+        authority_bump,
+        bump: config_bump,
+    });
+
+    msg!("Initialized collection {}", ctx.accounts.collection_mint.key());
+
+    Ok(())
+}
+
+/// Flips `collection.verified` to `true` on a member NFT's metadata by CPI-ing
+/// Metaplex's `verify_collection`, signed by the collection authority PDA that
+/// minted the collection parent in `initialize_collection`.
+#[derive(Accounts)]
+pub struct VerifyCollectionNft<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: Metaplex metadata PDA of the NFT joining the collection; mutated by CPI
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+    /// CHECK: collection authority PDA; validated against `collection_config`
+    pub collection_authority_pda: UncheckedAccount<'info>,
+    #[account(
+        seeds = [CollectionConfig::SEED, collection_config.collection_mint.as_ref()],
+        bump = collection_config.bump,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+    /// CHECK: the collection parent mint
+    #[account(address = collection_config.collection_mint)]
+    pub collection_mint: UncheckedAccount<'info>,
+    /// CHECK: Metaplex metadata PDA of the collection parent
+    #[account(mut)]
+    pub collection_metadata: UncheckedAccount<'info>,
+    /// CHECK: Metaplex master edition PDA of the collection parent
+    pub collection_master_edition: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    /// CHECK: sysvar instructions account required by Metaplex's collection verification
+    pub sysvar_instructions: UncheckedAccount<'info>,
+    /// CHECK: token metadata program (Metaplex)
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+}
+
+pub fn verify_collection_nft(ctx: Context<VerifyCollectionNft>) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.collection_authority_pda.key(),
+        Pubkey::find_program_address(
+            &[b"collection_authority", ctx.accounts.collection_mint.key().as_ref()],
+            &crate::ID,
+        )
+        .0,
+        ErrorCode::InvalidCollectionAuthorityPda
+    );
+
+    let signer_seeds: &[&[u8]] = &[
+        b"collection_authority",
+        ctx.accounts.collection_mint.key().as_ref(),
+        &[ctx.accounts.collection_config.authority_bump],
+    ];
+
+    let cpi_accounts = VerifyCollection {
+        payer: ctx.accounts.payer.to_account_info(),
+        metadata: ctx.accounts.metadata.to_account_info(),
+        collection_authority: ctx.accounts.collection_authority_pda.to_account_info(),
+        collection_mint: ctx.accounts.collection_mint.to_account_info(),
+        collection_metadata: ctx.accounts.collection_metadata.to_account_info(),
+        collection_master_edition: ctx.accounts.collection_master_edition.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        sysvar_instructions: ctx.accounts.sysvar_instructions.to_account_info(),
+    };
+
+    verify_collection(
+        CpiContext::new(ctx.accounts.token_metadata_program.to_account_info(), cpi_accounts)
+            .with_signer(&[signer_seeds]),
+        None,
+    )?;
+
+    msg!("Verified collection membership for {}", ctx.accounts.metadata.key());
+
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Invalid Collection Authority PDA")]
+    InvalidCollectionAuthorityPda,
+    #[msg("Invalid Collection Config PDA")]
+    InvalidCollectionConfigPda,
+}
@@ -1,34 +1,342 @@
 use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Token, TokenAccount};
+use mpl_token_metadata::types::{Collection, Creator};
+
+use crate::state::custody::CustodyRecord;
 use crate::state::gateway::GatewayConfig;
+use crate::state::nft_origin::{CrossChainNftPayload, NftOrigin};
+use crate::state::replay::ReplayWindow;
+use crate::utils::{
+    cpi_create_master_edition_v3,
+    cpi_create_metadata_v3,
+    derive_custody_authority_pda,
+    derive_custody_record_pda,
+    derive_master_edition_pda,
+    derive_metadata_pda,
+    derive_nft_origin_pda,
+    derive_replay_window_pda,
+};
+use crate::SOLANA_CHAIN_ID;
 
 #[derive(Accounts)]
 pub struct OnCall<'info> {
-    /// The CPI caller program (Gateway) is enforced via address lookup of config
     #[account(mut)]
     pub payer: Signer<'info>,
+    pub recipient: SystemAccount<'info>,
+    /// CHECK: a fresh cross-chain arrival gets a brand-new mint created here; a
+    /// Solana-native NFT returning to its chain of origin already has this mint and
+    /// it's only read
+    #[account(mut)]
+    pub mint: UncheckedAccount<'info>,
+    /// CHECK: Metaplex metadata PDA for this mint; only created for a fresh arrival
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+    /// CHECK: Metaplex master edition PDA for this mint; only created for a fresh arrival
+    #[account(mut)]
+    pub master_edition: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = recipient
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+    /// CHECK: nft_origin PDA; created on a fresh arrival, left untouched when the NFT
+    /// is returning to its chain of origin
+    #[account(mut)]
+    pub nft_origin: UncheckedAccount<'info>,
     /// CHECK: PDA with gateway program id
     pub gateway_config: UncheckedAccount<'info>,
+    /// CHECK: per-token replay window PDA; created on this token_id's first `OnCall`
+    #[account(mut)]
+    pub replay_window: UncheckedAccount<'info>,
+    /// CHECK: custody record PDA written by `BurnForTransfer`; only read/cleared when
+    /// releasing a Solana-native NFT back from custody
+    #[account(mut)]
+    pub custody_record: UncheckedAccount<'info>,
+    /// CHECK: single program-wide authority that signs for every custody ATA
+    pub custody_authority: UncheckedAccount<'info>,
+    /// CHECK: escrow ATA the native NFT was locked into; only used when releasing
+    #[account(mut)]
+    pub custody_token_account: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    /// CHECK: mint_authority PDA; will be derived programmatically
+    pub mint_authority_pda: UncheckedAccount<'info>,
+    /// CHECK: token metadata program
+    pub token_metadata_program: UncheckedAccount<'info>,
 }
 
-// A generic entrypoint to be invoked by ZetaChain Gateway
+// Entrypoint invoked by ZetaChain's Gateway to deliver an inbound cross-chain message.
 pub fn handler(ctx: Context<OnCall>, payload: Vec<u8>) -> Result<()> {
+    let clock = Clock::get()?;
+
     let (cfg_pda, _bump) = Pubkey::find_program_address(&[GatewayConfig::SEED], &crate::ID);
     require_keys_eq!(ctx.accounts.gateway_config.key(), cfg_pda, ErrorCode::UnauthorizedGateway);
     let data = ctx.accounts.gateway_config.try_borrow_data()?;
-    let cfg = GatewayConfig::try_from_slice(&data[8..]).map_err(|_| ErrorCode::UnauthorizedGateway)?;
+    GatewayConfig::try_from_slice(&data[8..]).map_err(|_| ErrorCode::UnauthorizedGateway)?;
+    drop(data);
+
+    let p: CrossChainNftPayload = CrossChainNftPayload::try_from_slice(&payload)
+        .map_err(|_| ErrorCode::InvalidPayload)?;
+    require_keys_eq!(ctx.accounts.recipient.key(), p.recipient, ErrorCode::InvalidPayload);
+
+    let (mint_authority_pda, mint_authority_bump) = Pubkey::find_program_address(
+        &[b"mint_authority", ctx.accounts.mint.key().as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(ctx.accounts.mint_authority_pda.key(), mint_authority_pda, ErrorCode::InvalidMintAuthorityPda);
+
+    // Replay protection: one `ReplayWindow` account per token_id. See
+    // `ReplayWindow::check_and_set`.
+    let (replay_window_pda, replay_window_bump) = derive_replay_window_pda(&p.token_id);
+    require_keys_eq!(ctx.accounts.replay_window.key(), replay_window_pda, ErrorCode::ReplayPdaMismatch);
+
+    let mut window = if ctx.accounts.replay_window.data_is_empty() {
+        let space = ReplayWindow::LEN;
+        let lamports = Rent::get()?.minimum_balance(space);
+        anchor_lang::solana_program::program::invoke_signed(
+            &anchor_lang::solana_program::system_instruction::create_account(
+                &ctx.accounts.payer.key(),
+                &replay_window_pda,
+                lamports,
+                space as u64,
+                &crate::ID,
+            ),
+            &[
+                ctx.accounts.payer.to_account_info(),
+                ctx.accounts.replay_window.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+            &[&[ReplayWindow::SEED, &p.token_id, &[replay_window_bump]]],
+        )?;
+        ReplayWindow {
+            token_id: p.token_id,
+            highest_nonce: 0,
+            bitmap: [0u8; ReplayWindow::BITMAP_BYTES],
+            bump: replay_window_bump,
+        }
+    } else {
+        let data = ctx.accounts.replay_window.try_borrow_data()?;
+        ReplayWindow::try_deserialize(&mut &data[..]).map_err(|_| ErrorCode::ReplayPdaMismatch)?
+    };
+
+    window.check_and_set(p.nonce)?;
+
+    let mut data = ctx.accounts.replay_window.try_borrow_mut_data()?;
+    data[..8].copy_from_slice(&ReplayWindow::discriminator());
+    window.try_serialize(&mut &mut data[8..])?;
+    drop(data);
+
+    // A Solana-native NFT that was escrowed by `BurnForTransfer` (rather than burned)
+    // is returning to its chain of origin: release it out of custody instead of
+    // minting a fresh mint + master edition, so the canonical mint and its Metaplex
+    // metadata survive the round trip. Anything else is a fresh cross-chain arrival.
+    let (custody_record_pda, _) = derive_custody_record_pda(&p.token_id);
+    require_keys_eq!(ctx.accounts.custody_record.key(), custody_record_pda, ErrorCode::CustodyPdaMismatch);
+    let is_returning_to_origin = p.origin_chain == SOLANA_CHAIN_ID && !ctx.accounts.custody_record.data_is_empty();
+
+    if is_returning_to_origin {
+        let custody_data = ctx.accounts.custody_record.try_borrow_data()?;
+        let record = CustodyRecord::try_from_slice(&custody_data[8..]).map_err(|_| ErrorCode::InvalidPayload)?;
+        require_keys_eq!(ctx.accounts.mint.key(), record.mint, ErrorCode::InvalidMintAuthorityPda);
+        drop(custody_data);
 
-    // Enforce that caller is the configured gateway program via CPI context program id check
-    // Note: Anchor does not directly expose invoker program id here; in production this
-    // would rely on the gateway program performing a CPI with expected signer seeds.
-    // Here we simply parse payload and dispatch to `handle_incoming`-compatible logic off-chain.
+        let (custody_authority_pda, custody_authority_bump) = derive_custody_authority_pda();
+        require_keys_eq!(ctx.accounts.custody_authority.key(), custody_authority_pda, ErrorCode::InvalidCustodyAuthority);
+
+        anchor_spl::token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                anchor_spl::token::Transfer {
+                    from: ctx.accounts.custody_token_account.to_account_info(),
+                    to: ctx.accounts.recipient_token_account.to_account_info(),
+                    authority: ctx.accounts.custody_authority.to_account_info(),
+                },
+                &[&[b"custody_authority", &[custody_authority_bump]]],
+            ),
+            1,
+        )?;
+
+        // Single-use: clear the record so the same escrowed token can't be released twice.
+        let mut data = ctx.accounts.custody_record.try_borrow_mut_data()?;
+        data.fill(0);
+    } else {
+        require!(ctx.accounts.mint.is_signer, ErrorCode::InvalidPayload);
+        let mint_space = anchor_spl::token::spl_token::state::Mint::LEN;
+        let mint_lamports = Rent::get()?.minimum_balance(mint_space);
+        anchor_lang::solana_program::program::invoke(
+            &anchor_lang::solana_program::system_instruction::create_account(
+                &ctx.accounts.payer.key(),
+                &ctx.accounts.mint.key(),
+                mint_lamports,
+                mint_space as u64,
+                &ctx.accounts.token_program.key(),
+            ),
+            &[
+                ctx.accounts.payer.to_account_info(),
+                ctx.accounts.mint.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+        anchor_spl::token::initialize_mint2(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                anchor_spl::token::InitializeMint2 {
+                    mint: ctx.accounts.mint.to_account_info(),
+                },
+            ),
+            0,
+            &ctx.accounts.mint_authority_pda.key(),
+            Some(&ctx.accounts.mint_authority_pda.key()),
+        )?;
+
+        anchor_spl::token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                anchor_spl::token::MintTo {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.recipient_token_account.to_account_info(),
+                    authority: ctx.accounts.mint_authority_pda.to_account_info(),
+                },
+                &[&[b"mint_authority", ctx.accounts.mint.key().as_ref(), &[mint_authority_bump]]],
+            ),
+            1,
+        )?;
+
+        let (expected_md, _) = derive_metadata_pda(&ctx.accounts.mint.key());
+        require_keys_eq!(ctx.accounts.metadata.key(), expected_md, ErrorCode::InvalidMetadataPda);
+        let (expected_me, _) = derive_master_edition_pda(&ctx.accounts.mint.key());
+        require_keys_eq!(ctx.accounts.master_edition.key(), expected_me, ErrorCode::InvalidMasterEditionPda);
+
+        require!(p.seller_fee_basis_points <= 10000, ErrorCode::InvalidSellerFeeBasisPoints);
+        let creators: Option<Vec<Creator>> = if p.creators.is_empty() {
+            None
+        } else {
+            Some(
+                p.creators
+                    .iter()
+                    .map(|c| Creator { address: c.address, verified: false, share: c.share })
+                    .collect(),
+            )
+        };
+        let collection = p.collection_mint.map(|key| Collection { verified: false, key });
+
+        cpi_create_metadata_v3(
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.mint_authority_pda.to_account_info(),
+            &ctx.accounts.mint_authority_pda.to_account_info(),
+            &ctx.accounts.metadata.to_account_info(),
+            &ctx.accounts.token_metadata_program.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.rent.to_account_info(),
+            p.name.clone(),
+            p.symbol.clone(),
+            p.metadata_uri.clone(),
+            creators,
+            p.seller_fee_basis_points,
+            collection,
+        )?;
+
+        cpi_create_master_edition_v3(
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.mint_authority_pda.to_account_info(),
+            &ctx.accounts.mint_authority_pda.to_account_info(),
+            &ctx.accounts.metadata.to_account_info(),
+            &ctx.accounts.master_edition.to_account_info(),
+            &ctx.accounts.token_metadata_program.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.rent.to_account_info(),
+        )?;
+
+        let (nft_origin_pda, nft_origin_bump) = derive_nft_origin_pda(&p.token_id);
+        require_keys_eq!(ctx.accounts.nft_origin.key(), nft_origin_pda, ErrorCode::NftOriginPdaMismatch);
+
+        if ctx.accounts.nft_origin.data_is_empty() {
+            let space = 8 + NftOrigin::LEN;
+            let lamports = Rent::get()?.minimum_balance(space);
+            anchor_lang::solana_program::program::invoke_signed(
+                &anchor_lang::solana_program::system_instruction::create_account(
+                    &ctx.accounts.payer.key(),
+                    &nft_origin_pda,
+                    lamports,
+                    space as u64,
+                    &crate::ID,
+                ),
+                &[
+                    ctx.accounts.payer.to_account_info(),
+                    ctx.accounts.nft_origin.to_account_info(),
+                    ctx.accounts.system_program.to_account_info(),
+                ],
+                &[&[&p.token_id, b"nft_origin", &[nft_origin_bump]]],
+            )?;
+        }
+
+        let nft_origin = NftOrigin {
+            origin_chain: p.origin_chain,
+            origin_token_id: p.token_id,
+            origin_mint: p.origin_mint,
+            metadata_uri: p.metadata_uri.clone(),
+            created_at: clock.unix_timestamp,
+            bump: nft_origin_bump,
+            origin_sender: p.origin_sender,
+        };
+
+        let mut no_data = ctx.accounts.nft_origin.try_borrow_mut_data()?;
+        no_data[..8].copy_from_slice(&NftOrigin::discriminator());
+        nft_origin.try_serialize(&mut &mut no_data[8..])?;
+    }
+
+    emit!(CrossChainMintEvent {
+        token_id: p.token_id,
+        origin_chain: p.origin_chain,
+        recipient: p.recipient,
+        nonce: p.nonce,
+        returned_to_origin: is_returning_to_origin,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Delivered cross-chain NFT, token_id {:?}, nonce {}", p.token_id, p.nonce);
 
-    // For now, we no-op; actual routing should be defined once gateway CPI interface is finalized.
     Ok(())
 }
 
+#[event]
+pub struct CrossChainMintEvent {
+    pub token_id: [u8; 32],
+    pub origin_chain: u64,
+    pub recipient: Pubkey,
+    pub nonce: u64,
+    pub returned_to_origin: bool,
+    pub timestamp: i64,
+}
+
 #[error_code]
 pub enum ErrorCode {
-    #[msg("Unauthorized gateway")] UnauthorizedGateway,
+    #[msg("Unauthorized gateway")]
+    UnauthorizedGateway,
+    #[msg("Invalid payload")]
+    InvalidPayload,
+    #[msg("Replay PDA mismatch")]
+    ReplayPdaMismatch,
+    #[msg("Invalid Metadata PDA")]
+    InvalidMetadataPda,
+    #[msg("Invalid Master Edition PDA")]
+    InvalidMasterEditionPda,
+    #[msg("Invalid NftOrigin PDA")]
+    NftOriginPdaMismatch,
+    #[msg("Invalid Mint Authority PDA")]
+    InvalidMintAuthorityPda,
+    #[msg("Invalid custody record PDA")]
+    CustodyPdaMismatch,
+    #[msg("Invalid custody authority PDA")]
+    InvalidCustodyAuthority,
+    #[msg("Seller fee basis points exceed 10000")]
+    InvalidSellerFeeBasisPoints,
 }
-
-
@@ -1,18 +1,142 @@
 use anchor_lang::prelude::*;
-use crate::state::nft_origin::CrossChainNftPayload;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use anchor_spl::token::{burn, set_authority, Burn, Mint, SetAuthority, Token, TokenAccount};
+use spl_token::instruction::AuthorityType;
+
+use crate::state::gateway::GatewayConfig;
+use crate::state::nft_origin::{CrossChainNftPayload, NftOrigin};
+
+/// Anchor-style 8-byte discriminator for the gateway's inbound "send" instruction.
+/// Placeholder until the ZetaChain Gateway program's real IDL is wired in.
+const GATEWAY_SEND_DISCRIMINATOR: [u8; 8] = [0x73, 0x65, 0x6e, 0x64, 0x5f, 0x6e, 0x66, 0x74]; // "send_nft"
 
 #[derive(Accounts)]
 pub struct SendToZeta<'info> {
     #[account(mut)]
-    pub payer: Signer<'info>,
-    /// CHECK: Gateway program account to CPI into
+    pub owner: Signer<'info>,
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = owner
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+    #[account(
+        seeds = [&nft_origin.origin_token_id, NftOrigin::SEED],
+        bump = nft_origin.bump,
+        constraint = nft_origin.origin_mint == mint.key() @ ErrorCode::InvalidMint,
+    )]
+    pub nft_origin: Account<'info, NftOrigin>,
+    /// CHECK: mint authority PDA being revoked; derived programmatically
+    pub mint_authority_pda: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [GatewayConfig::SEED],
+        bump = gateway_config.bump,
+    )]
+    pub gateway_config: Account<'info, GatewayConfig>,
+    /// CHECK: gateway program to CPI into; pinned to the configured gateway
+    #[account(address = gateway_config.gateway_program)]
     pub gateway_program: UncheckedAccount<'info>,
+    /// CHECK: gateway PDA receiving the message; pinned to the configured gateway PDA
+    #[account(mut, address = gateway_config.gateway_pda)]
+    pub gateway_pda: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token>,
 }
 
-// Stub to demonstrate constructing payload; actual CPI depends on gateway interface
-pub fn handler(_ctx: Context<SendToZeta>, _payload: CrossChainNftPayload) -> Result<()> {
-    // TODO: replace with actual CPI to ZetaChain Gateway program once interface is available
+pub fn handler(ctx: Context<SendToZeta>, destination_chain_id: u64, recipient: Pubkey) -> Result<()> {
+    require!(!ctx.accounts.gateway_config.is_paused, ErrorCode::ProgramPaused);
+    require!(ctx.accounts.owner_token_account.amount > 0, ErrorCode::NoTokensToSend);
+
+    let (mint_authority_pda, mint_authority_bump) = Pubkey::find_program_address(
+        &[b"mint_authority", ctx.accounts.mint.key().as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(ctx.accounts.mint_authority_pda.key(), mint_authority_pda, ErrorCode::InvalidMintAuthorityPda);
+
+    // Burn the NFT so it cannot be double-spent while bridged.
+    burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.mint.to_account_info(),
+                from: ctx.accounts.owner_token_account.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ),
+        1,
+    )?;
+
+    // Permanently disable minting so the local leg is fully retired while bridged.
+    set_authority(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            SetAuthority {
+                current_authority: ctx.accounts.mint_authority_pda.to_account_info(),
+                account_or_mint: ctx.accounts.mint.to_account_info(),
+            },
+            &[&[b"mint_authority", ctx.accounts.mint.key().as_ref(), &[mint_authority_bump]]],
+        ),
+        AuthorityType::MintTokens,
+        None,
+    )?;
+
+    let gateway_config = &mut ctx.accounts.gateway_config;
+    let nonce = gateway_config.nonce;
+    gateway_config.nonce = nonce.checked_add(1).ok_or(ErrorCode::NonceOverflow)?;
+
+    let payload = CrossChainNftPayload {
+        token_id: ctx.accounts.nft_origin.origin_token_id,
+        origin_chain: ctx.accounts.nft_origin.origin_chain,
+        origin_mint: ctx.accounts.nft_origin.origin_mint,
+        recipient,
+        metadata_uri: ctx.accounts.nft_origin.metadata_uri.clone(),
+        nonce,
+    };
+
+    let mut data = GATEWAY_SEND_DISCRIMINATOR.to_vec();
+    destination_chain_id.serialize(&mut data)?;
+    payload.serialize(&mut data)?;
+
+    let ix = Instruction {
+        program_id: ctx.accounts.gateway_program.key(),
+        accounts: vec![
+            AccountMeta::new(ctx.accounts.gateway_pda.key(), false),
+            AccountMeta::new(ctx.accounts.owner.key(), true),
+        ],
+        data,
+    };
+
+    invoke(
+        &ix,
+        &[
+            ctx.accounts.gateway_pda.to_account_info(),
+            ctx.accounts.owner.to_account_info(),
+        ],
+    )?;
+
+    msg!(
+        "Sent NFT {} to ZetaChain gateway, destination chain {}, nonce {}",
+        hex::encode(&payload.token_id[..8]),
+        destination_chain_id,
+        nonce
+    );
+
     Ok(())
 }
 
-
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Program is paused")]
+    ProgramPaused,
+    #[msg("No tokens to send")]
+    NoTokensToSend,
+    #[msg("Invalid mint")]
+    InvalidMint,
+    #[msg("Invalid mint authority PDA")]
+    InvalidMintAuthorityPda,
+    #[msg("Nonce overflow")]
+    NonceOverflow,
+}
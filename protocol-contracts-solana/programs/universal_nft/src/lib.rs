@@ -1,21 +1,86 @@
 use anchor_lang::prelude::*;
 
+pub mod burn;
+pub mod collection;
 pub mod handle_incoming;
 pub mod mint;
+pub mod mint_edition;
+pub mod mint_from_origin;
+pub mod mint_token2022;
 pub mod on_call;
+pub mod on_revert;
+pub mod register_endpoint;
 pub mod state;
 pub mod utils;
 
 declare_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
 
-pub fn mint_new_nft(ctx: Context<mint::MintNewNft>, metadata_uri: String) -> Result<()> {
-    mint::handler(ctx, metadata_uri)
+/// ZetaChain's chain id for Solana - the `origin_chain` value a Solana-native NFT
+/// carries on `NftOrigin`, distinguishing it from a wrapped (foreign-originated) one.
+pub const SOLANA_CHAIN_ID: u64 = 7565164;
+
+pub fn mint_new_nft(
+    ctx: Context<mint::MintNewNft>,
+    metadata_uri: String,
+    creators: Vec<mint::CreatorArg>,
+    seller_fee_basis_points: u16,
+) -> Result<()> {
+    mint::handler(ctx, metadata_uri, creators, seller_fee_basis_points)
+}
+
+pub fn initialize_collection(
+    ctx: Context<collection::InitializeCollection>,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> Result<()> {
+    collection::initialize_collection(ctx, name, symbol, uri)
+}
+
+pub fn verify_collection_nft(ctx: Context<collection::VerifyCollectionNft>) -> Result<()> {
+    collection::verify_collection_nft(ctx)
 }
 
 pub fn handle_incoming(ctx: Context<handle_incoming::HandleIncoming>, payload: Vec<u8>) -> Result<()> {
     handle_incoming::handler(ctx, payload)
 }
 
+pub fn mint_from_origin(
+    ctx: Context<mint_from_origin::ReceiveFromZeta>,
+    payload: state::nft_origin::CrossChainNftPayload,
+) -> Result<()> {
+    mint_from_origin::handler(ctx, payload)
+}
+
+pub fn mint_edition(ctx: Context<mint_edition::MintEdition>, edition_number: u64) -> Result<()> {
+    mint_edition::handler(ctx, edition_number)
+}
+
+pub fn mint_new_nft_token2022(
+    ctx: Context<mint_token2022::MintNewNftToken2022>,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> Result<()> {
+    mint_token2022::handler(ctx, name, symbol, uri)
+}
+
 pub fn on_call(ctx: Context<on_call::OnCall>, payload: Vec<u8>) -> Result<()> {
     on_call::handler(ctx, payload)
 }
+
+pub fn on_revert(ctx: Context<on_revert::OnRevert>, revert_data: Vec<u8>) -> Result<()> {
+    on_revert::handler(ctx, revert_data)
+}
+
+pub fn burn_for_transfer(ctx: Context<burn::BurnForTransfer>, nonce: u64) -> Result<()> {
+    burn::handler(ctx, nonce)
+}
+
+pub fn register_endpoint(
+    ctx: Context<register_endpoint::RegisterEndpoint>,
+    origin_chain: u64,
+    emitter_address: [u8; 20],
+) -> Result<()> {
+    register_endpoint::handler(ctx, origin_chain, emitter_address)
+}
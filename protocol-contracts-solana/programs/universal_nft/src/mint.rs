@@ -2,11 +2,20 @@ use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token::{Mint, Token, TokenAccount};
 use anchor_lang::prelude::UncheckedAccount;
+use mpl_token_metadata::types::{Collection, Creator};
 use sha2::{Digest, Sha256};
 
 use crate::state::nft_origin::NftOrigin;
 use crate::utils::*;
 
+/// On-chain creator entry supplied by the caller; `share` is a percentage (0-100)
+/// of royalties and all shares in a mint must sum to 100.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CreatorArg {
+    pub address: Pubkey,
+    pub share: u8,
+}
+
 #[derive(Accounts)]
 pub struct MintNewNft<'info> {
     #[account(mut)]
@@ -45,12 +54,25 @@ pub struct MintNewNft<'info> {
     /// CHECK: token metadata program (Metaplex)
     #[account(address = mpl_token_metadata::ID)]
     pub token_metadata_program: UncheckedAccount<'info>,
+    /// Collection this NFT should join, if any. Membership is stamped unverified
+    /// here; call `verify_collection_nft` afterwards to flip it to `verified: true`.
+    pub collection_mint: Option<Account<'info, Mint>>,
 }
 
-pub fn handler(ctx: Context<MintNewNft>, metadata_uri: String) -> Result<()> {
+pub fn handler(
+    ctx: Context<MintNewNft>,
+    metadata_uri: String,
+    creators: Vec<CreatorArg>,
+    seller_fee_basis_points: u16,
+) -> Result<()> {
     let clock = Clock::get()?;
 
     require!(metadata_uri.len() <= NftOrigin::MAX_URI_LEN, ErrorCode::MetadataTooLong);
+    require!(seller_fee_basis_points <= 10000, ErrorCode::InvalidSellerFeeBasisPoints);
+    require!(
+        creators.is_empty() || creators.iter().map(|c| c.share as u16).sum::<u16>() == 100,
+        ErrorCode::InvalidCreatorShares
+    );
 
     // Generate unique token ID: hash of mint pubkey + slot + timestamp
     let mut hasher = Sha256::new();
@@ -88,6 +110,28 @@ pub fn handler(ctx: Context<MintNewNft>, metadata_uri: String) -> Result<()> {
         1,
     )?;
 
+    // Mark the mint authority PDA as verified since it signs this CPI; any other
+    // listed creator is left unverified until they co-sign off-chain.
+    let on_chain_creators = if creators.is_empty() {
+        None
+    } else {
+        Some(
+            creators
+                .iter()
+                .map(|c| Creator {
+                    address: c.address,
+                    verified: c.address == ctx.accounts.mint_authority_pda.key(),
+                    share: c.share,
+                })
+                .collect::<Vec<_>>(),
+        )
+    };
+
+    let collection = ctx.accounts.collection_mint.as_ref().map(|m| Collection {
+        key: m.key(),
+        verified: false,
+    });
+
     // Create Metaplex metadata and master edition
     cpi_create_metadata_v3(
         &ctx.accounts.payer.to_account_info(),
@@ -101,6 +145,9 @@ pub fn handler(ctx: Context<MintNewNft>, metadata_uri: String) -> Result<()> {
         "UniversalNFT".to_string(),
         "UNFT".to_string(),
         metadata_uri.clone(),
+        on_chain_creators,
+        seller_fee_basis_points,
+        collection,
     )?;
 
     cpi_create_master_edition_v3(
@@ -141,12 +188,17 @@ pub fn handler(ctx: Context<MintNewNft>, metadata_uri: String) -> Result<()> {
     }
     
     let nft_origin = NftOrigin {
-        origin_chain: 0u64, // Solana
+        // `SOLANA_CHAIN_ID`, not a placeholder `0` - `BurnForTransfer`/`HandleIncoming`
+        // key their custody-lock/release branch off this exact value to tell a
+        // Solana-native NFT apart from a bridged-in one.
+        origin_chain: crate::SOLANA_CHAIN_ID,
         origin_token_id: token_id,
         origin_mint: ctx.accounts.mint.key(),
         metadata_uri,
         created_at: clock.unix_timestamp,
         bump: nft_origin_bump,
+        // Minted directly on Solana, not via a cross-chain message - no origin sender.
+        origin_sender: [0u8; 20],
     };
 
     // Write discriminator + data
@@ -174,4 +226,8 @@ pub enum ErrorCode {
     NftOriginPdaMismatch,
     #[msg("Invalid Mint Authority PDA")]
     InvalidMintAuthorityPda,
+    #[msg("Creator shares must sum to 100")]
+    InvalidCreatorShares,
+    #[msg("Seller fee basis points must not exceed 10000")]
+    InvalidSellerFeeBasisPoints,
 }
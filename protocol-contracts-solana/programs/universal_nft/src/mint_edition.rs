@@ -0,0 +1,215 @@
+use anchor_lang::prelude::*;
+use anchor_lang::prelude::UncheckedAccount;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use mpl_token_metadata::instructions::{
+    MintNewEditionFromMasterEditionViaToken, MintNewEditionFromMasterEditionViaTokenInstructionArgs,
+};
+use mpl_token_metadata::types::MintNewEditionFromMasterEditionViaTokenArgs;
+use sha2::{Digest, Sha256};
+
+use crate::state::nft_origin::NftOrigin;
+use crate::utils::{derive_master_edition_pda, derive_metadata_pda, derive_nft_origin_pda};
+
+/// Mints a numbered print from a master edition created by `mint::handler`, CPI-ing
+/// Metaplex's `mint_new_edition_from_master_edition_via_token`. The edition marker
+/// PDA (derived by the Token Metadata program) rejects a repeat of `edition_number`,
+/// so double-prints fail at the CPI rather than needing a local check here.
+#[derive(Accounts)]
+pub struct MintEdition<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub recipient: SystemAccount<'info>,
+    pub master_mint: Account<'info, Mint>,
+    #[account(
+        associated_token::mint = master_mint,
+        associated_token::authority = master_token_owner
+    )]
+    pub master_token_account: Account<'info, TokenAccount>,
+    pub master_token_owner: SystemAccount<'info>,
+    #[account(
+        seeds = [&master_nft_origin.origin_token_id, b"nft_origin"],
+        bump = master_nft_origin.bump,
+        constraint = master_nft_origin.origin_mint == master_mint.key() @ ErrorCode::InvalidMasterOrigin,
+    )]
+    pub master_nft_origin: Account<'info, NftOrigin>,
+    /// CHECK: Metaplex master metadata PDA; validated by the Token Metadata CPI
+    #[account(mut)]
+    pub master_metadata: UncheckedAccount<'info>,
+    /// CHECK: Metaplex master edition PDA; validated by the Token Metadata CPI
+    pub master_edition: UncheckedAccount<'info>,
+    /// CHECK: Metaplex edition marker PDA; created by the Token Metadata CPI and
+    /// rejects a repeat mint of the same edition_number
+    #[account(mut)]
+    pub edition_marker: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = payer,
+        mint::decimals = 0,
+        mint::authority = mint_authority_pda,
+        mint::freeze_authority = mint_authority_pda,
+    )]
+    pub edition_mint: Account<'info, Mint>,
+    /// CHECK: Metaplex metadata PDA for the new print; created via CPI
+    #[account(mut)]
+    pub edition_metadata: UncheckedAccount<'info>,
+    /// CHECK: Metaplex edition PDA for the new print; created via CPI
+    #[account(mut)]
+    pub edition_edition: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = edition_mint,
+        associated_token::authority = recipient
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+    /// CHECK: nft_origin PDA for the print, derived from parent token_id + edition number
+    #[account(mut)]
+    pub edition_nft_origin: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    /// CHECK: mint_authority PDA for the new print mint; derived programmatically
+    pub mint_authority_pda: UncheckedAccount<'info>,
+    /// CHECK: token metadata program (Metaplex)
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<MintEdition>, edition_number: u64) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let (mint_authority_pda, mint_authority_bump) = Pubkey::find_program_address(
+        &[b"mint_authority", ctx.accounts.edition_mint.key().as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(ctx.accounts.mint_authority_pda.key(), mint_authority_pda, ErrorCode::InvalidMintAuthorityPda);
+    let mint_authority_seeds: &[&[u8]] =
+        &[b"mint_authority", ctx.accounts.edition_mint.key().as_ref(), &[mint_authority_bump]];
+
+    let (expected_master_metadata, _) = derive_metadata_pda(&ctx.accounts.master_mint.key());
+    let (expected_master_edition, _) = derive_master_edition_pda(&ctx.accounts.master_mint.key());
+    require_keys_eq!(ctx.accounts.master_metadata.key(), expected_master_metadata, ErrorCode::InvalidMetadataPda);
+    require_keys_eq!(ctx.accounts.master_edition.key(), expected_master_edition, ErrorCode::InvalidMasterEditionPda);
+
+    anchor_spl::token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token::MintTo {
+                mint: ctx.accounts.edition_mint.to_account_info(),
+                to: ctx.accounts.recipient_token_account.to_account_info(),
+                authority: ctx.accounts.mint_authority_pda.to_account_info(),
+            },
+            &[mint_authority_seeds],
+        ),
+        1,
+    )?;
+
+    let ix = MintNewEditionFromMasterEditionViaToken {
+        new_metadata: ctx.accounts.edition_metadata.key(),
+        new_edition: ctx.accounts.edition_edition.key(),
+        master_edition: ctx.accounts.master_edition.key(),
+        new_mint: ctx.accounts.edition_mint.key(),
+        edition_mark_pda: ctx.accounts.edition_marker.key(),
+        new_mint_authority: ctx.accounts.mint_authority_pda.key(),
+        payer: ctx.accounts.payer.key(),
+        token_account_owner: ctx.accounts.master_token_owner.key(),
+        token_account: ctx.accounts.master_token_account.key(),
+        new_metadata_update_authority: ctx.accounts.mint_authority_pda.key(),
+        metadata: ctx.accounts.master_metadata.key(),
+        token_program: spl_token::ID,
+        system_program: anchor_lang::solana_program::system_program::ID,
+        rent: Some(*ctx.accounts.rent.key),
+    }
+    .instruction(MintNewEditionFromMasterEditionViaTokenInstructionArgs {
+        mint_new_edition_from_master_edition_via_token_args: MintNewEditionFromMasterEditionViaTokenArgs {
+            edition: edition_number,
+        },
+    });
+
+    invoke_signed(
+        &ix,
+        &[
+            ctx.accounts.edition_metadata.to_account_info(),
+            ctx.accounts.edition_edition.to_account_info(),
+            ctx.accounts.master_edition.to_account_info(),
+            ctx.accounts.edition_mint.to_account_info(),
+            ctx.accounts.edition_marker.to_account_info(),
+            ctx.accounts.mint_authority_pda.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.master_token_account.to_account_info(),
+            ctx.accounts.master_metadata.to_account_info(),
+            ctx.accounts.token_metadata_program.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.rent.to_account_info(),
+        ],
+        &[mint_authority_seeds],
+    )?;
+
+    // Derive the print's own token_id from the parent token_id plus edition number
+    // so every print is individually bridgeable under a stable, unique identity.
+    let mut hasher = Sha256::new();
+    hasher.update(ctx.accounts.master_nft_origin.origin_token_id);
+    hasher.update(edition_number.to_le_bytes());
+    let hash = hasher.finalize();
+    let mut token_id = [0u8; 32];
+    token_id.copy_from_slice(&hash[..32]);
+
+    let (edition_nft_origin_pda, edition_nft_origin_bump) = derive_nft_origin_pda(&token_id);
+    require_keys_eq!(ctx.accounts.edition_nft_origin.key(), edition_nft_origin_pda, ErrorCode::NftOriginPdaMismatch);
+
+    let space = 8 + NftOrigin::LEN;
+    let lamports = Rent::get()?.minimum_balance(space);
+    invoke_signed(
+        &anchor_lang::solana_program::system_instruction::create_account(
+            &ctx.accounts.payer.key(),
+            &edition_nft_origin_pda,
+            lamports,
+            space as u64,
+            &crate::ID,
+        ),
+        &[
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.edition_nft_origin.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+        &[&[&token_id, b"nft_origin", &[edition_nft_origin_bump]]],
+    )?;
+
+    let nft_origin = NftOrigin {
+        origin_chain: ctx.accounts.master_nft_origin.origin_chain,
+        origin_token_id: token_id,
+        origin_mint: ctx.accounts.edition_mint.key(),
+        metadata_uri: ctx.accounts.master_nft_origin.metadata_uri.clone(),
+        created_at: clock.unix_timestamp,
+        bump: edition_nft_origin_bump,
+        // Printed directly on Solana from the master edition - no origin sender.
+        origin_sender: [0u8; 20],
+    };
+
+    use anchor_lang::Discriminator;
+    let mut data = ctx.accounts.edition_nft_origin.try_borrow_mut_data()?;
+    data[..8].copy_from_slice(&NftOrigin::discriminator());
+    nft_origin.try_serialize(&mut &mut data[8..])?;
+
+    msg!("Minted print edition {} of master {}", edition_number, ctx.accounts.master_mint.key());
+    msg!("Print NFT Origin PDA: {}", edition_nft_origin_pda);
+
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Invalid Metadata PDA")]
+    InvalidMetadataPda,
+    #[msg("Invalid Master Edition PDA")]
+    InvalidMasterEditionPda,
+    #[msg("Invalid Mint Authority PDA")]
+    InvalidMintAuthorityPda,
+    #[msg("Invalid NftOrigin PDA")]
+    NftOriginPdaMismatch,
+    #[msg("Master mint does not match its origin record")]
+    InvalidMasterOrigin,
+}
@@ -0,0 +1,185 @@
+use anchor_lang::prelude::*;
+use anchor_lang::prelude::UncheckedAccount;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::state::gateway::GatewayConfig;
+use crate::state::nft_origin::{CrossChainNftPayload, NftOrigin};
+use crate::utils::*;
+
+/// Mints (or re-mints) an NFT arriving from another chain, callable only by the
+/// configured ZetaChain gateway PDA. Unlike `mint::handler`, the `token_id` is taken
+/// verbatim from the inbound payload rather than freshly hashed, so an NFT keeps a
+/// stable global identity across every chain it visits.
+#[derive(Accounts)]
+pub struct ReceiveFromZeta<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub recipient: SystemAccount<'info>,
+    #[account(
+        seeds = [GatewayConfig::SEED],
+        bump = gateway_config.bump,
+    )]
+    pub gateway_config: Account<'info, GatewayConfig>,
+    /// Only the gateway PDA recorded in `gateway_config` may authorize an inbound mint.
+    #[account(address = gateway_config.gateway_pda)]
+    pub gateway_pda: Signer<'info>,
+    #[account(
+        init,
+        payer = payer,
+        mint::decimals = 0,
+        mint::authority = mint_authority_pda,
+        mint::freeze_authority = mint_authority_pda,
+    )]
+    pub mint: Account<'info, Mint>,
+    /// CHECK: Metaplex metadata PDA for this mint; created via CPI
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+    /// CHECK: Metaplex master edition PDA for this mint; created via CPI
+    #[account(mut)]
+    pub master_edition: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = recipient
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+    /// CHECK: nft_origin PDA derived from the payload's origin_token_id; created if
+    /// absent, left untouched if this token_id already has an origin record
+    #[account(mut)]
+    pub nft_origin: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    /// CHECK: mint_authority PDA; derived programmatically
+    pub mint_authority_pda: UncheckedAccount<'info>,
+    /// CHECK: token metadata program (Metaplex)
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<ReceiveFromZeta>, payload: CrossChainNftPayload) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(payload.metadata_uri.len() <= NftOrigin::MAX_URI_LEN, ErrorCode::MetadataTooLong);
+
+    let (mint_authority_pda, mint_authority_bump) = Pubkey::find_program_address(
+        &[b"mint_authority", ctx.accounts.mint.key().as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(ctx.accounts.mint_authority_pda.key(), mint_authority_pda, ErrorCode::InvalidMintAuthorityPda);
+
+    let (expected_metadata_pda, _) = derive_metadata_pda(&ctx.accounts.mint.key());
+    let (expected_master_edition_pda, _) = derive_master_edition_pda(&ctx.accounts.mint.key());
+    require_keys_eq!(ctx.accounts.metadata.key(), expected_metadata_pda, ErrorCode::InvalidMetadataPda);
+    require_keys_eq!(ctx.accounts.master_edition.key(), expected_master_edition_pda, ErrorCode::InvalidMasterEditionPda);
+
+    anchor_spl::token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token::MintTo {
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.recipient_token_account.to_account_info(),
+                authority: ctx.accounts.mint_authority_pda.to_account_info(),
+            },
+            &[&[b"mint_authority", ctx.accounts.mint.key().as_ref(), &[mint_authority_bump]]],
+        ),
+        1,
+    )?;
+
+    cpi_create_metadata_v3(
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.mint_authority_pda.to_account_info(),
+        &ctx.accounts.mint_authority_pda.to_account_info(),
+        &ctx.accounts.metadata.to_account_info(),
+        &ctx.accounts.token_metadata_program.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.rent.to_account_info(),
+        "UniversalNFT".to_string(),
+        "UNFT".to_string(),
+        payload.metadata_uri.clone(),
+        None,
+        0,
+        None,
+    )?;
+
+    cpi_create_master_edition_v3(
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.mint_authority_pda.to_account_info(),
+        &ctx.accounts.mint_authority_pda.to_account_info(),
+        &ctx.accounts.metadata.to_account_info(),
+        &ctx.accounts.master_edition.to_account_info(),
+        &ctx.accounts.token_metadata_program.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.rent.to_account_info(),
+    )?;
+
+    let (nft_origin_pda, nft_origin_bump) = derive_nft_origin_pda(&payload.token_id);
+    require_keys_eq!(ctx.accounts.nft_origin.key(), nft_origin_pda, ErrorCode::NftOriginPdaMismatch);
+
+    if ctx.accounts.nft_origin.data_is_empty() {
+        // First time this token_id has been seen on Solana: record its true origin.
+        let space = 8 + NftOrigin::LEN;
+        let lamports = Rent::get()?.minimum_balance(space);
+        anchor_lang::solana_program::program::invoke_signed(
+            &anchor_lang::solana_program::system_instruction::create_account(
+                &ctx.accounts.payer.key(),
+                &nft_origin_pda,
+                lamports,
+                space as u64,
+                &crate::ID,
+            ),
+            &[
+                ctx.accounts.payer.to_account_info(),
+                ctx.accounts.nft_origin.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+            &[&[&payload.token_id, b"nft_origin", &[nft_origin_bump]]],
+        )?;
+
+        let nft_origin = NftOrigin {
+            origin_chain: payload.origin_chain,
+            origin_token_id: payload.token_id,
+            origin_mint: ctx.accounts.mint.key(),
+            metadata_uri: payload.metadata_uri.clone(),
+            created_at: clock.unix_timestamp,
+            bump: nft_origin_bump,
+            origin_sender: payload.origin_sender,
+        };
+
+        use anchor_lang::Discriminator;
+        let mut data = ctx.accounts.nft_origin.try_borrow_mut_data()?;
+        data[..8].copy_from_slice(&NftOrigin::discriminator());
+        nft_origin.try_serialize(&mut &mut data[8..])?;
+    } else {
+        // This token_id already has an origin record (it was minted on Solana
+        // previously and is now coming home) - leave that record untouched, it
+        // still describes where the NFT is really from.
+        msg!("NFT {} returning home; preserving existing origin record", hex::encode(&payload.token_id[..8]));
+    }
+
+    msg!("Received Universal NFT with token ID: {}", hex::encode(&payload.token_id[..8]));
+    msg!("Metadata URI: {}", payload.metadata_uri);
+    msg!("NFT Origin PDA: {}", nft_origin_pda);
+
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Metadata URI too long")]
+    MetadataTooLong,
+    #[msg("Invalid Metadata PDA")]
+    InvalidMetadataPda,
+    #[msg("Invalid Master Edition PDA")]
+    InvalidMasterEditionPda,
+    #[msg("Invalid NftOrigin PDA")]
+    NftOriginPdaMismatch,
+    #[msg("Invalid Mint Authority PDA")]
+    InvalidMintAuthorityPda,
+}
@@ -0,0 +1,210 @@
+use anchor_lang::prelude::*;
+use anchor_lang::prelude::UncheckedAccount;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::system_instruction;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_2022::Token2022,
+    token_interface::{
+        initialize_mint2, metadata_pointer_initialize, mint_to, token_metadata_initialize,
+        InitializeMint2, MetadataPointerInitialize, MintTo as MintTo2022,
+        TokenAccount as TokenAccount2022, TokenMetadataInitialize,
+    },
+};
+use spl_token_2022::extension::ExtensionType;
+use sha2::{Digest, Sha256};
+
+use crate::state::nft_origin::NftOrigin;
+use crate::utils::derive_nft_origin_pda;
+
+/// Self-contained alternative to `mint::handler` for integrators who want metadata
+/// co-located with the mint account instead of separate Metaplex metadata/master
+/// edition PDAs. Uses Token-2022's `MetadataPointer` + `TokenMetadata` extensions so
+/// the cross-chain payload only ever needs to reference a single account.
+#[derive(Accounts)]
+pub struct MintNewNftToken2022<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub recipient: SystemAccount<'info>,
+    /// CHECK: Token-2022 mint account; created manually below once its extension
+    /// space is known, since `mint::` account constraints don't size extensions.
+    #[account(mut)]
+    pub mint: Signer<'info>,
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program,
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount2022>,
+    /// CHECK: nft_origin PDA; created programmatically with seeds
+    #[account(mut)]
+    pub nft_origin: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    /// CHECK: mint_authority PDA; derived programmatically
+    pub mint_authority_pda: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<MintNewNftToken2022>,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(uri.len() <= NftOrigin::MAX_URI_LEN, ErrorCode::MetadataTooLong);
+
+    let mut hasher = Sha256::new();
+    hasher.update(ctx.accounts.mint.key().as_ref());
+    hasher.update(&clock.slot.to_le_bytes());
+    hasher.update(&clock.unix_timestamp.to_le_bytes());
+    let token_id_hash = hasher.finalize();
+    let mut token_id: [u8; 32] = [0u8; 32];
+    token_id.copy_from_slice(&token_id_hash[..32]);
+
+    let (mint_authority_pda, mint_authority_bump) = Pubkey::find_program_address(
+        &[b"mint_authority", ctx.accounts.mint.key().as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(ctx.accounts.mint_authority_pda.key(), mint_authority_pda, ErrorCode::InvalidMintAuthorityPda);
+    let mint_authority_seeds: &[&[u8]] =
+        &[b"mint_authority", ctx.accounts.mint.key().as_ref(), &[mint_authority_bump]];
+
+    // Only the fixed-size MetadataPointer extension needs to be sized up front;
+    // the variable-length TokenMetadata content is appended (and its rent funded)
+    // by `token_metadata_initialize` itself.
+    let mint_len = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(
+        &[ExtensionType::MetadataPointer],
+    )?;
+    let lamports = Rent::get()?.minimum_balance(mint_len);
+
+    invoke_signed(
+        &system_instruction::create_account(
+            &ctx.accounts.payer.key(),
+            &ctx.accounts.mint.key(),
+            lamports,
+            mint_len as u64,
+            &ctx.accounts.token_program.key(),
+        ),
+        &[
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+        ],
+        &[],
+    )?;
+
+    // Metadata lives in the mint account itself, so point the extension at `mint`.
+    metadata_pointer_initialize(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            MetadataPointerInitialize {
+                token_program_id: ctx.accounts.token_program.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+        ),
+        Some(mint_authority_pda),
+        Some(ctx.accounts.mint.key()),
+    )?;
+
+    initialize_mint2(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            InitializeMint2 {
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+        ),
+        0,
+        &mint_authority_pda,
+        Some(&mint_authority_pda),
+    )?;
+
+    token_metadata_initialize(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TokenMetadataInitialize {
+                token_program_id: ctx.accounts.token_program.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                metadata: ctx.accounts.mint.to_account_info(),
+                mint_authority: ctx.accounts.mint_authority_pda.to_account_info(),
+                update_authority: ctx.accounts.mint_authority_pda.to_account_info(),
+            },
+            &[mint_authority_seeds],
+        ),
+        name,
+        symbol,
+        uri.clone(),
+    )?;
+
+    mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo2022 {
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.recipient_token_account.to_account_info(),
+                authority: ctx.accounts.mint_authority_pda.to_account_info(),
+            },
+            &[mint_authority_seeds],
+        ),
+        1,
+    )?;
+
+    let (nft_origin_pda, nft_origin_bump) = derive_nft_origin_pda(&token_id);
+    require_keys_eq!(ctx.accounts.nft_origin.key(), nft_origin_pda, ErrorCode::NftOriginPdaMismatch);
+
+    let space = 8 + NftOrigin::LEN;
+    let origin_lamports = Rent::get()?.minimum_balance(space);
+    invoke_signed(
+        &system_instruction::create_account(
+            &ctx.accounts.payer.key(),
+            &nft_origin_pda,
+            origin_lamports,
+            space as u64,
+            &crate::ID,
+        ),
+        &[
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.nft_origin.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+        &[&[&token_id, b"nft_origin", &[nft_origin_bump]]],
+    )?;
+
+    let nft_origin = NftOrigin {
+        // `SOLANA_CHAIN_ID`, not a placeholder `0` - `BurnForTransfer`/`HandleIncoming`
+        // key their custody-lock/release branch off this exact value to tell a
+        // Solana-native NFT apart from a bridged-in one.
+        origin_chain: crate::SOLANA_CHAIN_ID,
+        origin_token_id: token_id,
+        origin_mint: ctx.accounts.mint.key(),
+        metadata_uri: uri,
+        created_at: clock.unix_timestamp,
+        bump: nft_origin_bump,
+        // Minted directly on Solana, not via a cross-chain message - no origin sender.
+        origin_sender: [0u8; 20],
+    };
+
+    use anchor_lang::Discriminator;
+    let mut data = ctx.accounts.nft_origin.try_borrow_mut_data()?;
+    data[..8].copy_from_slice(&NftOrigin::discriminator());
+    nft_origin.try_serialize(&mut &mut data[8..])?;
+
+    msg!("Minted Token-2022 Universal NFT with token ID: {}", hex::encode(&token_id[..8]));
+    msg!("NFT Origin PDA: {}", nft_origin_pda);
+
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Metadata URI too long")]
+    MetadataTooLong,
+    #[msg("Invalid Mint Authority PDA")]
+    InvalidMintAuthorityPda,
+    #[msg("Invalid NftOrigin PDA")]
+    NftOriginPdaMismatch,
+}
@@ -1,16 +1,28 @@
 use anchor_lang::prelude::*;
 use anchor_lang::Discriminator;
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token::{Mint, Token, TokenAccount};
+use anchor_spl::metadata::{verify_collection, VerifyCollection};
+use anchor_spl::token::{Token, TokenAccount};
+use mpl_token_metadata::types::{Collection, Creator};
+
+use crate::state::attributes::{assert_attributes_valid, NftAttributeSet};
+use crate::state::collection::CollectionConfig;
+use crate::state::custody::CustodyRecord;
+use crate::state::endpoint::RegisteredEmitter;
 use crate::state::gateway::GatewayConfig;
-use crate::state::nft_origin::{NftOrigin, CrossChainNftPayload};
-use crate::state::replay::ReplayMarker;
+use crate::state::nft_origin::{NftOrigin, CrossChainNftPayload, MAX_APP_PAYLOAD_LEN};
+use crate::state::replay::ReplayWindow;
 use crate::utils::{
     derive_nft_origin_pda,
-    derive_replay_marker_pda,
+    derive_nft_attributes_pda,
+    derive_replay_window_pda,
+    derive_custody_record_pda,
+    derive_custody_authority_pda,
+    derive_endpoint_pda,
     cpi_create_metadata_v3,
     cpi_create_master_edition_v3,
 };
+use crate::SOLANA_CHAIN_ID;
 
 #[derive(Accounts)]
 pub struct OnCall<'info> {
@@ -18,22 +30,19 @@ pub struct OnCall<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     pub recipient: SystemAccount<'info>,
-    #[account(
-        init,
-        payer = payer,
-        mint::decimals = 0,
-        mint::authority = mint_authority_pda,
-        mint::freeze_authority = mint_authority_pda,
-    )]
-    pub mint: Account<'info, Mint>,
-    /// CHECK: Metaplex metadata PDA for this mint; created via CPI
+    /// CHECK: a wrapped NFT gets a brand-new mint created here; a Solana-native NFT
+    /// being released from custody already has this mint and it's only read
+    #[account(mut)]
+    pub mint: UncheckedAccount<'info>,
+    /// CHECK: Metaplex metadata PDA for this mint; only created for a wrapped NFT -
+    /// a native NFT's metadata was never touched by `BurnForTransfer`
     #[account(mut)]
     pub metadata: UncheckedAccount<'info>,
-    /// CHECK: Metaplex master edition PDA for this mint; created via CPI
+    /// CHECK: Metaplex master edition PDA for this mint; only created for a wrapped NFT
     #[account(mut)]
     pub master_edition: UncheckedAccount<'info>,
     #[account(
-        init,
+        init_if_needed,
         payer = payer,
         associated_token::mint = mint,
         associated_token::authority = recipient
@@ -42,14 +51,30 @@ pub struct OnCall<'info> {
     /// CHECK: nft_origin PDA; will be created with seeds [token_id, "nft_origin"]
     #[account(mut)]
     pub nft_origin: UncheckedAccount<'info>,
+    /// CHECK: `NftAttributeSet` PDA; only created when `payload.attributes` is non-empty,
+    /// since Metaplex's `DataV2` has no field to carry them through the CPI above
+    #[account(mut)]
+    pub nft_attributes: UncheckedAccount<'info>,
     /// CHECK: PDA with gateway program id
     pub gateway_config: UncheckedAccount<'info>,
+    /// CHECK: `RegisteredEmitter` for the payload's `origin_chain`; its stored
+    /// `emitter_address` must match the payload's `origin_sender` before anything mints
+    pub endpoint: UncheckedAccount<'info>,
     /// CHECK: Sysvar instructions for CPI caller verification
     #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
     pub instructions: UncheckedAccount<'info>,
-    /// CHECK: replay marker account
+    /// CHECK: per-token replay window PDA; created on this token_id's first `OnCall`
+    #[account(mut)]
+    pub replay_window: UncheckedAccount<'info>,
+    /// CHECK: custody record PDA written by `BurnForTransfer`; only read/cleared when
+    /// releasing a Solana-native NFT
+    #[account(mut)]
+    pub custody_record: UncheckedAccount<'info>,
+    /// CHECK: single program-wide authority that signs for every custody ATA
+    pub custody_authority: UncheckedAccount<'info>,
+    /// CHECK: escrow ATA the native NFT was locked into; only used when releasing
     #[account(mut)]
-    pub replay_marker: UncheckedAccount<'info>,
+    pub custody_token_account: UncheckedAccount<'info>,
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
@@ -58,6 +83,19 @@ pub struct OnCall<'info> {
     pub mint_authority_pda: UncheckedAccount<'info>,
     /// CHECK: token metadata program
     pub token_metadata_program: UncheckedAccount<'info>,
+    /// CHECK: collection parent's authority PDA; only read when `payload.collection_mint`
+    /// is `Some` and validated against `collection_config` before signing
+    pub collection_authority_pda: Option<UncheckedAccount<'info>>,
+    /// CHECK: `CollectionConfig` PDA for `payload.collection_mint`; its own seeds are
+    /// checked against the payload-supplied mint before use
+    pub collection_config: Option<UncheckedAccount<'info>>,
+    /// CHECK: the collection parent mint; checked against `payload.collection_mint`
+    pub collection_mint: Option<UncheckedAccount<'info>>,
+    /// CHECK: Metaplex metadata PDA of the collection parent
+    #[account(mut)]
+    pub collection_metadata: Option<UncheckedAccount<'info>>,
+    /// CHECK: Metaplex master edition PDA of the collection parent
+    pub collection_master_edition: Option<UncheckedAccount<'info>>,
 }
 
 // A generic entrypoint to be invoked by ZetaChain Gateway
@@ -85,6 +123,18 @@ pub fn handler(ctx: Context<OnCall>, payload: Vec<u8>) -> Result<()> {
     let p: CrossChainNftPayload = CrossChainNftPayload::try_from_slice(&payload)
         .map_err(|_| ErrorCode::InvalidPayload)?;
 
+    // Only a registered counterpart contract may author a payload for its chain -
+    // without this, anyone routing a payload through the (correctly pinned) gateway
+    // could claim any `origin_chain` they like.
+    let (endpoint_pda, _) = derive_endpoint_pda(p.origin_chain);
+    require_keys_eq!(ctx.accounts.endpoint.key(), endpoint_pda, ErrorCode::UnregisteredEmitter);
+    require!(!ctx.accounts.endpoint.data_is_empty(), ErrorCode::UnregisteredEmitter);
+    let endpoint_data = ctx.accounts.endpoint.try_borrow_data()?;
+    let endpoint = RegisteredEmitter::try_deserialize(&mut &endpoint_data[..])
+        .map_err(|_| ErrorCode::UnregisteredEmitter)?;
+    drop(endpoint_data);
+    require!(endpoint.emitter_address == p.origin_sender, ErrorCode::UnregisteredEmitter);
+
     // Derive mint authority PDA
     let (mint_authority_pda, mint_authority_bump) = Pubkey::find_program_address(
         &[b"mint_authority", ctx.accounts.mint.key().as_ref()],
@@ -92,87 +142,246 @@ pub fn handler(ctx: Context<OnCall>, payload: Vec<u8>) -> Result<()> {
     );
     require_keys_eq!(ctx.accounts.mint_authority_pda.key(), mint_authority_pda, ErrorCode::InvalidMintAuthorityPda);
 
-    // Replay protection: derive and ensure empty
-    let (replay_pda, bump) = derive_replay_marker_pda(&p.token_id, p.nonce);
-    require_keys_eq!(ctx.accounts.replay_marker.key(), replay_pda, ErrorCode::ReplayPdaMismatch);
-    if !ctx.accounts.replay_marker.data_is_empty() {
-        return Err(ErrorCode::ReplayAttack.into());
-    }
-            let space = 8 + ReplayMarker::LEN; // add discriminator
-    let lamports = Rent::get()?.minimum_balance(space);
-    anchor_lang::solana_program::program::invoke_signed(
-        &anchor_lang::solana_program::system_instruction::create_account(
-            &ctx.accounts.payer.key(),
-            &replay_pda,
-            lamports,
-            space as u64,
-            &crate::ID,
-        ),
-        &[
-            ctx.accounts.payer.to_account_info(),
-            ctx.accounts.replay_marker.to_account_info(),
-            ctx.accounts.system_program.to_account_info(),
-        ],
-                    &[&[b"replay", &p.token_id, &p.nonce.to_le_bytes(), &[bump]]],
-    )?;
-
-    // Write replay marker with discriminator
-    let marker = ReplayMarker {
-        token_id: p.token_id,
-        nonce: p.nonce,
-        created_at: clock.unix_timestamp,
-        bump,
+    // Replay protection: one `ReplayWindow` account per token_id rather than one new
+    // account per `(token_id, nonce)`. See `ReplayWindow::check_and_set`.
+    let (replay_window_pda, replay_window_bump) = derive_replay_window_pda(&p.token_id);
+    require_keys_eq!(ctx.accounts.replay_window.key(), replay_window_pda, ErrorCode::ReplayPdaMismatch);
+
+    let mut window = if ctx.accounts.replay_window.data_is_empty() {
+        let space = ReplayWindow::LEN;
+        let lamports = Rent::get()?.minimum_balance(space);
+        anchor_lang::solana_program::program::invoke_signed(
+            &anchor_lang::solana_program::system_instruction::create_account(
+                &ctx.accounts.payer.key(),
+                &replay_window_pda,
+                lamports,
+                space as u64,
+                &crate::ID,
+            ),
+            &[
+                ctx.accounts.payer.to_account_info(),
+                ctx.accounts.replay_window.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+            &[&[ReplayWindow::SEED, &p.token_id, &[replay_window_bump]]],
+        )?;
+        ReplayWindow {
+            token_id: p.token_id,
+            highest_nonce: 0,
+            bitmap: [0u8; ReplayWindow::BITMAP_BYTES],
+            bump: replay_window_bump,
+        }
+    } else {
+        let data = ctx.accounts.replay_window.try_borrow_data()?;
+        ReplayWindow::try_deserialize(&mut &data[..]).map_err(|_| ErrorCode::ReplayPdaMismatch)?
     };
-    let mut data = ctx.accounts.replay_marker.try_borrow_mut_data()?;
-    data[..8].copy_from_slice(&ReplayMarker::discriminator());
-    marker.try_serialize(&mut &mut data[8..])?;
-
-    // Mint 1 token to recipient
-    anchor_spl::token::mint_to(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            anchor_spl::token::MintTo {
-                mint: ctx.accounts.mint.to_account_info(),
-                to: ctx.accounts.recipient_token_account.to_account_info(),
-                authority: ctx.accounts.mint_authority_pda.to_account_info(),
-            },
-            &[&[b"mint_authority", ctx.accounts.mint.key().as_ref(), &[mint_authority_bump]]],
-        ),
-        1,
-    )?;
-
-    // Create Metaplex metadata and master edition
-    // Validate MPL PDAs first
-    use crate::utils::{derive_metadata_pda, derive_master_edition_pda};
-    let (expected_md, _) = derive_metadata_pda(&ctx.accounts.mint.key());
-    require_keys_eq!(ctx.accounts.metadata.key(), expected_md, ErrorCode::InvalidMetadataPda);
-    let (expected_me, _) = derive_master_edition_pda(&ctx.accounts.mint.key());
-    require_keys_eq!(ctx.accounts.master_edition.key(), expected_me, ErrorCode::InvalidMasterEditionPda);
-    cpi_create_metadata_v3(
-        &ctx.accounts.payer.to_account_info(),
-        &ctx.accounts.mint.to_account_info(),
-        &ctx.accounts.mint_authority_pda.to_account_info(),
-        &ctx.accounts.mint_authority_pda.to_account_info(),
-        &ctx.accounts.metadata.to_account_info(),
-        &ctx.accounts.token_metadata_program.to_account_info(),
-        &ctx.accounts.system_program.to_account_info(),
-        &ctx.accounts.rent.to_account_info(),
-        "UniversalNFT".to_string(),
-        "UNFT".to_string(),
-        p.metadata_uri.clone(),
-    )?;
-
-    cpi_create_master_edition_v3(
-        &ctx.accounts.payer.to_account_info(),
-        &ctx.accounts.mint.to_account_info(),
-        &ctx.accounts.mint_authority_pda.to_account_info(),
-        &ctx.accounts.mint_authority_pda.to_account_info(),
-        &ctx.accounts.metadata.to_account_info(),
-        &ctx.accounts.master_edition.to_account_info(),
-        &ctx.accounts.token_metadata_program.to_account_info(),
-        &ctx.accounts.system_program.to_account_info(),
-        &ctx.accounts.rent.to_account_info(),
-    )?;
+
+    window.check_and_set(p.nonce)?;
+
+    let mut data = ctx.accounts.replay_window.try_borrow_mut_data()?;
+    data[..8].copy_from_slice(&ReplayWindow::discriminator());
+    window.try_serialize(&mut &mut data[8..])?;
+    drop(data);
+
+    // A Solana-native NFT that was escrowed by `BurnForTransfer` (rather than burned)
+    // gets released back out of custody instead of minting a fresh mint + master
+    // edition, so the canonical mint and its Metaplex metadata survive the round trip.
+    let (custody_record_pda, _) = derive_custody_record_pda(&p.token_id);
+    require_keys_eq!(ctx.accounts.custody_record.key(), custody_record_pda, ErrorCode::CustodyPdaMismatch);
+    let is_native_release = p.origin_chain == SOLANA_CHAIN_ID && !ctx.accounts.custody_record.data_is_empty();
+
+    if is_native_release {
+        let custody_data = ctx.accounts.custody_record.try_borrow_data()?;
+        let record = CustodyRecord::try_from_slice(&custody_data[8..]).map_err(|_| ErrorCode::InvalidPayload)?;
+        require_keys_eq!(ctx.accounts.mint.key(), record.mint, ErrorCode::InvalidMintAuthorityPda);
+        drop(custody_data);
+
+        let (custody_authority_pda, custody_authority_bump) = derive_custody_authority_pda();
+        require_keys_eq!(ctx.accounts.custody_authority.key(), custody_authority_pda, ErrorCode::InvalidCustodyAuthority);
+
+        anchor_spl::token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                anchor_spl::token::Transfer {
+                    from: ctx.accounts.custody_token_account.to_account_info(),
+                    to: ctx.accounts.recipient_token_account.to_account_info(),
+                    authority: ctx.accounts.custody_authority.to_account_info(),
+                },
+                &[&[b"custody_authority", &[custody_authority_bump]]],
+            ),
+            1,
+        )?;
+
+        // Single-use: clear the record so the same escrowed token can't be released twice.
+        let mut data = ctx.accounts.custody_record.try_borrow_mut_data()?;
+        data.fill(0);
+    } else {
+        // Wrapped NFT: create a brand-new mint, same as before custody existed.
+        require!(ctx.accounts.mint.is_signer, ErrorCode::InvalidPayload);
+        let mint_space = anchor_spl::token::spl_token::state::Mint::LEN;
+        let mint_lamports = Rent::get()?.minimum_balance(mint_space);
+        anchor_lang::solana_program::program::invoke(
+            &anchor_lang::solana_program::system_instruction::create_account(
+                &ctx.accounts.payer.key(),
+                &ctx.accounts.mint.key(),
+                mint_lamports,
+                mint_space as u64,
+                &ctx.accounts.token_program.key(),
+            ),
+            &[
+                ctx.accounts.payer.to_account_info(),
+                ctx.accounts.mint.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+        anchor_spl::token::initialize_mint2(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                anchor_spl::token::InitializeMint2 {
+                    mint: ctx.accounts.mint.to_account_info(),
+                },
+            ),
+            0,
+            &ctx.accounts.mint_authority_pda.key(),
+            Some(&ctx.accounts.mint_authority_pda.key()),
+        )?;
+
+        anchor_spl::token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                anchor_spl::token::MintTo {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.recipient_token_account.to_account_info(),
+                    authority: ctx.accounts.mint_authority_pda.to_account_info(),
+                },
+                &[&[b"mint_authority", ctx.accounts.mint.key().as_ref(), &[mint_authority_bump]]],
+            ),
+            1,
+        )?;
+
+        // Create Metaplex metadata and master edition
+        // Validate MPL PDAs first
+        use crate::utils::{derive_metadata_pda, derive_master_edition_pda};
+        let (expected_md, _) = derive_metadata_pda(&ctx.accounts.mint.key());
+        require_keys_eq!(ctx.accounts.metadata.key(), expected_md, ErrorCode::InvalidMetadataPda);
+        let (expected_me, _) = derive_master_edition_pda(&ctx.accounts.mint.key());
+        require_keys_eq!(ctx.accounts.master_edition.key(), expected_me, ErrorCode::InvalidMasterEditionPda);
+
+        require!(p.seller_fee_basis_points <= 10000, ErrorCode::InvalidSellerFeeBasisPoints);
+        let creators = if p.creators.is_empty() {
+            None
+        } else {
+            Some(
+                p.creators
+                    .iter()
+                    .map(|c| Creator { address: c.address, verified: false, share: c.share })
+                    .collect(),
+            )
+        };
+        let collection = p.collection_mint.map(|key| Collection { verified: false, key });
+
+        cpi_create_metadata_v3(
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.mint_authority_pda.to_account_info(),
+            &ctx.accounts.mint_authority_pda.to_account_info(),
+            &ctx.accounts.metadata.to_account_info(),
+            &ctx.accounts.token_metadata_program.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.rent.to_account_info(),
+            p.name.clone(),
+            p.symbol.clone(),
+            p.metadata_uri.clone(),
+            creators,
+            p.seller_fee_basis_points,
+            collection,
+        )?;
+
+        cpi_create_master_edition_v3(
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.mint_authority_pda.to_account_info(),
+            &ctx.accounts.mint_authority_pda.to_account_info(),
+            &ctx.accounts.metadata.to_account_info(),
+            &ctx.accounts.master_edition.to_account_info(),
+            &ctx.accounts.token_metadata_program.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.rent.to_account_info(),
+        )?;
+
+        // Flip the collection membership stamped above to verified in the same
+        // transaction - there's no follow-up user-signed instruction the way a direct
+        // `mint_new_nft` caller can call `verify_collection_nft` afterwards, since a
+        // ZetaChain-triggered mint only gets this one `OnCall`.
+        if let Some(collection_mint) = p.collection_mint {
+            let collection_authority_pda = ctx
+                .accounts
+                .collection_authority_pda
+                .as_ref()
+                .ok_or(ErrorCode::MissingCollectionAccounts)?;
+            let collection_config_account = ctx
+                .accounts
+                .collection_config
+                .as_ref()
+                .ok_or(ErrorCode::MissingCollectionAccounts)?;
+            let collection_metadata = ctx
+                .accounts
+                .collection_metadata
+                .as_ref()
+                .ok_or(ErrorCode::MissingCollectionAccounts)?;
+            let collection_master_edition = ctx
+                .accounts
+                .collection_master_edition
+                .as_ref()
+                .ok_or(ErrorCode::MissingCollectionAccounts)?;
+            let collection_mint_account = ctx
+                .accounts
+                .collection_mint
+                .as_ref()
+                .ok_or(ErrorCode::MissingCollectionAccounts)?;
+            require_keys_eq!(collection_mint_account.key(), collection_mint, ErrorCode::InvalidCollectionConfigPda);
+
+            let (config_pda, _) = Pubkey::find_program_address(
+                &[CollectionConfig::SEED, collection_mint.as_ref()],
+                &crate::ID,
+            );
+            require_keys_eq!(collection_config_account.key(), config_pda, ErrorCode::InvalidCollectionConfigPda);
+            let config_data = collection_config_account.try_borrow_data()?;
+            let config = CollectionConfig::try_deserialize(&mut &config_data[..])
+                .map_err(|_| ErrorCode::InvalidCollectionConfigPda)?;
+            drop(config_data);
+            require_keys_eq!(config.collection_mint, collection_mint, ErrorCode::InvalidCollectionConfigPda);
+
+            let (collection_authority_key, _) = Pubkey::find_program_address(
+                &[b"collection_authority", collection_mint.as_ref()],
+                &crate::ID,
+            );
+            require_keys_eq!(collection_authority_pda.key(), collection_authority_key, ErrorCode::InvalidCollectionAuthorityPda);
+
+            let signer_seeds: &[&[u8]] = &[
+                b"collection_authority",
+                collection_mint.as_ref(),
+                &[config.authority_bump],
+            ];
+
+            let cpi_accounts = VerifyCollection {
+                payer: ctx.accounts.payer.to_account_info(),
+                metadata: ctx.accounts.metadata.to_account_info(),
+                collection_authority: collection_authority_pda.to_account_info(),
+                collection_mint: collection_mint_account.to_account_info(),
+                collection_metadata: collection_metadata.to_account_info(),
+                collection_master_edition: collection_master_edition.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                sysvar_instructions: ctx.accounts.instructions.to_account_info(),
+            };
+
+            verify_collection(
+                CpiContext::new(ctx.accounts.token_metadata_program.to_account_info(), cpi_accounts)
+                    .with_signer(&[signer_seeds]),
+                None,
+            )?;
+        }
+    }
 
     // Create nft_origin PDA deterministically
     let (nft_origin_pda, nft_origin_bump) = derive_nft_origin_pda(&p.token_id);
@@ -207,12 +416,92 @@ pub fn handler(ctx: Context<OnCall>, payload: Vec<u8>) -> Result<()> {
         metadata_uri: p.metadata_uri,
         created_at: clock.unix_timestamp,
         bump: nft_origin_bump,
+        origin_sender: p.origin_sender,
     };
 
     // Write discriminator + data
     let mut no_data = ctx.accounts.nft_origin.try_borrow_mut_data()?;
     no_data[..8].copy_from_slice(&NftOrigin::discriminator());
     nft_origin.try_serialize(&mut &mut no_data[8..])?;
+    drop(no_data);
+
+    // Persist attributes in their own PDA rather than inside the Metaplex CPI above,
+    // since `DataV2` has no field for them - skip the account entirely when the payload
+    // carries none so a plain transfer doesn't pay rent for an empty `NftAttributeSet`.
+    if !p.attributes.is_empty() {
+        assert_attributes_valid(&p.attributes)?;
+
+        let (nft_attributes_pda, nft_attributes_bump) = derive_nft_attributes_pda(&p.token_id);
+        require_keys_eq!(ctx.accounts.nft_attributes.key(), nft_attributes_pda, ErrorCode::AttributesPdaMismatch);
+
+        if ctx.accounts.nft_attributes.data_is_empty() {
+            let space = NftAttributeSet::LEN;
+            let lamports = Rent::get()?.minimum_balance(space);
+            anchor_lang::solana_program::program::invoke_signed(
+                &anchor_lang::solana_program::system_instruction::create_account(
+                    &ctx.accounts.payer.key(),
+                    &nft_attributes_pda,
+                    lamports,
+                    space as u64,
+                    &crate::ID,
+                ),
+                &[
+                    ctx.accounts.payer.to_account_info(),
+                    ctx.accounts.nft_attributes.to_account_info(),
+                    ctx.accounts.system_program.to_account_info(),
+                ],
+                &[&[NftAttributeSet::SEED, &p.token_id, &[nft_attributes_bump]]],
+            )?;
+        }
+
+        let attribute_set = NftAttributeSet {
+            token_id: p.token_id,
+            attributes: p.attributes.clone(),
+            bump: nft_attributes_bump,
+        };
+        let mut attr_data = ctx.accounts.nft_attributes.try_borrow_mut_data()?;
+        attr_data[..8].copy_from_slice(&NftAttributeSet::discriminator());
+        attribute_set.try_serialize(&mut &mut attr_data[8..])?;
+    }
+
+    require!(p.app_payload.len() <= MAX_APP_PAYLOAD_LEN, ErrorCode::AppPayloadTooLarge);
+    let app_payload_hash = if p.app_payload.is_empty() {
+        [0u8; 32]
+    } else {
+        anchor_lang::solana_program::keccak::hash(&p.app_payload).to_bytes()
+    };
+
+    // "Transfer with payload": once the mint/release above lands, hand the app payload
+    // off to `target_program` via CPI so it can react atomically in the same
+    // transaction (e.g. a marketplace listing the NFT or a vault staking it). The
+    // target program and any accounts it needs are supplied through
+    // `remaining_accounts`, with the target program itself expected first.
+    if let Some(target_program) = p.target_program {
+        let target_program_info = ctx
+            .remaining_accounts
+            .first()
+            .ok_or(ErrorCode::MissingTargetProgram)?;
+        require_keys_eq!(target_program_info.key(), target_program, ErrorCode::MissingTargetProgram);
+
+        let extra_accounts = &ctx.remaining_accounts[1..];
+        let account_metas = extra_accounts
+            .iter()
+            .map(|info| {
+                if info.is_writable {
+                    AccountMeta::new(info.key(), info.is_signer)
+                } else {
+                    AccountMeta::new_readonly(info.key(), info.is_signer)
+                }
+            })
+            .collect();
+
+        let ix = anchor_lang::solana_program::instruction::Instruction {
+            program_id: target_program,
+            accounts: account_metas,
+            data: p.app_payload.clone(),
+        };
+        anchor_lang::solana_program::program::invoke(&ix, extra_accounts)?;
+    }
 
     // Emit cross-chain mint event
     emit!(CrossChainMintEvent {
@@ -221,6 +510,8 @@ pub fn handler(ctx: Context<OnCall>, payload: Vec<u8>) -> Result<()> {
         recipient: p.recipient,
         nonce: p.nonce,
         timestamp: clock.unix_timestamp,
+        app_payload_hash,
+        origin_sender: p.origin_sender,
     });
 
     msg!("Minted Universal NFT from cross-chain transfer");
@@ -238,6 +529,13 @@ pub struct CrossChainMintEvent {
     pub recipient: Pubkey,
     pub nonce: u64,
     pub timestamp: i64,
+    /// keccak256 of the payload's `app_payload`, `[0u8; 32]` when there was none -
+    /// lets an indexer confirm what was handed to `target_program` without
+    /// replaying the whole cross-chain message.
+    pub app_payload_hash: [u8; 32],
+    /// `msg.sender` on the origin chain, so indexers and royalty/allowlist logic can
+    /// attribute this mint to who actually initiated it rather than just the gateway.
+    pub origin_sender: [u8; 20],
 }
 
 #[error_code]
@@ -246,8 +544,6 @@ pub enum ErrorCode {
     UnauthorizedGateway,
     #[msg("Invalid payload")]
     InvalidPayload,
-    #[msg("Replay attack detected")]
-    ReplayAttack,
     #[msg("Replay PDA mismatch")]
     ReplayPdaMismatch,
     #[msg("Invalid Metadata PDA")]
@@ -258,6 +554,26 @@ pub enum ErrorCode {
     NftOriginPdaMismatch,
     #[msg("Invalid Mint Authority PDA")]
     InvalidMintAuthorityPda,
+    #[msg("Custody PDA mismatch")]
+    CustodyPdaMismatch,
+    #[msg("Invalid custody authority")]
+    InvalidCustodyAuthority,
+    #[msg("App payload exceeds maximum length")]
+    AppPayloadTooLarge,
+    #[msg("Target program account missing or mismatched")]
+    MissingTargetProgram,
+    #[msg("Seller fee basis points must be <= 10000")]
+    InvalidSellerFeeBasisPoints,
+    #[msg("Collection accounts required when payload.collection_mint is set")]
+    MissingCollectionAccounts,
+    #[msg("Invalid Collection Config PDA")]
+    InvalidCollectionConfigPda,
+    #[msg("Invalid Collection Authority PDA")]
+    InvalidCollectionAuthorityPda,
+    #[msg("No registered emitter for this origin chain, or sender mismatch")]
+    UnregisteredEmitter,
+    #[msg("NftAttributeSet PDA mismatch")]
+    AttributesPdaMismatch,
 }
 
 
@@ -0,0 +1,339 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface};
+use crate::state::gateway::GatewayConfig;
+use crate::state::nft_origin::{NftOrigin, RevertContext, MAX_APP_PAYLOAD_LEN};
+use crate::state::replay::{ReplayWindow, RevertMarker};
+use crate::utils::{
+    derive_nft_origin_pda,
+    derive_replay_window_pda,
+    derive_revert_marker_pda,
+    cpi_create_metadata_v3,
+    cpi_create_master_edition_v3,
+};
+
+#[derive(Accounts)]
+pub struct OnRevert<'info> {
+    /// The CPI caller program (Gateway) is enforced via address lookup of config
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub owner: SystemAccount<'info>,
+    #[account(
+        init,
+        payer = payer,
+        mint::decimals = 0,
+        mint::authority = mint_authority_pda,
+        mint::freeze_authority = mint_authority_pda,
+        mint::token_program = token_program,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    /// CHECK: Metaplex metadata PDA for this mint; created via CPI
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+    /// CHECK: Metaplex master edition PDA for this mint; created via CPI
+    #[account(mut)]
+    pub master_edition: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK: nft_origin PDA; recreated with seeds [token_id, "nft_origin"] since
+    /// `BurnForTransfer` destroyed the supply backing the original one
+    #[account(mut)]
+    pub nft_origin: UncheckedAccount<'info>,
+    /// CHECK: PDA with gateway program id
+    pub gateway_config: UncheckedAccount<'info>,
+    /// CHECK: Sysvar instructions for CPI caller verification
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    /// CHECK: per-token replay window `BurnForTransfer` writes to; its nonce bit must
+    /// already be set to prove a burn actually happened for (token_id, nonce)
+    pub replay_window: UncheckedAccount<'info>,
+    /// CHECK: revert marker account; created here, must not already exist
+    #[account(mut)]
+    pub revert_marker: UncheckedAccount<'info>,
+    /// Either the classic Token program or Token-2022 - whichever the original mint
+    /// belonged to - so a Token-2022-native NFT reverts onto the same kind of mint.
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    /// CHECK: mint_authority PDA; will be derived programmatically
+    pub mint_authority_pda: UncheckedAccount<'info>,
+    /// CHECK: token metadata program
+    pub token_metadata_program: UncheckedAccount<'info>,
+}
+
+// Invoked by ZetaChain's Gateway when an outbound transfer started by `BurnForTransfer`
+// can't be completed on the destination chain. Mirrors `on_call`'s mint-in flow, but
+// gated on proof that the token was actually burned and on a dedicated idempotency
+// marker instead of the inbound replay marker.
+pub fn handler(ctx: Context<OnRevert>, revert_data: Vec<u8>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // Verify gateway config PDA
+    let (cfg_pda, _bump) = Pubkey::find_program_address(&[GatewayConfig::SEED], &crate::ID);
+    require_keys_eq!(ctx.accounts.gateway_config.key(), cfg_pda, ErrorCode::UnauthorizedGateway);
+    let data = ctx.accounts.gateway_config.try_borrow_data()?;
+    let cfg = GatewayConfig::try_from_slice(&data[8..]).map_err(|_| ErrorCode::UnauthorizedGateway)?;
+    drop(data);
+
+    // Verify the immediate CPI caller via Instructions sysvar (if applicable)
+    use anchor_lang::solana_program::sysvar::instructions as sys_ix;
+    let ix_sysvar = &ctx.accounts.instructions.to_account_info();
+    if let Ok(cur_idx) = sys_ix::load_current_index_checked(ix_sysvar) {
+        if cur_idx > 0 {
+            let prev = sys_ix::load_instruction_at_checked((cur_idx - 1) as usize, ix_sysvar)
+                .map_err(|_| ErrorCode::UnauthorizedGateway)?;
+            require_keys_eq!(prev.program_id, cfg.gateway_program, ErrorCode::UnauthorizedGateway);
+        }
+    }
+
+    // Deserialize the revert payload
+    let ctx_data: RevertContext = RevertContext::try_from_slice(&revert_data)
+        .map_err(|_| ErrorCode::InvalidPayload)?;
+    require_keys_eq!(ctx.accounts.owner.key(), ctx_data.owner, ErrorCode::InvalidPayload);
+
+    // Confirm a burn actually occurred: `BurnForTransfer` must have set this nonce's bit
+    // in the token's replay window.
+    let (replay_window_pda, _) = derive_replay_window_pda(&ctx_data.token_id);
+    require_keys_eq!(ctx.accounts.replay_window.key(), replay_window_pda, ErrorCode::ReplayPdaMismatch);
+    require!(!ctx.accounts.replay_window.data_is_empty(), ErrorCode::BurnNotFound);
+    let window_data = ctx.accounts.replay_window.try_borrow_data()?;
+    let window = ReplayWindow::try_deserialize(&mut &window_data[..])
+        .map_err(|_| ErrorCode::ReplayPdaMismatch)?;
+    drop(window_data);
+    require!(window.contains(ctx_data.nonce), ErrorCode::BurnNotFound);
+
+    // Idempotency: a revert-marker PDA keyed by the original transaction hash, distinct
+    // from the replay marker, so a failed transfer can only be refunded once.
+    let (revert_pda, revert_bump) = derive_revert_marker_pda(&ctx_data.original_tx_hash);
+    require_keys_eq!(ctx.accounts.revert_marker.key(), revert_pda, ErrorCode::RevertPdaMismatch);
+    require!(ctx.accounts.revert_marker.data_is_empty(), ErrorCode::AlreadyReverted);
+
+    let space = RevertMarker::LEN;
+    let lamports = Rent::get()?.minimum_balance(space);
+    anchor_lang::solana_program::program::invoke_signed(
+        &anchor_lang::solana_program::system_instruction::create_account(
+            &ctx.accounts.payer.key(),
+            &revert_pda,
+            lamports,
+            space as u64,
+            &crate::ID,
+        ),
+        &[
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.revert_marker.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+        &[&[RevertMarker::SEED, &ctx_data.original_tx_hash, &[revert_bump]]],
+    )?;
+
+    let marker = RevertMarker {
+        original_tx_hash: ctx_data.original_tx_hash,
+        token_id: ctx_data.token_id,
+        created_at: clock.unix_timestamp,
+        bump: revert_bump,
+    };
+    let mut revert_data_acc = ctx.accounts.revert_marker.try_borrow_mut_data()?;
+    revert_data_acc[..8].copy_from_slice(&RevertMarker::discriminator());
+    marker.try_serialize(&mut &mut revert_data_acc[8..])?;
+    drop(revert_data_acc);
+
+    // Derive mint authority PDA
+    let (mint_authority_pda, mint_authority_bump) = Pubkey::find_program_address(
+        &[b"mint_authority", ctx.accounts.mint.key().as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(ctx.accounts.mint_authority_pda.key(), mint_authority_pda, ErrorCode::InvalidMintAuthorityPda);
+
+    // Re-mint the single token `BurnForTransfer` destroyed, back to the original owner.
+    // `mint` was created with decimals 0 by its `init` constraint, whichever token
+    // program backs it.
+    token_interface::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::MintTo {
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.owner_token_account.to_account_info(),
+                authority: ctx.accounts.mint_authority_pda.to_account_info(),
+            },
+            &[&[b"mint_authority", ctx.accounts.mint.key().as_ref(), &[mint_authority_bump]]],
+        ),
+        1,
+    )?;
+
+    // Re-create Metaplex metadata and master edition for the re-minted token
+    use crate::utils::{derive_metadata_pda, derive_master_edition_pda};
+    let (expected_md, _) = derive_metadata_pda(&ctx.accounts.mint.key());
+    require_keys_eq!(ctx.accounts.metadata.key(), expected_md, ErrorCode::InvalidMetadataPda);
+    let (expected_me, _) = derive_master_edition_pda(&ctx.accounts.mint.key());
+    require_keys_eq!(ctx.accounts.master_edition.key(), expected_me, ErrorCode::InvalidMasterEditionPda);
+    cpi_create_metadata_v3(
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.mint_authority_pda.to_account_info(),
+        &ctx.accounts.mint_authority_pda.to_account_info(),
+        &ctx.accounts.metadata.to_account_info(),
+        &ctx.accounts.token_metadata_program.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.rent.to_account_info(),
+        "UniversalNFT".to_string(),
+        "UNFT".to_string(),
+        ctx_data.metadata_uri.clone(),
+        None,
+        0,
+        None,
+    )?;
+
+    cpi_create_master_edition_v3(
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.mint_authority_pda.to_account_info(),
+        &ctx.accounts.mint_authority_pda.to_account_info(),
+        &ctx.accounts.metadata.to_account_info(),
+        &ctx.accounts.master_edition.to_account_info(),
+        &ctx.accounts.token_metadata_program.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.rent.to_account_info(),
+    )?;
+
+    // Recreate the `NftOrigin` PDA `BurnForTransfer` left behind with no backing supply
+    let (nft_origin_pda, nft_origin_bump) = derive_nft_origin_pda(&ctx_data.token_id);
+    require_keys_eq!(ctx.accounts.nft_origin.key(), nft_origin_pda, ErrorCode::NftOriginPdaMismatch);
+
+    if ctx.accounts.nft_origin.data_is_empty() {
+        let space = 8 + NftOrigin::LEN; // add discriminator
+        let lamports = Rent::get()?.minimum_balance(space);
+        anchor_lang::solana_program::program::invoke_signed(
+            &anchor_lang::solana_program::system_instruction::create_account(
+                &ctx.accounts.payer.key(),
+                &nft_origin_pda,
+                lamports,
+                space as u64,
+                &crate::ID,
+            ),
+            &[
+                ctx.accounts.payer.to_account_info(),
+                ctx.accounts.nft_origin.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+            &[&[&ctx_data.token_id, b"nft_origin", &[nft_origin_bump]]],
+        )?;
+    }
+
+    let nft_origin = NftOrigin {
+        origin_chain: ctx_data.origin_chain,
+        origin_token_id: ctx_data.token_id,
+        origin_mint: ctx_data.origin_mint,
+        metadata_uri: ctx_data.metadata_uri,
+        created_at: clock.unix_timestamp,
+        bump: nft_origin_bump,
+        origin_sender: ctx_data.origin_sender,
+    };
+
+    let mut no_data = ctx.accounts.nft_origin.try_borrow_mut_data()?;
+    no_data[..8].copy_from_slice(&NftOrigin::discriminator());
+    nft_origin.try_serialize(&mut &mut no_data[8..])?;
+    drop(no_data);
+
+    require!(ctx_data.app_payload.len() <= MAX_APP_PAYLOAD_LEN, ErrorCode::AppPayloadTooLarge);
+    let app_payload_hash = if ctx_data.app_payload.is_empty() {
+        [0u8; 32]
+    } else {
+        anchor_lang::solana_program::keccak::hash(&ctx_data.app_payload).to_bytes()
+    };
+
+    // Same "transfer with payload" composability `on_call` gives an inbound mint: once
+    // the token is back in the owner's hands, hand the app payload off to
+    // `target_program` via CPI so it can react to the revert atomically (e.g. releasing
+    // an escrow it was holding pending the transfer's success).
+    if let Some(target_program) = ctx_data.target_program {
+        let target_program_info = ctx
+            .remaining_accounts
+            .first()
+            .ok_or(ErrorCode::MissingTargetProgram)?;
+        require_keys_eq!(target_program_info.key(), target_program, ErrorCode::MissingTargetProgram);
+
+        let extra_accounts = &ctx.remaining_accounts[1..];
+        let account_metas = extra_accounts
+            .iter()
+            .map(|info| {
+                if info.is_writable {
+                    AccountMeta::new(info.key(), info.is_signer)
+                } else {
+                    AccountMeta::new_readonly(info.key(), info.is_signer)
+                }
+            })
+            .collect();
+
+        let ix = anchor_lang::solana_program::instruction::Instruction {
+            program_id: target_program,
+            accounts: account_metas,
+            data: ctx_data.app_payload.clone(),
+        };
+        anchor_lang::solana_program::program::invoke(&ix, extra_accounts)?;
+    }
+
+    emit!(CrossChainRevertEvent {
+        token_id: ctx_data.token_id,
+        origin_chain: ctx_data.origin_chain,
+        owner: ctx_data.owner,
+        nonce: ctx_data.nonce,
+        original_tx_hash: ctx_data.original_tx_hash,
+        timestamp: clock.unix_timestamp,
+        app_payload_hash,
+        origin_sender: ctx_data.origin_sender,
+    });
+
+    msg!("Reverted cross-chain transfer - re-minted token_id {} to {}", hex::encode(&ctx_data.token_id[..8]), ctx.accounts.owner.key());
+
+    Ok(())
+}
+
+#[event]
+pub struct CrossChainRevertEvent {
+    pub token_id: [u8; 32],
+    pub origin_chain: u64,
+    pub owner: Pubkey,
+    pub nonce: u64,
+    pub original_tx_hash: [u8; 32],
+    pub timestamp: i64,
+    /// keccak256 of `RevertContext::app_payload`, `[0u8; 32]` when there was none.
+    pub app_payload_hash: [u8; 32],
+    /// `msg.sender` on the origin chain whose outbound transfer is being reverted.
+    pub origin_sender: [u8; 20],
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Unauthorized gateway")]
+    UnauthorizedGateway,
+    #[msg("Invalid payload")]
+    InvalidPayload,
+    #[msg("Replay PDA mismatch")]
+    ReplayPdaMismatch,
+    #[msg("No matching burn found for this revert")]
+    BurnNotFound,
+    #[msg("Revert marker PDA mismatch")]
+    RevertPdaMismatch,
+    #[msg("This transfer has already been reverted")]
+    AlreadyReverted,
+    #[msg("Invalid Metadata PDA")]
+    InvalidMetadataPda,
+    #[msg("Invalid Master Edition PDA")]
+    InvalidMasterEditionPda,
+    #[msg("Invalid NftOrigin PDA")]
+    NftOriginPdaMismatch,
+    #[msg("Invalid Mint Authority PDA")]
+    InvalidMintAuthorityPda,
+    #[msg("App payload exceeds the maximum allowed size")]
+    AppPayloadTooLarge,
+    #[msg("Target program account missing from remaining_accounts")]
+    MissingTargetProgram,
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::state::endpoint::RegisteredEmitter;
+
+/// Registers the authorized emitter address for a source chain. Like
+/// `InitializeGatewayConfig`, this is a one-shot `init` rather than authority-gated -
+/// the PDA can only be written once per `origin_chain`, so whoever stands up the
+/// deployment's endpoint table first locks it in for good.
+#[derive(Accounts)]
+#[instruction(origin_chain: u64)]
+pub struct RegisterEndpoint<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = RegisteredEmitter::LEN,
+        seeds = [RegisteredEmitter::SEED, &origin_chain.to_le_bytes()],
+        bump
+    )]
+    pub endpoint: Account<'info, RegisteredEmitter>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RegisterEndpoint>, origin_chain: u64, emitter_address: [u8; 20]) -> Result<()> {
+    let endpoint = &mut ctx.accounts.endpoint;
+    endpoint.origin_chain = origin_chain;
+    endpoint.emitter_address = emitter_address;
+    endpoint.bump = ctx.bumps.endpoint;
+    Ok(())
+}
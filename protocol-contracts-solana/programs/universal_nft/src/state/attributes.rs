@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+/// Upper bound on the number of trait/value pairs a single `NftAttributeSet` can hold.
+/// Metaplex's on-chain `DataV2` has no slot for arbitrary attributes the way it does for
+/// `creators`, so this mirrors `MAX_CREATORS` in spirit: a generous but fixed cap that
+/// keeps the account's space constant.
+pub const MAX_ATTRIBUTES_COUNT: usize = 16;
+/// Upper bound on the byte length of a single `trait_type` or `value` string.
+pub const MAX_ATTRIBUTE_STRING_LEN: usize = 64;
+
+/// One trait/value pair carried by a `CrossChainNftPayload` and persisted alongside a
+/// minted NFT so it round-trips on a later outbound transfer.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct NftAttribute {
+    pub trait_type: String,
+    pub value: String,
+}
+
+/// Stores the attributes carried by a `CrossChainNftPayload`, since Metaplex's on-chain
+/// `DataV2` - the struct `cpi_create_metadata_v3` actually writes - has no field for
+/// them, only `name`/`symbol`/`uri`/`creators`/`collection`/`uses`. `OnCall` creates this
+/// account alongside `NftOrigin` whenever a payload carries a non-empty attribute list,
+/// and `BurnForTransfer` reads it back so a Solana-native NFT carries the same
+/// attributes it arrived with on its next outbound transfer.
+#[account]
+pub struct NftAttributeSet {
+    pub token_id: [u8; 32],
+    pub attributes: Vec<NftAttribute>,
+    pub bump: u8,
+}
+
+impl NftAttributeSet {
+    pub const SEED: &'static [u8] = b"nft_attrs";
+    pub const LEN: usize = 8 // discriminator
+        + 32 // token_id
+        + 4 + MAX_ATTRIBUTES_COUNT * (4 + MAX_ATTRIBUTE_STRING_LEN + 4 + MAX_ATTRIBUTE_STRING_LEN) // attributes
+        + 1; // bump
+}
+
+pub fn assert_attributes_valid(attributes: &[NftAttribute]) -> Result<()> {
+    require!(attributes.len() <= MAX_ATTRIBUTES_COUNT, AttributesError::TooManyAttributes);
+    for attribute in attributes {
+        require!(
+            attribute.trait_type.len() <= MAX_ATTRIBUTE_STRING_LEN
+                && attribute.value.len() <= MAX_ATTRIBUTE_STRING_LEN,
+            AttributesError::AttributeFieldTooLong
+        );
+    }
+    Ok(())
+}
+
+#[error_code]
+pub enum AttributesError {
+    #[msg("More than 16 attributes")]
+    TooManyAttributes,
+    #[msg("Attribute trait_type or value exceeds 64 bytes")]
+    AttributeFieldTooLong,
+}
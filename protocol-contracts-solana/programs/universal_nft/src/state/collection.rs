@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct CollectionConfig {
+    pub collection_mint: Pubkey,
+    pub authority_bump: u8,
+    pub bump: u8,
+}
+
+impl CollectionConfig {
+    pub const SEED: &'static [u8] = b"collection_config";
+    // discriminator (8) + collection_mint(32) + authority_bump(1) + bump(1)
+    pub const LEN: usize = 8 + 32 + 1 + 1;
+}
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+/// Marks a Solana-native NFT as escrowed in program custody (see `BurnForTransfer`)
+/// rather than burned, so a round trip back to Solana can release the original mint
+/// through `OnCall` instead of minting a new one and losing the canonical identity.
+#[account]
+pub struct CustodyRecord {
+    pub token_id: [u8; 32],
+    pub mint: Pubkey,
+    pub locked_at: i64,
+    pub bump: u8,
+}
+
+impl CustodyRecord {
+    pub const SEED: &'static [u8] = b"custody";
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 1; // discriminator + token_id + mint + locked_at + bump
+}
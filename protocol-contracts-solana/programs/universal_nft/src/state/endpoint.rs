@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+/// A source chain's authorized emitter, following the Wormhole `Endpoint`/registered-
+/// emitter model: binding `origin_chain` to a fixed `emitter_address` up front means an
+/// inbound payload claiming to be from `origin_chain` must also carry that chain's real
+/// sender, so a spoofed `origin_chain` can't be paired with an attacker-controlled one.
+#[account]
+pub struct RegisteredEmitter {
+    pub origin_chain: u64,
+    pub emitter_address: [u8; 20],
+    pub bump: u8,
+}
+
+impl RegisteredEmitter {
+    pub const SEED: &'static [u8] = b"endpoint";
+    pub const LEN: usize = 8 + 8 + 20 + 1;
+}
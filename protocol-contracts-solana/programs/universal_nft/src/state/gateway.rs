@@ -4,11 +4,15 @@ use anchor_lang::prelude::*;
 pub struct GatewayConfig {
     pub gateway_program: Pubkey,
     pub gateway_pda: Pubkey,
+    /// Monotonically increasing nonce embedded in outbound payloads for replay protection.
+    pub nonce: u64,
+    /// When true, `send_to_zeta` rejects new outbound transfers.
+    pub is_paused: bool,
     pub bump: u8,
 }
 
 impl GatewayConfig {
     pub const SEED: &'static [u8] = b"gateway_config";
-    // discriminator (8) + gateway_program(32) + gateway_pda(32) + bump(1)
-    pub const LEN: usize = 8 + 32 + 32 + 1;
+    // discriminator (8) + gateway_program(32) + gateway_pda(32) + nonce(8) + is_paused(1) + bump(1)
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 1 + 1;
 }
@@ -1,3 +1,7 @@
+pub mod attributes;
+pub mod collection;
+pub mod custody;
+pub mod endpoint;
 pub mod gateway;
 pub mod nft_origin;
 pub mod replay;
@@ -50,6 +54,8 @@ pub fn initialize_gateway_config(
     let cfg = GatewayConfig {
         gateway_program: ctx.accounts.gateway_program.key(),
         gateway_pda: ctx.accounts.gateway_pda.key(),
+        nonce: 0,
+        is_paused: false,
         bump,
     };
     cfg.try_serialize(&mut &mut data[8..])?;
@@ -8,6 +8,10 @@ pub struct NftOrigin {
     pub metadata_uri: String,
     pub created_at: i64,
     pub bump: u8,
+    /// `msg.sender` on the origin chain that initiated this transfer, so downstream
+    /// authorization decisions (e.g. only accept mints originated by a known remote
+    /// collection contract) don't have to trust the gateway identity alone.
+    pub origin_sender: [u8; 20],
 }
 
 impl NftOrigin {
@@ -19,7 +23,8 @@ impl NftOrigin {
         + 32                           // origin_mint (Pubkey)
         + 4 + Self::MAX_URI_LEN       // metadata_uri (length prefix + data)
         + 8                            // created_at (i64)
-        + 1;                           // bump (u8)
+        + 1                            // bump (u8)
+        + 20;                          // origin_sender ([u8; 20])
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
@@ -30,4 +35,55 @@ pub struct CrossChainNftPayload {
     pub recipient: Pubkey,
     pub metadata_uri: String,
     pub nonce: u64,
+    /// `msg.sender` on the origin chain that initiated this transfer - carried over so
+    /// the program learns who triggered the transfer, not just who the gateway is.
+    pub origin_sender: [u8; 20],
+    /// Downstream program to CPI into right after the mint completes, e.g. a
+    /// marketplace or staking vault reacting to the NFT's arrival. `None` skips the
+    /// CPI entirely - the existing behavior for a payload with no app-level composition.
+    pub target_program: Option<Pubkey>,
+    /// Opaque bytes handed to `target_program` as-is; empty when `target_program` is `None`.
+    pub app_payload: Vec<u8>,
+    /// The NFT's real name/symbol on its origin chain, so a bridged mint isn't a
+    /// generic "UniversalNFT"/"UNFT" singleton indistinguishable from any other.
+    pub name: String,
+    pub symbol: String,
+    pub creators: Vec<crate::mint::CreatorArg>,
+    pub seller_fee_basis_points: u16,
+    /// Collection this bridged NFT should join and have verified against, mirroring
+    /// `MintNewNft::collection_mint`. `None` mints a standalone NFT as before.
+    pub collection_mint: Option<Pubkey>,
+    /// Trait/value pairs to persist in a `NftAttributeSet` alongside the mint, since
+    /// Metaplex's `DataV2` has no attributes field of its own. Empty when the origin
+    /// chain's asset carries none.
+    pub attributes: Vec<crate::state::attributes::NftAttribute>,
+}
+
+/// Upper bound on `CrossChainNftPayload::app_payload` so a single inbound message
+/// can't blow out transaction/account size limits.
+pub const MAX_APP_PAYLOAD_LEN: usize = 1024;
+
+/// Carried back by ZetaChain's Gateway when an outbound transfer started by
+/// `BurnForTransfer` can't be completed on the destination chain, so `on_revert` has
+/// everything it needs to re-mint the token without looking anything up off-chain.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct RevertContext {
+    pub token_id: [u8; 32],
+    pub nonce: u64,
+    pub origin_chain: u64,
+    pub origin_mint: Pubkey,
+    pub metadata_uri: String,
+    pub owner: Pubkey,
+    /// Hash of the original outbound transaction; keys the revert-marker PDA so the
+    /// same failed transfer can't be refunded twice.
+    pub original_tx_hash: [u8; 32],
+    /// `msg.sender` on the origin chain whose outbound transfer is being reverted -
+    /// carried over onto the re-minted `NftOrigin` the same way `CrossChainNftPayload`
+    /// does for an inbound mint.
+    pub origin_sender: [u8; 20],
+    /// Downstream program to CPI into once the token is re-minted, mirroring
+    /// `CrossChainNftPayload::target_program`. `None` skips the CPI entirely.
+    pub target_program: Option<Pubkey>,
+    /// Opaque bytes handed to `target_program` as-is; empty when `target_program` is `None`.
+    pub app_payload: Vec<u8>,
 }
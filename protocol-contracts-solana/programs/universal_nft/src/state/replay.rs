@@ -1,14 +1,119 @@
 use anchor_lang::prelude::*;
 
+/// Per-message replay protection: one account per `(token_id, origin_chain, nonce)`,
+/// created by `handle_incoming`. `OnCall` and `BurnForTransfer` use `ReplayWindow`
+/// instead.
 #[account]
 pub struct ReplayMarker {
     pub token_id: [u8; 32],
+    /// Source chain this marker was claimed under - folded into the PDA seeds so the
+    /// same `(token_id, nonce)` pair from two different chains can't collide.
+    pub origin_chain: u64,
     pub nonce: u64,
     pub created_at: i64,
     pub bump: u8,
 }
 
 impl ReplayMarker {
-    pub const LEN: usize = 8 + 32 + 8 + 8 + 1; // discriminator + [u8; 32] + u64 + i64 + u8
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 8 + 1; // discriminator + [u8; 32] + origin_chain(u64) + nonce(u64) + i64 + u8
     pub const SEED: &[u8] = b"replay";
 }
+
+/// Bounds replay-protection storage to one account per token instead of one per
+/// `(token_id, nonce)`: a high-water-mark `highest_nonce` plus a fixed `WINDOW_SIZE`-bit
+/// circular bitmap covering the most recently seen nonces, the same way bridges track a
+/// monotonic sequence number rather than materializing a claim account per message.
+#[account]
+pub struct ReplayWindow {
+    pub token_id: [u8; 32],
+    pub highest_nonce: u64,
+    pub bitmap: [u8; ReplayWindow::BITMAP_BYTES],
+    pub bump: u8,
+}
+
+impl ReplayWindow {
+    pub const WINDOW_SIZE: u64 = 1024;
+    pub const BITMAP_BYTES: usize = (Self::WINDOW_SIZE / 8) as usize;
+    pub const SEED: &'static [u8] = b"replay_window";
+    pub const LEN: usize = 8 + 32 + 8 + Self::BITMAP_BYTES + 1;
+
+    fn floor(highest_nonce: u64) -> u64 {
+        highest_nonce.saturating_sub(Self::WINDOW_SIZE - 1)
+    }
+
+    fn bit(&self, nonce: u64) -> bool {
+        let idx = (nonce % Self::WINDOW_SIZE) as usize;
+        self.bitmap[idx / 8] & (1 << (idx % 8)) != 0
+    }
+
+    fn set_bit(&mut self, nonce: u64) {
+        let idx = (nonce % Self::WINDOW_SIZE) as usize;
+        self.bitmap[idx / 8] |= 1 << (idx % 8);
+    }
+
+    fn clear_bit(&mut self, nonce: u64) {
+        let idx = (nonce % Self::WINDOW_SIZE) as usize;
+        self.bitmap[idx / 8] &= !(1 << (idx % 8));
+    }
+
+    /// Read-only counterpart to `check_and_set`: true if `nonce` is within the current
+    /// window and its bit is set. Used by callers that only need to confirm a nonce was
+    /// already processed (e.g. `on_revert` confirming a burn happened) without claiming it.
+    pub fn contains(&self, nonce: u64) -> bool {
+        if nonce > self.highest_nonce || nonce < Self::floor(self.highest_nonce) {
+            return false;
+        }
+        self.bit(nonce)
+    }
+
+    /// Rejects a stale (below the window floor) or already-seen nonce; otherwise marks
+    /// it seen, sliding the window forward (and clearing bits that scroll out of range)
+    /// if `nonce` advances `highest_nonce`.
+    pub fn check_and_set(&mut self, nonce: u64) -> Result<()> {
+        if nonce <= self.highest_nonce {
+            require!(nonce >= Self::floor(self.highest_nonce), ReplayWindowError::NonceBelowWindow);
+            require!(!self.bit(nonce), ReplayWindowError::NonceAlreadySeen);
+            self.set_bit(nonce);
+            return Ok(());
+        }
+
+        if nonce - self.highest_nonce >= Self::WINDOW_SIZE {
+            self.bitmap = [0u8; Self::BITMAP_BYTES];
+        } else {
+            let old_floor = Self::floor(self.highest_nonce);
+            let new_floor = Self::floor(nonce);
+            let mut stale = old_floor;
+            while stale < new_floor {
+                self.clear_bit(stale);
+                stale += 1;
+            }
+        }
+
+        self.highest_nonce = nonce;
+        self.set_bit(nonce);
+        Ok(())
+    }
+}
+
+#[error_code]
+pub enum ReplayWindowError {
+    #[msg("Nonce is below the replay window floor")]
+    NonceBelowWindow,
+    #[msg("Nonce has already been seen")]
+    NonceAlreadySeen,
+}
+
+/// Written once a revert for a given outbound transaction has been processed, so
+/// `on_revert` can't re-mint the same burned token twice.
+#[account]
+pub struct RevertMarker {
+    pub original_tx_hash: [u8; 32],
+    pub token_id: [u8; 32],
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl RevertMarker {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 1; // discriminator + [u8; 32] + [u8; 32] + i64 + u8
+    pub const SEED: &[u8] = b"revert";
+}
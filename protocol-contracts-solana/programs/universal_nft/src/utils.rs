@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use mpl_token_metadata::types::DataV2;
+use mpl_token_metadata::types::{Collection, Creator, DataV2};
 use anchor_spl::metadata::{create_master_edition_v3, CreateMasterEditionV3, create_metadata_accounts_v3};
 
 pub fn derive_nft_origin_pda(token_id: &[u8]) -> (Pubkey, u8) {
@@ -7,8 +7,44 @@ pub fn derive_nft_origin_pda(token_id: &[u8]) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[token_id, b"nft_origin"], &crate::ID)
 }
 
-pub fn derive_replay_marker_pda(token_id: &[u8], nonce: u64) -> (Pubkey, u8) {
-    Pubkey::find_program_address(&[b"replay", token_id, &nonce.to_le_bytes()], &crate::ID)
+/// `origin_chain` is folded into the seeds (not just `token_id`/`nonce`) so two source
+/// chains replaying the same `(token_id, nonce)` pair land on different marker PDAs
+/// instead of colliding - the `origin_chain` baked in here is the one already checked
+/// against the payload's registered emitter, not attacker-controlled at this point.
+pub fn derive_replay_marker_pda(token_id: &[u8], origin_chain: u64, nonce: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"replay", token_id, &origin_chain.to_le_bytes(), &nonce.to_le_bytes()],
+        &crate::ID,
+    )
+}
+
+/// PDA for the `RegisteredEmitter` binding `origin_chain` to its authorized sender.
+pub fn derive_endpoint_pda(origin_chain: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"endpoint", &origin_chain.to_le_bytes()], &crate::ID)
+}
+
+pub fn derive_replay_window_pda(token_id: &[u8]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"replay_window", token_id], &crate::ID)
+}
+
+pub fn derive_revert_marker_pda(original_tx_hash: &[u8]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"revert", original_tx_hash], &crate::ID)
+}
+
+pub fn derive_custody_record_pda(token_id: &[u8]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"custody", token_id], &crate::ID)
+}
+
+/// PDA for the `NftAttributeSet` holding a token's trait/value pairs, since Metaplex's
+/// `DataV2` has no attributes field of its own.
+pub fn derive_nft_attributes_pda(token_id: &[u8]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[crate::state::attributes::NftAttributeSet::SEED, token_id], &crate::ID)
+}
+
+/// Authority over every custody ATA a Solana-native NFT is escrowed into - a single
+/// PDA rather than one per token, since it only ever signs token transfers.
+pub fn derive_custody_authority_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"custody_authority"], &crate::ID)
 }
 
 pub fn derive_metadata_pda(mint: &Pubkey) -> (Pubkey, u8) {
@@ -32,6 +68,49 @@ pub fn derive_mint_authority_pda() -> (Pubkey, u8) {
     Pubkey::find_program_address(&[b"mint_authority"], &crate::ID)
 }
 
+/// Metaplex's Token Metadata program silently enforces these bounds and fails deep
+/// inside its own instruction handler with an opaque error if violated. Checking them
+/// here, before the CPI, mirrors Metaplex's own `assert_data_valid` so a malformed
+/// cross-chain payload is rejected with a clear reason before any account is touched.
+pub const MAX_NAME_LEN: usize = 32;
+pub const MAX_SYMBOL_LEN: usize = 10;
+pub const MAX_URI_LEN: usize = 200;
+pub const MAX_CREATORS: usize = 5;
+
+fn assert_data_valid(
+    name: &str,
+    symbol: &str,
+    uri: &str,
+    seller_fee_basis_points: u16,
+    creators: &Option<Vec<Creator>>,
+) -> Result<()> {
+    require!(name.len() <= MAX_NAME_LEN, MetadataValidationError::NameTooLong);
+    require!(symbol.len() <= MAX_SYMBOL_LEN, MetadataValidationError::SymbolTooLong);
+    require!(!uri.is_empty(), MetadataValidationError::EmptyUri);
+    require!(uri.len() <= MAX_URI_LEN, MetadataValidationError::UriTooLong);
+    require!(seller_fee_basis_points <= 10000, MetadataValidationError::InvalidBasisPoints);
+    if let Some(creators) = creators {
+        require!(creators.len() <= MAX_CREATORS, MetadataValidationError::TooManyCreators);
+    }
+    Ok(())
+}
+
+#[error_code]
+pub enum MetadataValidationError {
+    #[msg("Metadata name exceeds Metaplex's 32-byte limit")]
+    NameTooLong,
+    #[msg("Metadata symbol exceeds Metaplex's 10-byte limit")]
+    SymbolTooLong,
+    #[msg("Metadata URI exceeds Metaplex's 200-byte limit")]
+    UriTooLong,
+    #[msg("Metadata URI is empty")]
+    EmptyUri,
+    #[msg("Seller fee basis points exceeds 10000 (100%)")]
+    InvalidBasisPoints,
+    #[msg("More than 5 creators")]
+    TooManyCreators,
+}
+
 pub fn cpi_create_metadata_v3<'a>(
     payer: &AccountInfo<'a>,
     mint: &AccountInfo<'a>,
@@ -44,14 +123,19 @@ pub fn cpi_create_metadata_v3<'a>(
     name: String,
     symbol: String,
     uri: String,
+    creators: Option<Vec<Creator>>,
+    seller_fee_basis_points: u16,
+    collection: Option<Collection>,
 ) -> Result<()> {
+    assert_data_valid(&name, &symbol, &uri, seller_fee_basis_points, &creators)?;
+
     let data = DataV2 {
         name,
         symbol,
         uri,
-        seller_fee_basis_points: 0,
-        creators: None,
-        collection: None,
+        seller_fee_basis_points,
+        creators,
+        collection,
         uses: None,
     };
 
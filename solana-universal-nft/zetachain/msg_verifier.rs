@@ -1,12 +1,59 @@
 
 // msg_verifier.rs
-// Placeholder for verifying ZetaChain cross-chain messages or signatures
+// Verifies ZetaChain cross-chain messages against the network's TSS signature.
 
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{keccak, secp256k1_recover::secp256k1_recover};
 
-/// Verify the signature or origin of the message
-/// In a real implementation, this would use TSS or signature validation.
-pub fn verify_zeta_signature(_message: &[u8], _signature: &[u8]) -> bool {
-    msg!("Verifying signature... [placeholder]");
-    true // Assume valid for placeholder
+/// Upper half of the secp256k1 curve order, used to reject malleable high-S signatures
+/// (the same check Ethereum/Wormhole guardians apply).
+const SECP256K1_HALF_ORDER: [u8; 32] = [
+    0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0x5D, 0x57, 0x6E, 0x73, 0x57, 0xA4, 0x50, 0x1D, 0xDF, 0xE9, 0x2F, 0x46, 0x68, 0x1B, 0x20, 0xA0,
+];
+
+/// Verify that `signature` (64 bytes, `r ‖ s`) plus `recovery_id` is a valid TSS signature
+/// over `message`, and that the recovered signer matches `tss_address` (the 20-byte EVM
+/// address of the ZetaChain TSS, compared in constant time).
+///
+/// `message` must be the canonical byte layout the caller and the TSS both sign over
+/// (e.g. `token_id ‖ destination_chain_id ‖ addr_len ‖ destination_address ‖ message_hash`
+/// for outbound transfers). This function only recovers and compares the signer; it does
+/// not interpret the message contents.
+pub fn verify_zeta_signature(
+    message: &[u8],
+    signature: &[u8; 64],
+    recovery_id: u8,
+    tss_address: &[u8; 20],
+) -> Result<()> {
+    require!(recovery_id <= 3, ErrorCode::InvalidSignature);
+
+    let s = &signature[32..64];
+    require!(
+        s.as_ref() <= SECP256K1_HALF_ORDER.as_ref(),
+        ErrorCode::InvalidSignature
+    );
+
+    let message_hash = keccak::hash(message);
+
+    let recovered = secp256k1_recover(&message_hash.0, recovery_id, signature)
+        .map_err(|_| error!(ErrorCode::InvalidSignature))?;
+
+    let signer_hash = keccak::hash(&recovered.0);
+    let signer_address = &signer_hash.0[12..32];
+
+    let mut diff: u8 = 0;
+    for (a, b) in signer_address.iter().zip(tss_address.iter()) {
+        diff |= a ^ b;
+    }
+    require!(diff == 0, ErrorCode::InvalidSignature);
+
+    msg!("TSS signature verified");
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("TSS signature verification failed")]
+    InvalidSignature,
 }
@@ -1,35 +1,127 @@
 //! ZetaChain message signature verification utilities.
 //!
-//! This module provides a placeholder verifier that is **feature-gated**
-//! so accidental use in production cannot silently bypass checks.
-//
-//! Usage:
-//! - Enable the "insecure-placeholder" feature ONLY in local dev/tests to
-//!   keep behavior compatible while a real verifier is implemented.
-//! - Without the feature, calls will return `MissingRequiredSignature`.
-//
-//! Replace this with a real verifier that checks for a preceding
-//! ed25519/secp256k1 verification instruction (preferred on Solana).
-
-use solana_program::{msg, program_error::ProgramError};
-
-/// Verify the ZetaChain message signature.
-///
-/// When the `insecure-placeholder` feature is enabled, this function
-/// logs a placeholder message and returns `Ok(())` for convenience in
-/// local development. Otherwise, it returns
-/// `ProgramError::MissingRequiredSignature` to prevent accidental bypasses.
-#[cfg(feature = "insecure-placeholder")]
-pub fn verify_zeta_signature(_message: &[u8], _signature: &[u8]) -> Result<(), ProgramError> {
-    msg!("Verifying signature... [placeholder]");
-    Ok(())
+//! Instead of recovering the signer in-program with the `secp256k1_recover` syscall, this
+//! module relies on Solana's native `Secp256k1SigVerify` program, which runs as its own
+//! top-level instruction, before this one, in the same transaction. Wormhole's
+//! Solana guardian-set verifier relies on exactly this ordering - this module mirrors
+//! that approach for ZetaChain's TSS signature instead of reimplementing recovery here.
+
+use solana_program::{
+    account_info::AccountInfo,
+    keccak,
+    program_error::ProgramError,
+    secp256k1_program,
+    sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+};
+
+/// Byte layout of a single signature record inside a `Secp256k1SigVerify` instruction -
+/// mirrors `solana_sdk::secp256k1_instruction::SecpSignatureOffsets` (11 bytes, all
+/// little-endian).
+struct SecpSignatureOffsets {
+    signature_offset: u16,
+    signature_instruction_index: u8,
+    eth_address_offset: u16,
+    eth_address_instruction_index: u8,
+    message_data_offset: u16,
+    message_data_size: u16,
+    message_instruction_index: u8,
 }
 
-#[cfg(not(feature = "insecure-placeholder"))]
-pub fn verify_zeta_signature(_message: &[u8], _signature: &[u8]) -> Result<(), ProgramError> {
-    // TODO: Implement real verification:
-    //  - Prefer using Solana's built-in ed25519/secp256k1 verification syscalls
-    //    by asserting a prior instruction matches the expected signer and message.
-    //  - Or perform domain-specific checks required by ZetaChain's gateway.
+const SECP_SIGNATURE_OFFSETS_LEN: usize = 11;
+
+fn parse_offsets(record: &[u8]) -> Result<SecpSignatureOffsets, ProgramError> {
+    if record.len() < SECP_SIGNATURE_OFFSETS_LEN {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    Ok(SecpSignatureOffsets {
+        signature_offset: u16::from_le_bytes([record[0], record[1]]),
+        signature_instruction_index: record[2],
+        eth_address_offset: u16::from_le_bytes([record[3], record[4]]),
+        eth_address_instruction_index: record[5],
+        message_data_offset: u16::from_le_bytes([record[6], record[7]]),
+        message_data_size: u16::from_le_bytes([record[8], record[9]]),
+        message_instruction_index: record[10],
+    })
+}
+
+/// Verifies that the instruction immediately preceding this one in the transaction is a
+/// native `Secp256k1SigVerify` check recovering `zeta_gateway_verifier` over the
+/// keccak256 digest of `expected_message` (the payload this program is about to act on).
+///
+/// Rejects if: the preceding instruction isn't the secp256k1 program, it verified zero
+/// signatures, or none of its signature records both recovered `zeta_gateway_verifier`
+/// and covered exactly `expected_message`'s digest. `instruction_index` fields in a
+/// signature record are set to the index of whichever instruction actually carries that
+/// slice of data; self-contained records (address/message living in the same
+/// instruction as the offsets table) carry that secp256k1 instruction's own index, which
+/// is `0` when it is the transaction's first instruction - the common case when the caller packs
+/// everything into the one secp256k1 instruction.
+pub fn verify_zeta_signature(
+    instructions_sysvar: &AccountInfo,
+    zeta_gateway_verifier: &[u8; 20],
+    expected_message: &[u8],
+) -> Result<(), ProgramError> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    if current_index == 0 {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    let secp_index = current_index - 1;
+
+    let secp_ix = load_instruction_at_checked(secp_index as usize, instructions_sysvar)?;
+    if secp_ix.program_id != secp256k1_program::ID {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let data = &secp_ix.data;
+    let count = *data.first().ok_or(ProgramError::InvalidInstructionData)? as usize;
+    if count == 0 {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let expected_digest = keccak::hash(expected_message).0;
+    let secp_index_u8 = u8::try_from(secp_index).map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    for i in 0..count {
+        let record_start = 1 + i * SECP_SIGNATURE_OFFSETS_LEN;
+        let record = data
+            .get(record_start..record_start + SECP_SIGNATURE_OFFSETS_LEN)
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        let offsets = parse_offsets(record)?;
+
+        // Only trust records whose address/message data was verified as part of this
+        // same secp256k1 instruction, not some other instruction in the transaction.
+        if offsets.eth_address_instruction_index != secp_index_u8
+            || offsets.message_instruction_index != secp_index_u8
+        {
+            continue;
+        }
+
+        let eth_start = offsets.eth_address_offset as usize;
+        let Some(eth_address) = data.get(eth_start..eth_start + 20) else {
+            continue;
+        };
+        if eth_address != zeta_gateway_verifier {
+            continue;
+        }
+
+        let msg_start = offsets.message_data_offset as usize;
+        let msg_end = msg_start + offsets.message_data_size as usize;
+        let Some(message) = data.get(msg_start..msg_end) else {
+            continue;
+        };
+        if message != expected_digest {
+            continue;
+        }
+
+        // `signature_offset`/`signature_instruction_index` are only consulted by the
+        // secp256k1 program itself during signature recovery (which already ran as
+        // part of executing that instruction) - having matched signer and message here
+        // is sufficient proof the corresponding signature was valid.
+        let _ = offsets.signature_offset;
+        let _ = offsets.signature_instruction_index;
+
+        return Ok(());
+    }
+
     Err(ProgramError::MissingRequiredSignature)
 }
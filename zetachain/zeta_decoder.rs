@@ -2,41 +2,133 @@ use anchor_lang::prelude::*;
 use solana_program::program_error::ProgramError;
 use anchor_lang::solana_program::pubkey::Pubkey;
 
-/// Minimal NFT message structure expected from ZetaChain.
+/// Upper bound on the total size of an inbound ZetaChain payload. Rejecting anything
+/// larger up front avoids doing partial parsing work on a message that was never going to
+/// fit a real transfer.
+pub const MAX_ZETA_PAYLOAD_LEN: usize = 2048;
+
+/// Bare NFT transfer: no trailing `sender`/`message`.
+const PAYLOAD_ID_TRANSFER: u8 = 1;
+/// NFT transfer carrying an extra `sender` and arbitrary-length `message` blob.
+const PAYLOAD_ID_TRANSFER_WITH_MESSAGE: u8 = 3;
+
+/// NFT message decoded from a ZetaChain cross-chain payload.
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
 pub struct NFTMessage {
+    pub payload_id: u8,
+    pub token_id: [u8; 32],
+    pub origin_chain: u16,
+    pub origin_mint: [u8; 32],
     pub uri: String,
     pub title: String,
     pub symbol: String,
     pub recipient: Pubkey,
     pub nonce: u64,
+    /// Only populated when `payload_id == 3`.
+    pub sender: [u8; 32],
+    /// Only populated when `payload_id == 3`.
+    pub message: Vec<u8>,
 }
 
 /// Decode a ZetaChain payload into an `NFTMessage`.
 ///
-/// By default (without feature flags), this function fails closed so we don't
-/// accept arbitrary payloads in production. Enable `insecure-placeholder` for
-/// local testing to return a dummy message.
-#[cfg(feature = "insecure-placeholder")]
-pub fn decode_zeta_payload(payload: &[u8]) -> Result<NFTMessage> {
-    msg!("Decoding payload... [placeholder]");
-    if payload.is_empty() {
-        return Err(ProgramError::InvalidInstructionData.into());
+/// Wire format, modeled on Wormhole's `PayloadTransfer` / `PayloadTransferWithPayload`
+/// (all multi-byte integers big-endian):
+///
+/// `payload_id: u8` (1 = bare transfer, 3 = transfer-with-message) | `token_id: [u8; 32]` |
+/// `origin_chain: u16` | `origin_mint: [u8; 32]` | `uri`, `title`, `symbol`: each a `u16`
+/// length prefix followed by that many UTF-8 bytes | `recipient: [u8; 32]` | `nonce: u64` |
+/// and, only when `payload_id == 3`: `sender: [u8; 32]` followed by `message`, which fills
+/// the remainder of the slice.
+///
+/// Any length prefix that would overrun the slice, an unrecognized `payload_id`, or
+/// trailing bytes left over once the format above has been fully consumed, is rejected as
+/// `ProgramError::InvalidInstructionData` rather than parsed leniently - a malformed or
+/// truncated payload must fail closed, not decode to a partially-populated message.
+pub fn decode_zeta_payload(payload: &[u8]) -> Result<NFTMessage, ProgramError> {
+    if payload.is_empty() || payload.len() > MAX_ZETA_PAYLOAD_LEN {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let mut offset = 0usize;
+    let payload_id = read_u8(payload, &mut offset)?;
+    if payload_id != PAYLOAD_ID_TRANSFER && payload_id != PAYLOAD_ID_TRANSFER_WITH_MESSAGE {
+        return Err(ProgramError::InvalidInstructionData);
     }
+
+    let token_id = read_array32(payload, &mut offset)?;
+    let origin_chain = read_u16_be(payload, &mut offset)?;
+    let origin_mint = read_array32(payload, &mut offset)?;
+    let uri = read_length_prefixed_string(payload, &mut offset)?;
+    let title = read_length_prefixed_string(payload, &mut offset)?;
+    let symbol = read_length_prefixed_string(payload, &mut offset)?;
+    let recipient = Pubkey::new_from_array(read_array32(payload, &mut offset)?);
+    let nonce = read_u64_be(payload, &mut offset)?;
+
+    let (sender, message) = if payload_id == PAYLOAD_ID_TRANSFER_WITH_MESSAGE {
+        let sender = read_array32(payload, &mut offset)?;
+        let message = payload[offset..].to_vec();
+        offset = payload.len();
+        (sender, message)
+    } else {
+        ([0u8; 32], Vec::new())
+    };
+
+    // A bare transfer consumes exactly through `nonce`; a transfer-with-message consumes
+    // through `message` (which was defined to fill the remainder). Either way `offset`
+    // should land exactly on the end of the slice - anything left over is trailing garbage.
+    if offset != payload.len() {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
     Ok(NFTMessage {
-        uri: "ipfs://dummy_uri".to_string(),
-        title: "ZetaNFT".to_string(),
-        symbol: "ZETA".to_string(),
-        recipient: Pubkey::default(),
-        nonce: 0,
+        payload_id,
+        token_id,
+        origin_chain,
+        origin_mint,
+        uri,
+        title,
+        symbol,
+        recipient,
+        nonce,
+        sender,
+        message,
     })
 }
 
-#[cfg(not(feature = "insecure-placeholder"))]
-pub fn decode_zeta_payload(payload: &[u8]) -> Result<NFTMessage> {
-    // Until a real decoder is implemented, fail closed.
-    if payload.is_empty() {
-        return Err(ProgramError::InvalidInstructionData.into());
+fn read_bytes<'a>(payload: &'a [u8], offset: &mut usize, len: usize) -> Result<&'a [u8], ProgramError> {
+    let end = offset.checked_add(len).ok_or(ProgramError::InvalidInstructionData)?;
+    if end > payload.len() {
+        return Err(ProgramError::InvalidInstructionData);
     }
-    Err(ProgramError::InvalidInstructionData.into())
+    let slice = &payload[*offset..end];
+    *offset = end;
+    Ok(slice)
+}
+
+fn read_u8(payload: &[u8], offset: &mut usize) -> Result<u8, ProgramError> {
+    Ok(read_bytes(payload, offset, 1)?[0])
+}
+
+fn read_u16_be(payload: &[u8], offset: &mut usize) -> Result<u16, ProgramError> {
+    let bytes = read_bytes(payload, offset, 2)?;
+    Ok(u16::from_be_bytes(bytes.try_into().unwrap()))
+}
+
+fn read_u64_be(payload: &[u8], offset: &mut usize) -> Result<u64, ProgramError> {
+    let bytes = read_bytes(payload, offset, 8)?;
+    Ok(u64::from_be_bytes(bytes.try_into().unwrap()))
+}
+
+fn read_array32(payload: &[u8], offset: &mut usize) -> Result<[u8; 32], ProgramError> {
+    let bytes = read_bytes(payload, offset, 32)?;
+    let mut out = [0u8; 32];
+    out.copy_from_slice(bytes);
+    Ok(out)
+}
+
+fn read_length_prefixed_string(payload: &[u8], offset: &mut usize) -> Result<String, ProgramError> {
+    let len = read_u16_be(payload, offset)? as usize;
+    let bytes = read_bytes(payload, offset, len)?;
+    String::from_utf8(bytes.to_vec()).map_err(|_| ProgramError::InvalidInstructionData)
 }